@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use splines::{Interpolation, Key, Spline};
 
 use crate::TrafficChart;
@@ -132,7 +133,8 @@ fn get_max(serie: &ChartSeries) -> f32 {
     max
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(from = "ChartSeriesPoints", into = "ChartSeriesPoints")]
 pub struct ChartSeries {
     /// Series to be displayed DURING live/offline capture
     pub spline: Spline<f32, f32>,
@@ -140,6 +142,41 @@ pub struct ChartSeries {
     pub all_time: Vec<(f32, f32)>,
 }
 
+/// Serializable representation of a `ChartSeries`, storing the points of the spline
+#[derive(Serialize, Deserialize)]
+struct ChartSeriesPoints {
+    spline: Vec<(f32, f32)>,
+    all_time: Vec<(f32, f32)>,
+}
+
+impl From<ChartSeries> for ChartSeriesPoints {
+    fn from(series: ChartSeries) -> Self {
+        Self {
+            spline: series
+                .spline
+                .keys()
+                .iter()
+                .map(|k| (k.t, k.value))
+                .collect(),
+            all_time: series.all_time,
+        }
+    }
+}
+
+impl From<ChartSeriesPoints> for ChartSeries {
+    fn from(points: ChartSeriesPoints) -> Self {
+        let keys = points
+            .spline
+            .into_iter()
+            .map(|(t, value)| Key::new(t, value, Interpolation::Cosine))
+            .collect();
+        Self {
+            spline: Spline::from_vec(keys),
+            all_time: points.all_time,
+        }
+    }
+}
+
 fn reduce_all_time_data(all_time: &mut Vec<(f32, f32)>) {
     // bisect data until we have less than 150 points
     while all_time.len() > 150 {
@@ -8,6 +8,7 @@
 use plotters::prelude::*;
 use plotters::series::LineSeries;
 use plotters_iced::{Chart, ChartBuilder, ChartWidget, DrawingBackend};
+use serde::{Deserialize, Serialize};
 use splines::{Interpolation, Key, Spline};
 
 use crate::chart::manage_chart_data::ChartSeries;
@@ -23,6 +24,7 @@
 use crate::{ByteMultiple, ChartType, Language, StyleType, location};
 
 /// Struct defining the chart to be displayed in gui run page
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TrafficChart {
     /// Current time interval number
     pub ticks: u32,
@@ -43,12 +45,15 @@ pub struct TrafficChart {
     /// Maximum number of packets per time interval (computed on last 30 intervals)
     pub max_packets: f32,
     /// Language used for the chart legend
+    #[serde(skip)]
     pub language: Language,
     /// Packets or bytes
     pub chart_type: ChartType,
     /// Style of the chart
+    #[serde(skip)]
     pub style: StyleType,
     /// Whether the chart is for the thumbnail page
+    #[serde(skip)]
     pub thumbnail: bool,
     /// Whether this is a live capture
     pub is_live_capture: bool,
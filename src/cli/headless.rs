@@ -0,0 +1,440 @@
+//! Module implementing the headless mode, used to sniff traffic without starting the GUI.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::thread;
+
+use async_channel::Receiver;
+use chrono::Local;
+use clap::ValueEnum;
+use pcap::Device;
+use serde::Serialize;
+
+use crate::location;
+use crate::mmdb::asn::ASN_MMDB;
+use crate::mmdb::country::COUNTRY_MMDB;
+use crate::mmdb::types::mmdb_reader::{MmdbReader, MmdbReaders};
+use crate::networking::parse_packets::{BackendTrafficMessage, parse_packets};
+use crate::networking::types::capture_context::{CaptureContext, CaptureSource, MyPcapImport};
+use crate::networking::types::filters::Filters;
+use crate::networking::types::host::{Host, HostMessage};
+use crate::networking::types::info_traffic::InfoTraffic;
+use crate::networking::types::my_device::MyDevice;
+use crate::report::get_report_entries::{get_host_entries, get_service_entries};
+use crate::report::traffic_report::TrafficReport;
+use crate::report::types::sort_type::SortType;
+use crate::utils::error_logger::{ErrorLogger, Location};
+use crate::{ByteMultiple, CONFIGS, ChartType};
+
+/// Maximum number of hosts and services included in each summary
+const TOP_ENTRIES: usize = 5;
+
+/// Format of the summaries printed to stdout in headless mode, and of the reports of PCAP files
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// Sniffs packets from the given adapter (or the last one used) without starting the GUI,
+/// printing a summary of the traffic observed every `interval` seconds.
+///
+/// Returns when the capture terminates or when SIGINT, SIGTERM, or SIGHUP is received.
+pub fn run_headless(
+    adapter: Option<String>,
+    filters: Filters,
+    interval: u64,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let device_name = adapter.unwrap_or_else(|| CONFIGS.device.device_name.clone());
+    let device = Device::list()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|d| d.name.eq(&device_name))
+        .ok_or_else(|| format!("network adapter '{device_name}' not found"))?;
+    let capture_source = CaptureSource::Device(MyDevice::from_pcap_device(device));
+
+    let rx = start_capture(capture_source, filters)?;
+
+    // stop the capture when receiving SIGINT, SIGTERM, or SIGHUP
+    let rx_sigint = rx.clone();
+    let _ = ctrlc::set_handler(move || {
+        rx_sigint.close();
+    })
+    .log_err(location!());
+
+    let mut interval_traffic = InfoTraffic::default();
+    let mut ticks = 0;
+    while let Ok(backend_msg) = rx.recv_blocking() {
+        match backend_msg {
+            BackendTrafficMessage::TickRun(_, mut msg, host_msgs, _) => {
+                interval_traffic.refresh(&mut msg);
+                add_new_hosts(&mut interval_traffic, host_msgs);
+                ticks += 1;
+                if ticks >= interval {
+                    let summary = TrafficSummary::new(&interval_traffic, ticks);
+                    println!("{}", summary.formatted(format));
+                    interval_traffic = InfoTraffic::default();
+                    ticks = 0;
+                }
+            }
+            BackendTrafficMessage::PendingHosts(_, host_msgs) => {
+                add_new_hosts(&mut interval_traffic, host_msgs);
+            }
+            BackendTrafficMessage::OfflineGap(..) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads all the packets of the given PCAP file without starting the GUI,
+/// writing a report of the traffic to `report_path`.
+pub fn run_pcap_analysis(
+    pcap_path: String,
+    filters: Filters,
+    report_path: &str,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let capture_source = CaptureSource::File(MyPcapImport::new(pcap_path.clone()));
+
+    let rx = start_capture(capture_source, filters)?;
+
+    let mut info_traffic = InfoTraffic::default();
+    let mut addresses_resolved: HashMap<IpAddr, Host> = HashMap::new();
+    // the parsing thread closes the channel once it reaches the end of the file
+    while let Ok(backend_msg) = rx.recv_blocking() {
+        let host_msgs = match backend_msg {
+            BackendTrafficMessage::TickRun(_, mut msg, host_msgs, _) => {
+                info_traffic.refresh(&mut msg);
+                host_msgs
+            }
+            BackendTrafficMessage::PendingHosts(_, host_msgs) => host_msgs,
+            BackendTrafficMessage::OfflineGap(..) => continue,
+        };
+        for host_msg in &host_msgs {
+            addresses_resolved.insert(host_msg.address_to_lookup, host_msg.host.clone());
+        }
+        add_new_hosts(&mut info_traffic, host_msgs);
+    }
+
+    let report = TrafficReport::new(pcap_path, &info_traffic, &addresses_resolved);
+    let content = match format {
+        OutputFormat::Text => report.to_text(),
+        OutputFormat::Json => report.to_json(),
+    };
+    std::fs::write(report_path, content).map_err(|e| e.to_string())
+}
+
+/// Spawns the thread in charge of parsing packets for the given capture source,
+/// returning the channel where its traffic messages are received.
+pub fn start_capture(
+    mut capture_source: CaptureSource,
+    filters: Filters,
+) -> Result<Receiver<BackendTrafficMessage>, String> {
+    let capture_context = CaptureContext::new(&capture_source, None, &filters, false);
+    if let Some(error) = capture_context.error() {
+        return Err(error.to_string());
+    }
+    capture_source.set_link_type(capture_context.my_link_type());
+    if !capture_source.get_link_type().is_supported() {
+        return Err(format!(
+            "unsupported link type for '{}'",
+            capture_source.get_name()
+        ));
+    }
+
+    let mmdb_readers = MmdbReaders {
+        country: Arc::new(MmdbReader::from(
+            &CONFIGS.settings.mmdb_country,
+            COUNTRY_MMDB,
+        )),
+        asn: Arc::new(MmdbReader::from(&CONFIGS.settings.mmdb_asn, ASN_MMDB)),
+    };
+
+    let (tx, rx) = async_channel::unbounded();
+    thread::Builder::new()
+        .name("thread_parse_packets".to_string())
+        .spawn(move || {
+            parse_packets(
+                0,
+                capture_source,
+                &filters,
+                &mmdb_readers,
+                capture_context,
+                &tx,
+            );
+        })
+        .map_err(|e| e.to_string())?;
+
+    Ok(rx)
+}
+
+/// Adds the hosts resolved by the parsing thread to the given traffic data
+pub fn add_new_hosts(info_traffic: &mut InfoTraffic, host_msgs: Vec<HostMessage>) {
+    for HostMessage {
+        host,
+        data_info_host,
+        ..
+    } in host_msgs
+    {
+        info_traffic
+            .hosts
+            .entry(host)
+            .and_modify(|d| d.refresh(&data_info_host))
+            .or_insert(data_info_host);
+    }
+}
+
+/// Summary of the traffic observed during a time interval
+#[derive(Serialize, Debug, PartialEq)]
+struct TrafficSummary {
+    timestamp: String,
+    interval_secs: u64,
+    incoming_packets: u128,
+    outgoing_packets: u128,
+    incoming_bytes: u128,
+    outgoing_bytes: u128,
+    excluded_packets: u128,
+    dropped_packets: u32,
+    connections: usize,
+    top_hosts: Vec<EntrySummary>,
+    top_services: Vec<EntrySummary>,
+}
+
+/// Data exchanged by a single host or service
+#[derive(Serialize, Debug, PartialEq)]
+struct EntrySummary {
+    name: String,
+    packets: u128,
+    bytes: u128,
+}
+
+impl TrafficSummary {
+    fn new(info_traffic: &InfoTraffic, interval_secs: u64) -> Self {
+        let tot = info_traffic.tot_data_info;
+
+        let top_hosts = get_host_entries(info_traffic, ChartType::Bytes, SortType::Descending)
+            .into_iter()
+            .take(TOP_ENTRIES)
+            .map(|(host, data_info_host)| {
+                let mut name = if host.domain.is_empty() {
+                    "?".to_string()
+                } else {
+                    host.domain
+                };
+                if !host.asn.name.is_empty() {
+                    name.push_str(&format!(" - {}", host.asn.name));
+                }
+                name.push_str(&format!(" ({})", host.country));
+                EntrySummary {
+                    name,
+                    packets: data_info_host.data_info.tot_packets(),
+                    bytes: data_info_host.data_info.tot_bytes(),
+                }
+            })
+            .collect();
+
+        let top_services =
+            get_service_entries(info_traffic, ChartType::Bytes, SortType::Descending)
+                .into_iter()
+                .take(TOP_ENTRIES)
+                .map(|(service, data_info)| EntrySummary {
+                    name: service.to_string(),
+                    packets: data_info.tot_packets(),
+                    bytes: data_info.tot_bytes(),
+                })
+                .collect();
+
+        Self {
+            timestamp: Local::now().format("%Y/%m/%d %H:%M:%S").to_string(),
+            interval_secs,
+            incoming_packets: tot.incoming_packets(),
+            outgoing_packets: tot.outgoing_packets(),
+            incoming_bytes: tot.incoming_bytes(),
+            outgoing_bytes: tot.outgoing_bytes(),
+            excluded_packets: info_traffic.all_packets.saturating_sub(tot.tot_packets()),
+            dropped_packets: info_traffic.dropped_packets,
+            connections: info_traffic.map.len(),
+            top_hosts,
+            top_services,
+        }
+    }
+
+    fn formatted(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.to_text(),
+            OutputFormat::Json => serde_json::to_string(self).unwrap_or_default(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut ret_val = format!(
+            "[{}] last {}s | incoming: {} packets ({}) | outgoing: {} packets ({}) | excluded: {} packets | dropped: {} packets | connections: {}",
+            self.timestamp,
+            self.interval_secs,
+            self.incoming_packets,
+            ByteMultiple::formatted_string(self.incoming_bytes),
+            self.outgoing_packets,
+            ByteMultiple::formatted_string(self.outgoing_bytes),
+            self.excluded_packets,
+            self.dropped_packets,
+            self.connections,
+        );
+        for (title, entries) in [("hosts", &self.top_hosts), ("services", &self.top_services)] {
+            if entries.is_empty() {
+                continue;
+            }
+            let list = entries
+                .iter()
+                .map(|e| format!("{} {}", e.name, ByteMultiple::formatted_string(e.bytes)))
+                .collect::<Vec<String>>()
+                .join(", ");
+            ret_val.push_str(&format!("\n    top {title}: {list}"));
+        }
+        ret_val
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Service;
+    use crate::countries::types::country::Country;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::data_info::DataInfo;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::host::Host;
+    use crate::networking::types::traffic_type::TrafficType;
+
+    use super::*;
+
+    fn info_traffic_for_tests() -> InfoTraffic {
+        let mut info_traffic = InfoTraffic {
+            tot_data_info: DataInfo::new_for_tests(10, 5, 1000, 500),
+            all_packets: 20,
+            all_bytes: 2000,
+            dropped_packets: 1,
+            ..InfoTraffic::default()
+        };
+        info_traffic.services.insert(
+            Service::Name("https"),
+            DataInfo::new_for_tests(8, 4, 900, 400),
+        );
+        info_traffic.services.insert(
+            Service::Name("dns"),
+            DataInfo::new_for_tests(2, 1, 100, 100),
+        );
+        info_traffic
+            .services
+            .insert(Service::NotApplicable, DataInfo::new_for_tests(1, 1, 1, 1));
+        info_traffic.hosts.insert(
+            Host {
+                domain: "sniffnet.net".to_string(),
+                asn: Asn {
+                    code: "1".to_string(),
+                    name: "ASN".to_string(),
+                },
+                country: Country::IT,
+            },
+            DataInfoHost {
+                data_info: DataInfo::new_for_tests(10, 5, 1000, 500),
+                is_favorite: false,
+                is_loopback: false,
+                is_local: false,
+                is_bogon: None,
+                is_blocklisted: false,
+                traffic_type: TrafficType::Unicast,
+            },
+        );
+        info_traffic
+    }
+
+    #[test]
+    fn test_traffic_summary_new() {
+        let summary = TrafficSummary::new(&info_traffic_for_tests(), 5);
+        assert_eq!(summary.interval_secs, 5);
+        assert_eq!(summary.incoming_packets, 10);
+        assert_eq!(summary.outgoing_packets, 5);
+        assert_eq!(summary.incoming_bytes, 1000);
+        assert_eq!(summary.outgoing_bytes, 500);
+        assert_eq!(summary.excluded_packets, 5);
+        assert_eq!(summary.dropped_packets, 1);
+        assert_eq!(summary.connections, 0);
+        assert_eq!(
+            summary.top_hosts,
+            vec![EntrySummary {
+                name: "sniffnet.net - ASN (IT)".to_string(),
+                packets: 15,
+                bytes: 1500
+            }]
+        );
+        assert_eq!(
+            summary.top_services,
+            vec![
+                EntrySummary {
+                    name: "https".to_string(),
+                    packets: 12,
+                    bytes: 1300
+                },
+                EntrySummary {
+                    name: "dns".to_string(),
+                    packets: 3,
+                    bytes: 200
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_traffic_summary_formatted() {
+        let mut summary = TrafficSummary::new(&info_traffic_for_tests(), 1);
+        summary.timestamp = "2025/01/01 00:00:00".to_string();
+        assert_eq!(
+            summary.formatted(OutputFormat::Text),
+            "[2025/01/01 00:00:00] last 1s | incoming: 10 packets (1.0 KB) | outgoing: 5 packets (500 B) | excluded: 5 packets | dropped: 1 packets | connections: 0\n    \
+            top hosts: sniffnet.net - ASN (IT) 1.5 KB\n    \
+            top services: https 1.3 KB, dns 200 B"
+        );
+        assert_eq!(
+            summary.formatted(OutputFormat::Json),
+            "{\"timestamp\":\"2025/01/01 00:00:00\",\"interval_secs\":1,\"incoming_packets\":10,\"outgoing_packets\":5,\"incoming_bytes\":1000,\"outgoing_bytes\":500,\"excluded_packets\":5,\"dropped_packets\":1,\"connections\":0,\
+            \"top_hosts\":[{\"name\":\"sniffnet.net - ASN (IT)\",\"packets\":15,\"bytes\":1500}],\
+            \"top_services\":[{\"name\":\"https\",\"packets\":12,\"bytes\":1300},{\"name\":\"dns\",\"packets\":3,\"bytes\":200}]}"
+        );
+    }
+
+    #[test]
+    fn test_add_new_hosts() {
+        let mut info_traffic = info_traffic_for_tests();
+        let (host, data_info_host) = info_traffic.hosts.iter().next().unwrap();
+        let (host, data_info_host) = (host.clone(), *data_info_host);
+        let new_host = Host {
+            domain: "8.8.8.8".to_string(),
+            ..Host::default()
+        };
+        add_new_hosts(
+            &mut info_traffic,
+            vec![
+                HostMessage {
+                    host: host.clone(),
+                    data_info_host,
+                    address_to_lookup: "1.1.1.1".parse().unwrap(),
+                    rdns: "sniffnet.net".to_string(),
+                },
+                HostMessage {
+                    host: new_host.clone(),
+                    data_info_host,
+                    address_to_lookup: "8.8.8.8".parse().unwrap(),
+                    rdns: "8.8.8.8".to_string(),
+                },
+            ],
+        );
+        assert_eq!(info_traffic.hosts.len(), 2);
+        assert_eq!(info_traffic.hosts[&host].data_info.tot_packets(), 30);
+        assert_eq!(info_traffic.hosts[&new_host].data_info.tot_packets(), 15);
+    }
+}
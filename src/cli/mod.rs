@@ -1,18 +1,30 @@
-use clap::Parser;
+use std::collections::HashSet;
+
+use clap::{ArgGroup, Parser};
 use iced::{Task, window};
 
 use crate::CONFIGS;
 use crate::Configs;
 use crate::SNIFFNET_LOWERCASE;
+use crate::cli::headless::{OutputFormat, run_headless, run_pcap_analysis};
 use crate::gui::types::message::Message;
+use crate::networking::types::filters::Filters;
+use crate::networking::types::ip_collection::AddressCollection;
+use crate::networking::types::my_link_type::MyLinkType;
+use crate::networking::types::port_collection::PortCollection;
+use crate::networking::types::vlan_collection::VlanCollection;
 use crate::utils::formatted_strings::APP_VERSION;
+use crate::{IpVersion, Protocol};
+
+pub mod headless;
 
 #[derive(Parser, Debug)]
 #[command(
     name = SNIFFNET_LOWERCASE,
     bin_name = SNIFFNET_LOWERCASE,
     version = APP_VERSION,
-    about = "Application to comfortably monitor your network traffic"
+    about = "Application to comfortably monitor your network traffic",
+    group(ArgGroup::new("no_gui").args(["headless", "pcap_import"]))
 )]
 struct Args {
     /// Start sniffing packets from the supplied network adapter
@@ -25,6 +37,44 @@ struct Args {
     /// Restore default settings
     #[arg(short, long, exclusive = true)]
     restore_default: bool,
+    /// Sniff packets without starting the GUI, printing traffic summaries to stdout
+    #[arg(long)]
+    headless: bool,
+    /// Analyse the supplied PCAP file without starting the GUI, writing a traffic report
+    #[arg(
+        long,
+        value_name = "PATH",
+        requires = "report",
+        conflicts_with = "adapter"
+    )]
+    pcap_import: Option<String>,
+    /// Path of the report file written when analysing a PCAP file
+    #[arg(long, value_name = "PATH", requires = "pcap_import")]
+    report: Option<String>,
+    /// Seconds between two traffic summaries in headless mode
+    #[arg(long, value_name = "SECONDS", default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..), requires = "headless")]
+    interval: u64,
+    /// Format of the traffic summaries in headless mode, or of the PCAP report
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, requires = "no_gui")]
+    format: OutputFormat,
+    /// Only consider traffic with the supplied IP versions (e.g., ipv4,ipv6)
+    #[arg(long, value_name = "VERSIONS", value_delimiter = ',', value_parser = parse_ip_version, requires = "no_gui")]
+    ip_versions: Vec<IpVersion>,
+    /// Only consider traffic with the supplied protocols (e.g., tcp,udp,icmp,arp)
+    #[arg(long, value_name = "PROTOCOLS", value_delimiter = ',', value_parser = parse_protocol, requires = "no_gui")]
+    protocols: Vec<Protocol>,
+    /// Only consider traffic with the supplied IP addresses (e.g., 10.0.0.1-10.0.0.255,fe80::1)
+    #[arg(long, value_name = "ADDRESSES", value_parser = parse_addresses, requires = "no_gui")]
+    addresses: Option<String>,
+    /// Only consider traffic with the supplied ports (e.g., 22,443,8000-8080)
+    #[arg(long, value_name = "PORTS", value_parser = parse_ports, requires = "no_gui")]
+    ports: Option<String>,
+    /// Only consider traffic tagged with the supplied VLAN IDs (e.g., 10,100-199)
+    #[arg(long, value_name = "VLANS", value_parser = parse_vlans, requires = "no_gui")]
+    vlans: Option<String>,
+    /// Only capture packets matching the supplied BPF expression (e.g., "tcp port 443 and not host 10.0.0.1")
+    #[arg(long, value_name = "EXPRESSION", requires = "no_gui")]
+    bpf: Option<String>,
 }
 
 pub fn handle_cli_args() -> Task<Message> {
@@ -56,6 +106,25 @@ pub fn handle_cli_args() -> Task<Message> {
         std::process::exit(0);
     }
 
+    if args.headless {
+        let filters = get_filters(&args);
+        if let Err(e) = run_headless(args.adapter, filters, args.interval, args.format) {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    }
+
+    if let (Some(pcap_path), Some(report_path)) = (args.pcap_import.clone(), &args.report) {
+        let filters = get_filters(&args);
+        if let Err(e) = run_pcap_analysis(pcap_path, filters, report_path, args.format) {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+        println!("Report written to {report_path}");
+        std::process::exit(0);
+    }
+
     let mut boot_task_chain = window::get_latest()
         .map(Message::StartApp)
         .chain(Task::done(Message::Periodic));
@@ -68,13 +137,75 @@ pub fn handle_cli_args() -> Task<Message> {
     boot_task_chain
 }
 
+/// Builds the traffic filters from the supplied command line arguments
+fn get_filters(args: &Args) -> Filters {
+    let mut filters = Filters::default();
+    if !args.ip_versions.is_empty() {
+        filters.ip_versions = HashSet::from_iter(args.ip_versions.iter().copied());
+    }
+    if !args.protocols.is_empty() {
+        filters.protocols = HashSet::from_iter(args.protocols.iter().copied());
+    }
+    if let Some(address_str) = &args.addresses {
+        filters.address_collection = AddressCollection::new(address_str).unwrap_or_default();
+        filters.address_str.clone_from(address_str);
+    }
+    if let Some(port_str) = &args.ports {
+        filters.port_collection = PortCollection::new(port_str).unwrap_or_default();
+        filters.port_str.clone_from(port_str);
+    }
+    if let Some(vlan_str) = &args.vlans {
+        filters.vlan_collection = VlanCollection::new(vlan_str).unwrap_or_default();
+        filters.vlan_str.clone_from(vlan_str);
+    }
+    if let Some(bpf) = &args.bpf {
+        filters.set_bpf(bpf.clone(), MyLinkType::NotYetAssigned);
+    }
+    filters
+}
+
+fn parse_ip_version(s: &str) -> Result<IpVersion, String> {
+    IpVersion::ALL
+        .into_iter()
+        .find(|v| v.to_string().eq_ignore_ascii_case(s.trim()))
+        .ok_or_else(|| format!("invalid IP version '{s}'"))
+}
+
+fn parse_addresses(s: &str) -> Result<String, String> {
+    AddressCollection::new(s)
+        .map(|_| s.to_string())
+        .ok_or_else(|| format!("invalid IP addresses '{s}'"))
+}
+
+fn parse_ports(s: &str) -> Result<String, String> {
+    PortCollection::new(s)
+        .map(|_| s.to_string())
+        .ok_or_else(|| format!("invalid ports '{s}'"))
+}
+
+fn parse_vlans(s: &str) -> Result<String, String> {
+    VlanCollection::new(s)
+        .map(|_| s.to_string())
+        .ok_or_else(|| format!("invalid VLAN IDs '{s}'"))
+}
+
+fn parse_protocol(s: &str) -> Result<Protocol, String> {
+    Protocol::ALL
+        .into_iter()
+        .find(|p| p.to_string().eq_ignore_ascii_case(s.trim()))
+        .ok_or_else(|| format!("invalid protocol '{s}'"))
+}
+
 #[cfg(test)]
 mod tests {
     use serial_test::serial;
 
+    use crate::configs::types::config_favorites::ConfigFavorites;
     use crate::configs::types::config_window::{PositionTuple, SizeTuple};
+    use crate::countries::types::country::Country;
     use crate::gui::styles::types::custom_palette::ExtraStyles;
     use crate::gui::styles::types::gradient_type::GradientType;
+    use crate::networking::types::host::Host;
     use crate::notifications::types::notifications::Notifications;
     use crate::{ConfigDevice, ConfigSettings, ConfigWindow, Language, Sniffer, StyleType};
 
@@ -92,6 +223,8 @@ fn test_restore_default_configs() {
                 scale_factor: 0.65,
                 mmdb_country: "countrymmdb".to_string(),
                 mmdb_asn: "asnmmdb".to_string(),
+                blocklists: vec![],
+                host_labels: vec![],
                 style_path: format!(
                     "{}/resources/themes/catppuccin.toml",
                     env!("CARGO_MANIFEST_DIR")
@@ -99,7 +232,14 @@ fn test_restore_default_configs() {
                 notifications: Notifications {
                     volume: 100,
                     data_notification: Default::default(),
+                    scoped_data_notifications: Vec::new(),
                     favorite_notification: Default::default(),
+                    first_seen_notification: Default::default(),
+                    anomaly_notification: Default::default(),
+                    scan_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    desktop_notifications: true,
+                    actions: Default::default(),
                 },
                 style: StyleType::Custom(ExtraStyles::DraculaDark),
             },
@@ -111,6 +251,13 @@ fn test_restore_default_configs() {
                 size: SizeTuple(452.0, 870.0),
                 thumbnail_position: PositionTuple(20.0, 20.0),
             },
+            favorites: ConfigFavorites {
+                hosts: vec![Host {
+                    domain: "example.com".to_string(),
+                    asn: Default::default(),
+                    country: Country::US,
+                }],
+            },
         };
         // we want to be sure that modified config is different from defaults
         assert_ne!(Configs::default(), modified_configs);
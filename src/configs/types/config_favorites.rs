@@ -0,0 +1,118 @@
+//! Module defining the `ConfigFavorites` struct, which allows to save and reload
+//! the favorite hosts across different runs of the application.
+
+use crate::networking::types::host::Host;
+#[cfg(not(test))]
+use crate::utils::error_logger::{ErrorLogger, Location};
+#[cfg(not(test))]
+use crate::{SNIFFNET_LOWERCASE, location};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ConfigFavorites {
+    /// Favorite hosts, in the order they were added
+    pub hosts: Vec<Host>,
+}
+
+impl ConfigFavorites {
+    const FILE_NAME: &'static str = "favorites";
+
+    #[cfg(not(test))]
+    pub fn load() -> Self {
+        if let Ok(favorites) = confy::load::<ConfigFavorites>(SNIFFNET_LOWERCASE, Self::FILE_NAME) {
+            favorites
+        } else {
+            let _ = confy::store(
+                SNIFFNET_LOWERCASE,
+                Self::FILE_NAME,
+                ConfigFavorites::default(),
+            )
+            .log_err(location!());
+            ConfigFavorites::default()
+        }
+    }
+
+    #[cfg(not(test))]
+    pub fn store(self) -> Result<(), confy::ConfyError> {
+        confy::store(SNIFFNET_LOWERCASE, Self::FILE_NAME, self).log_err(location!())
+    }
+
+    /// Adds the given host at the end of the list, if not already present
+    pub fn add(&mut self, host: &Host) {
+        if !self.hosts.contains(host) {
+            self.hosts.push(host.clone());
+        }
+    }
+
+    pub fn remove(&mut self, host: &Host) {
+        self.hosts.retain(|h| h != host);
+    }
+
+    /// Content of the file the favorites are exported to
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap_or_default()
+    }
+
+    /// Reads favorites previously exported with [`ConfigFavorites::to_toml`]
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::countries::types::country::Country;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::host::Host;
+
+    use super::ConfigFavorites;
+
+    impl ConfigFavorites {
+        pub fn test_path() -> String {
+            format!("{}/{}.toml", env!("CARGO_MANIFEST_DIR"), Self::FILE_NAME)
+        }
+
+        pub fn load() -> Self {
+            confy::load_path::<ConfigFavorites>(ConfigFavorites::test_path())
+                .unwrap_or_else(|_| ConfigFavorites::default())
+        }
+
+        pub fn store(self) -> Result<(), confy::ConfyError> {
+            confy::store_path(ConfigFavorites::test_path(), self)
+        }
+    }
+
+    fn host(domain: &str) -> Host {
+        Host {
+            domain: domain.to_string(),
+            asn: Asn {
+                code: "15169".to_string(),
+                name: "GOOGLE".to_string(),
+            },
+            country: Country::US,
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_favorites() {
+        let mut favorites = ConfigFavorites::default();
+        favorites.add(&host("b.com"));
+        favorites.add(&host("a.com"));
+        favorites.add(&host("b.com"));
+        assert_eq!(favorites.hosts, vec![host("b.com"), host("a.com")]);
+        favorites.remove(&host("b.com"));
+        favorites.remove(&host("c.com"));
+        assert_eq!(favorites.hosts, vec![host("a.com")]);
+    }
+
+    #[test]
+    fn test_favorites_export_and_import() {
+        let favorites = ConfigFavorites {
+            hosts: vec![host("dns.google"), host("8.8.4.4")],
+        };
+        let content = favorites.to_toml();
+        assert!(content.contains("[[hosts]]"));
+        assert_eq!(ConfigFavorites::from_toml(&content), Ok(favorites));
+        assert!(ConfigFavorites::from_toml("hosts = 3").is_err());
+    }
+}
@@ -4,6 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::gui::styles::types::gradient_type::GradientType;
+use crate::networking::types::host_label::HostLabel;
 use crate::notifications::types::notifications::Notifications;
 #[cfg(not(test))]
 use crate::utils::error_logger::{ErrorLogger, Location};
@@ -18,6 +19,8 @@ pub struct ConfigSettings {
     pub scale_factor: f64,
     pub mmdb_country: String,
     pub mmdb_asn: String,
+    pub blocklists: Vec<String>,
+    pub host_labels: Vec<HostLabel>,
     pub style_path: String,
     pub notifications: Notifications,
     // StyleType should be last in order to deserialize as a table properly
@@ -56,6 +59,8 @@ fn default() -> Self {
             scale_factor: 1.0,
             mmdb_country: String::new(),
             mmdb_asn: String::new(),
+            blocklists: Vec::new(),
+            host_labels: Vec::new(),
             style_path: String::new(),
             notifications: Notifications::default(),
             style: StyleType::default(),
@@ -1,3 +1,4 @@
+use crate::configs::types::config_favorites::ConfigFavorites;
 use crate::{ConfigDevice, ConfigSettings, ConfigWindow};
 use confy::ConfyError;
 
@@ -8,6 +9,7 @@ pub struct Configs {
     pub settings: ConfigSettings,
     pub device: ConfigDevice,
     pub window: ConfigWindow,
+    pub favorites: ConfigFavorites,
 }
 
 impl Configs {
@@ -18,6 +20,7 @@ pub fn load() -> Self {
             settings: ConfigSettings::load(),
             device: ConfigDevice::load(),
             window: ConfigWindow::load(),
+            favorites: ConfigFavorites::load(),
         }
     }
 
@@ -25,6 +28,7 @@ pub fn store(self) -> Result<(), ConfyError> {
         self.settings.store()?;
         self.device.store()?;
         self.window.store()?;
+        self.favorites.store()?;
         Ok(())
     }
 }
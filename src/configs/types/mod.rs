@@ -1,4 +1,5 @@
 pub mod config_device;
+pub mod config_favorites;
 pub mod config_settings;
 pub mod config_window;
 pub mod configs;
@@ -16,6 +16,7 @@
 use crate::gui::types::message::Message;
 use crate::translations::translations::{quit_analysis_translation, settings_translation};
 use crate::translations::translations_3::thumbnail_mode_translation;
+use crate::translations::translations_4::save_session_translation;
 use crate::utils::types::icon::Icon;
 use crate::{Language, SNIFFNET_TITLECASE, StyleType};
 
@@ -61,7 +62,11 @@ pub fn header(sniffer: &Sniffer) -> Container<Message, StyleType> {
                 Container::new(Space::with_width(60))
             })
             .push(horizontal_space())
-            .push(Container::new(Space::with_width(40)))
+            .push(if is_running {
+                Container::new(get_button_save_session(font, language))
+            } else {
+                Container::new(Space::with_width(40))
+            })
             .push(Space::with_width(20))
             .push(logo)
             .push(Space::with_width(20))
@@ -101,6 +106,28 @@ fn get_button_reset<'a>(font: Font, language: Language) -> Tooltip<'a, Message,
     .class(ContainerType::Tooltip)
 }
 
+fn get_button_save_session<'a>(font: Font, language: Language) -> Tooltip<'a, Message, StyleType> {
+    let content = button(
+        Icon::File
+            .to_text()
+            .size(18)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center),
+    )
+    .padding(0)
+    .height(40)
+    .width(40)
+    .on_press(Message::SaveSession);
+
+    Tooltip::new(
+        content,
+        Text::new(save_session_translation(language)).font(font),
+        Position::Right,
+    )
+    .gap(5)
+    .class(ContainerType::Tooltip)
+}
+
 pub fn get_button_settings<'a>(
     font: Font,
     language: Language,
@@ -15,6 +15,7 @@
     ask_clear_all_translation, ask_quit_translation, clear_all_translation,
     quit_analysis_translation, yes_translation,
 };
+use crate::translations::translations_4::save_failed_translation;
 use crate::{Language, StyleType};
 
 pub fn get_exit_overlay<'a>(
@@ -82,6 +83,32 @@ pub fn get_clear_all_overlay<'a>(
         .class(ContainerType::Modal)
 }
 
+pub fn get_save_failed_overlay<'a>(
+    error: String,
+    color_gradient: GradientType,
+    font: Font,
+    font_headers: Font,
+    language: Language,
+) -> Container<'a, Message, StyleType> {
+    let content = Column::new()
+        .align_x(Alignment::Center)
+        .width(Length::Fill)
+        .push(get_modal_header(
+            font,
+            font_headers,
+            color_gradient,
+            language,
+            save_failed_translation(language),
+        ))
+        .push(Space::with_height(20))
+        .push(Text::new(error).align_x(Alignment::Center).font(font));
+
+    Container::new(content)
+        .height(160)
+        .width(450)
+        .class(ContainerType::Modal)
+}
+
 fn get_modal_header<'a>(
     font: Font,
     font_headers: Font,
@@ -11,4 +11,8 @@ pub enum MyModal {
     ClearAll,
     /// Connection details modal.
     ConnectionDetails(AddressPortPair),
+    /// DNS log modal.
+    DnsLog,
+    /// Modal reporting that a file couldn't be saved, with the error.
+    SaveFailed(String),
 }
@@ -16,12 +16,15 @@
 use crate::networking::types::arp_type::ArpType;
 use crate::networking::types::bogon::is_bogon;
 use crate::networking::types::host::Host;
+use crate::networking::types::host_label::HostLabel;
 use crate::networking::types::icmp_type::IcmpType;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::tcp_flags::TcpFlagsCount;
 use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::report::types::search_parameters::FilterInputType;
 use crate::translations::translations::{
-    address_translation, incoming_translation, outgoing_translation, packets_translation,
-    protocol_translation,
+    address_translation, incoming_translation, network_adapter_translation, outgoing_translation,
+    packets_translation, protocol_translation,
 };
 use crate::translations::translations_2::{
     administrative_entity_translation, connection_details_translation, destination_translation,
@@ -31,6 +34,11 @@
 use crate::translations::translations_3::{
     copy_translation, messages_translation, service_translation,
 };
+use crate::translations::translations_4::{
+    connection_state_translation, label_translation, mpls_labels_translation,
+    network_adapters_translation, process_translation, server_name_translation,
+    vlan_ids_translation,
+};
 use crate::utils::formatted_strings::{get_formatted_timestamp, get_socket_address};
 use crate::utils::types::icon::Icon;
 use crate::{ByteMultiple, ConfigSettings, Language, Protocol, Sniffer, StyleType};
@@ -64,6 +72,10 @@ fn page_content<'a>(sniffer: &Sniffer, key: &AddressPortPair) -> Container<'a, M
         .get(key)
         .unwrap_or(&InfoAddressPortPair::default())
         .clone();
+    let reverse_tcp_flags = info_traffic
+        .map
+        .get(&key.reversed())
+        .map(|reverse_val| reverse_val.tcp_flags);
     let address_to_lookup = get_address_to_lookup(key, val.traffic_direction);
     let host_option = sniffer.addresses_resolved.get(&address_to_lookup).cloned();
     let host_info_option = info_traffic
@@ -110,6 +122,7 @@ fn page_content<'a>(sniffer: &Sniffer, key: &AddressPortPair) -> Container<'a, M
         &key.address1,
         key.port1,
         val.mac_address1.as_ref(),
+        sniffer.address_label(&key.address1, val.mac_address1.as_deref()),
         font,
         language,
         &sniffer.timing_events,
@@ -119,6 +132,7 @@ fn page_content<'a>(sniffer: &Sniffer, key: &AddressPortPair) -> Container<'a, M
         &key.address2,
         key.port2,
         val.mac_address2.as_ref(),
+        sniffer.address_label(&key.address2, val.mac_address2.as_deref()),
         font,
         language,
         &sniffer.timing_events,
@@ -130,7 +144,7 @@ fn page_content<'a>(sniffer: &Sniffer, key: &AddressPortPair) -> Container<'a, M
         dest_col = dest_col.push(host_info_col);
     }
 
-    let col_info = col_info(key, &val, font, language);
+    let col_info = col_info(key, &val, reverse_tcp_flags.as_ref(), font, language);
 
     let content = assemble_widgets(col_info, source_col, dest_col);
 
@@ -172,9 +186,11 @@ fn page_header<'a>(
 fn col_info<'a>(
     key: &AddressPortPair,
     val: &InfoAddressPortPair,
+    reverse_tcp_flags: Option<&TcpFlagsCount>,
     font: Font,
     language: Language,
 ) -> Column<'a, Message, StyleType> {
+    let is_tcp = key.protocol.eq(&Protocol::TCP);
     let is_icmp = key.protocol.eq(&Protocol::ICMP);
     let is_arp = key.protocol.eq(&Protocol::ARP);
 
@@ -230,6 +246,78 @@ fn col_info<'a>(
         font,
     ));
 
+    if !val.interfaces.is_empty() {
+        let interfaces: Vec<&str> = val.interfaces.iter().map(String::as_str).collect();
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            if interfaces.len() > 1 {
+                network_adapters_translation(language)
+            } else {
+                network_adapter_translation(language)
+            },
+            &interfaces.join("\n"),
+            font,
+        ));
+    }
+
+    if let Some(wifi) = &val.wifi {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            "Wi-Fi",
+            &wifi.to_lines(language).join("\n"),
+            font,
+        ));
+    }
+
+    if !val.vlan_ids.is_empty() {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            vlan_ids_translation(language),
+            &FilterInputType::Vlan.entry_value(key, val, None),
+            font,
+        ));
+    }
+
+    if !val.mpls_labels.is_empty() {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            mpls_labels_translation(language),
+            &FilterInputType::Mpls.entry_value(key, val, None),
+            font,
+        ));
+    }
+
+    if let Some(process) = &val.process {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            process_translation(language),
+            &process.to_string(),
+            font,
+        ));
+    }
+
+    if let Some(client_hello) = &val.tls_client_hello {
+        ret_val = ret_val
+            .push(TextType::highlighted_subtitle_with_desc(
+                &format!("{} (SNI)", server_name_translation(language)),
+                client_hello.server_name.as_deref().unwrap_or("-"),
+                font,
+            ))
+            .push(TextType::highlighted_subtitle_with_desc(
+                "ALPN",
+                &client_hello.alpn_string(),
+                font,
+            ));
+    }
+
+    if is_tcp {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            connection_state_translation(language),
+            format!(
+                "{}\n{}",
+                val.tcp_flags.state(reverse_tcp_flags).get_label(language),
+                val.tcp_flags.pretty_print()
+            )
+            .trim_end(),
+            font,
+        ));
+    }
+
     if is_icmp || is_arp {
         ret_val = ret_val.push(
             Column::new()
@@ -323,11 +411,13 @@ fn get_local_tooltip<'a>(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_src_or_dest_col<'a>(
     caption: Row<'a, Message, StyleType>,
     ip: &IpAddr,
     port: Option<u16>,
     mac: Option<&String>,
+    label: Option<&HostLabel>,
     font: Font,
     language: Language,
     timing_events: &TimingEvents,
@@ -364,6 +454,13 @@ fn get_src_or_dest_col<'a>(
             mac_str,
             font,
         ))
+        .push_maybe(label.map(|label| {
+            TextType::highlighted_subtitle_with_desc(
+                label_translation(language),
+                &label.text(),
+                font,
+            )
+        }))
 }
 
 fn assemble_widgets<'a>(
@@ -0,0 +1,190 @@
+use iced::widget::scrollable::Direction;
+use iced::widget::{Column, Container, Row, Rule, Scrollable, Text, horizontal_space};
+use iced::{Alignment, Font, Length};
+
+use crate::gui::components::button::button_hide;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::styles::types::gradient_type::GradientType;
+use crate::gui::types::message::Message;
+use crate::networking::types::dns_log_entry::DnsLogEntry;
+use crate::networking::types::dns_message::DnsResponseCode;
+use crate::translations::translations_2::domain_name_translation;
+use crate::translations::translations_4::{
+    dns_answers_translation, dns_log_translation, dns_query_type_translation,
+    dns_response_code_translation, no_dns_responses_translation,
+};
+use crate::utils::formatted_strings::get_formatted_timestamp;
+use crate::utils::types::icon::Icon;
+use crate::{ConfigSettings, Language, Sniffer, StyleType};
+
+/// Maximum number of (most recent) entries displayed
+const MAX_DISPLAYED_ENTRIES: usize = 200;
+
+const TIME_COL_WIDTH: f32 = 170.0;
+const NAME_COL_WIDTH: f32 = 320.0;
+const TYPE_COL_WIDTH: f32 = 70.0;
+const RCODE_COL_WIDTH: f32 = 100.0;
+
+const NAME_COL_MAX_CHARS: usize = 36;
+
+pub fn dns_log_page(sniffer: &Sniffer) -> Container<'_, Message, StyleType> {
+    let ConfigSettings {
+        style,
+        language,
+        color_gradient,
+        ..
+    } = sniffer.configs.settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let dns_log = &sniffer.info_traffic.dns_log;
+
+    let mut content = Column::new()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding([10, 20])
+        .spacing(5);
+
+    if dns_log.is_empty() {
+        content = content.push(
+            Container::new(Text::new(no_dns_responses_translation(language)).font(font))
+                .center(Length::Fill),
+        );
+    } else {
+        let mut entries_col = Column::new().padding([0, 10]).spacing(2);
+        for entry in dns_log.iter().rev().take(MAX_DISPLAYED_ENTRIES) {
+            entries_col = entries_col.push(entry_row(entry, font));
+        }
+        content = content
+            .push(header_row(font, language))
+            .push(Rule::horizontal(5))
+            .push(
+                Scrollable::with_direction(
+                    entries_col,
+                    Direction::Vertical(ScrollbarType::properties()),
+                )
+                .height(Length::Fill)
+                .width(Length::Fill),
+            );
+    }
+
+    Container::new(
+        Column::new()
+            .push(page_header(font, font_headers, color_gradient, language))
+            .push(content),
+    )
+    .width(1000)
+    .height(500)
+    .class(ContainerType::Modal)
+}
+
+fn page_header<'a>(
+    font: Font,
+    font_headers: Font,
+    color_gradient: GradientType,
+    language: Language,
+) -> Container<'a, Message, StyleType> {
+    Container::new(
+        Row::new()
+            .push(horizontal_space())
+            .push(
+                Text::new(dns_log_translation(language))
+                    .font(font_headers)
+                    .size(FONT_SIZE_TITLE)
+                    .width(Length::FillPortion(6))
+                    .align_x(Alignment::Center),
+            )
+            .push(
+                Container::new(button_hide(Message::HideModal, language, font))
+                    .width(Length::Fill)
+                    .align_x(Alignment::Center),
+            ),
+    )
+    .align_x(Alignment::Center)
+    .align_y(Alignment::Center)
+    .height(40.0)
+    .width(Length::Fill)
+    .class(ContainerType::Gradient(color_gradient))
+}
+
+fn header_row<'a>(font: Font, language: Language) -> Row<'a, Message, StyleType> {
+    Row::new()
+        .padding([0, 10])
+        .align_y(Alignment::Center)
+        .push(
+            Container::new(Icon::Clock.to_text())
+                .width(TIME_COL_WIDTH)
+                .align_x(Alignment::Center),
+        )
+        .push(
+            Text::new(domain_name_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .width(NAME_COL_WIDTH),
+        )
+        .push(
+            Text::new(dns_query_type_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .width(TYPE_COL_WIDTH),
+        )
+        .push(
+            Text::new(dns_response_code_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .width(RCODE_COL_WIDTH),
+        )
+        .push(
+            Text::new(dns_answers_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .width(Length::Fill),
+        )
+}
+
+fn entry_row<'a>(entry: &DnsLogEntry, font: Font) -> Row<'a, Message, StyleType> {
+    let query_name = if entry.query_name.chars().count() <= NAME_COL_MAX_CHARS {
+        entry.query_name.clone()
+    } else {
+        let truncated: String = entry
+            .query_name
+            .chars()
+            .take(NAME_COL_MAX_CHARS - 1)
+            .collect();
+        [&truncated, "…"].concat()
+    };
+    let rcode_type = if entry.response_code == DnsResponseCode::NoError {
+        TextType::Standard
+    } else {
+        TextType::Danger
+    };
+
+    Row::new()
+        .align_y(Alignment::Start)
+        .push(
+            Text::new(get_formatted_timestamp(entry.timestamp))
+                .font(font)
+                .width(TIME_COL_WIDTH)
+                .align_x(Alignment::Center),
+        )
+        .push(Text::new(query_name).font(font).width(NAME_COL_WIDTH))
+        .push(
+            Text::new(entry.query_type.to_string())
+                .font(font)
+                .width(TYPE_COL_WIDTH),
+        )
+        .push(
+            Text::new(entry.response_code.to_string())
+                .font(font)
+                .class(rcode_type)
+                .width(RCODE_COL_WIDTH),
+        )
+        .push(
+            Text::new(entry.answers_string())
+                .font(font)
+                .width(Length::Fill),
+        )
+}
@@ -29,6 +29,7 @@
 use crate::networking::types::filters::Filters;
 use crate::networking::types::ip_collection::AddressCollection;
 use crate::networking::types::port_collection::PortCollection;
+use crate::networking::types::vlan_collection::VlanCollection;
 use crate::translations::translations::{
     address_translation, addresses_translation, choose_adapters_translation,
     ip_version_translation, protocol_translation, select_filters_translation, start_translation,
@@ -36,7 +37,10 @@
 use crate::translations::translations_3::{
     directory_translation, export_capture_translation, file_name_translation, port_translation,
 };
-use crate::translations::translations_4::import_capture_translation;
+use crate::translations::translations_4::{
+    bpf_filter_translation, capture_also_adapter_translation, import_capture_translation,
+    monitor_mode_translation, open_session_translation, vlan_ids_translation,
+};
 use crate::utils::formatted_strings::{get_invalid_filters_string, get_path_termination_string};
 use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::icon::Icon;
@@ -73,6 +77,11 @@ pub fn initial_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
     let port_active = &sniffer.filters.port_str;
     let col_port_filter = col_port_input(port_active, font, language);
 
+    let vlan_active = &sniffer.filters.vlan_str;
+    let col_vlan_filter = col_vlan_input(vlan_active, font, language);
+
+    let col_bpf_filter = col_bpf_input(&sniffer.filters, font, language);
+
     let filters_pane = Column::new()
         .width(FillPortion(6))
         .padding(10)
@@ -93,8 +102,10 @@ pub fn initial_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
             Row::new()
                 .spacing(20)
                 .push(col_address_filter)
-                .push(col_port_filter),
+                .push(col_port_filter)
+                .push(col_vlan_filter),
         )
+        .push(col_bpf_filter)
         .push(Rule::horizontal(40))
         .push(get_export_pcap_group(
             &sniffer.capture_source,
@@ -265,6 +276,67 @@ fn col_port_input(value: &str, font: Font, language: Language) -> Column<Message
         .push(input_row)
 }
 
+fn col_vlan_input(value: &str, font: Font, language: Language) -> Column<'_, Message, StyleType> {
+    let is_error = if value.is_empty() {
+        false
+    } else {
+        VlanCollection::new(value).is_none()
+    };
+    let input_row = Row::new().padding(Padding::ZERO.left(5)).push(
+        TextInput::new(VlanCollection::PLACEHOLDER_STR, value)
+            .padding([3, 5])
+            .on_input(Message::VlanFilter)
+            .font(font)
+            .width(120)
+            .class(if is_error {
+                TextInputType::Error
+            } else {
+                TextInputType::Standard
+            }),
+    );
+
+    Column::new()
+        .width(Length::Fill)
+        .spacing(7)
+        .push(
+            Text::new(vlan_ids_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(input_row)
+}
+
+fn col_bpf_input(
+    filters: &Filters,
+    font: Font,
+    language: Language,
+) -> Column<'_, Message, StyleType> {
+    let input_row = Row::new().padding(Padding::ZERO.left(5)).push(
+        TextInput::new("tcp port 443 and not host 10.0.0.1", &filters.bpf)
+            .padding([3, 5])
+            .on_input(Message::BpfFilter)
+            .font(font)
+            .width(510)
+            .class(if filters.bpf_valid() {
+                TextInputType::Standard
+            } else {
+                TextInputType::Error
+            }),
+    );
+
+    Column::new()
+        .width(Length::Fill)
+        .spacing(7)
+        .push(
+            Text::new(bpf_filter_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(input_row)
+}
+
 fn button_start(
     font: Font,
     language: Language,
@@ -338,7 +410,7 @@ fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, StyleType>
             let address_string = addr.addr.to_string();
             let _ = write!(addrs_str, "\n   {address_string}");
         }
-        dev_str_list.push((name, title, subtitle, addrs_str));
+        dev_str_list.push((name, title, subtitle, addrs_str, my_dev.is_wireless()));
     }
 
     Column::new()
@@ -360,39 +432,62 @@ fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, StyleType>
             Scrollable::with_direction(
                 dev_str_list.into_iter().fold(
                     Column::new().padding(13).spacing(5),
-                    |scroll_adapters, (name, title, subtitle, addrs)| {
+                    |scroll_adapters, (name, title, subtitle, addrs, is_wireless)| {
                         let addrs_text = if addrs.is_empty() {
                             None
                         } else {
                             Some(Text::new(addrs).font(font))
                         };
-                        scroll_adapters.push(
-                            Button::new(
-                                Column::new()
-                                    .spacing(5)
-                                    .push(
-                                        Text::new(title)
-                                            .font(font)
-                                            .class(TextType::Subtitle)
-                                            .size(FONT_SIZE_SUBTITLE),
+                        let is_selected = matches!(
+                            &sniffer.capture_source,
+                            CaptureSource::Device(device) if name == device.get_name()
+                        );
+                        let adapter_button = Button::new(
+                            Column::new()
+                                .spacing(5)
+                                .push(
+                                    Text::new(title)
+                                        .font(font)
+                                        .class(TextType::Subtitle)
+                                        .size(FONT_SIZE_SUBTITLE),
+                                )
+                                .push_maybe(subtitle.map(|sub| Text::new(sub).font(font)))
+                                .push_maybe(addrs_text)
+                                .push_maybe((is_selected && is_wireless).then(|| {
+                                    Checkbox::new(
+                                        monitor_mode_translation(language),
+                                        sniffer.monitor_mode,
                                     )
-                                    .push_maybe(subtitle.map(|sub| Text::new(sub).font(font)))
-                                    .push_maybe(addrs_text),
-                            )
-                            .padding([20, 30])
-                            .width(Length::Fill)
-                            .class(
-                                if let CaptureSource::Device(device) = &sniffer.capture_source {
-                                    if name == device.get_name() {
-                                        ButtonType::BorderedRoundSelected
-                                    } else {
-                                        ButtonType::BorderedRound
-                                    }
-                                } else {
-                                    ButtonType::BorderedRound
-                                },
-                            )
-                            .on_press(Message::DeviceSelection(name.to_string())),
+                                    .on_toggle(Message::MonitorMode)
+                                    .size(18)
+                                    .font(font)
+                                })),
+                        )
+                        .padding([20, 30])
+                        .width(Length::Fill)
+                        .class(if is_selected {
+                            ButtonType::BorderedRoundSelected
+                        } else {
+                            ButtonType::BorderedRound
+                        })
+                        .on_press(Message::DeviceSelection(name.to_string()));
+                        scroll_adapters.push(
+                            Row::new()
+                                .align_y(Alignment::Center)
+                                .spacing(10)
+                                .push(adapter_button)
+                                .push_maybe(
+                                    matches!(&sniffer.capture_source, CaptureSource::Device(_))
+                                        .then(|| {
+                                            additional_device_checkbox(
+                                                name,
+                                                is_selected,
+                                                sniffer.additional_devices.contains(name),
+                                                language,
+                                                font,
+                                            )
+                                        }),
+                                ),
                         )
                     },
                 ),
@@ -402,6 +497,29 @@ fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, StyleType>
         })
 }
 
+fn additional_device_checkbox<'a>(
+    name: &str,
+    is_selected: bool,
+    is_additional: bool,
+    language: Language,
+    font: Font,
+) -> Tooltip<'a, Message, StyleType> {
+    let mut checkbox = Checkbox::new("", is_selected || is_additional).size(18);
+    // the selected adapter is always captured
+    if !is_selected {
+        let name = name.to_string();
+        checkbox = checkbox
+            .on_toggle(move |insert| Message::AdditionalDeviceSelection(name.clone(), insert));
+    }
+    Tooltip::new(
+        checkbox,
+        Text::new(capture_also_adapter_translation(language)).font(font),
+        Position::Left,
+    )
+    .gap(5)
+    .class(ContainerType::Tooltip)
+}
+
 fn get_col_import_pcap<'a>(
     language: Language,
     font: Font,
@@ -452,6 +570,21 @@ fn get_col_import_pcap<'a>(
                 .size(FONT_SIZE_TITLE),
         )
         .push(button)
+        .push(
+            Row::new()
+                .padding([0, 13])
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(Text::new(open_session_translation(language)).font(font))
+                .push(button_open_file(
+                    String::new(),
+                    FileInfo::Session,
+                    language,
+                    font,
+                    true,
+                    Message::OpenSession,
+                )),
+        )
 }
 
 fn get_export_pcap_group<'a>(
@@ -9,7 +9,7 @@
     ComboBox, Rule, Space, Toggler, Tooltip, button, combo_box, horizontal_space, text_input,
     vertical_space,
 };
-use iced::{Alignment, Font, Length, Padding, Pixels, alignment};
+use iced::{Alignment, Element, Font, Length, Padding, Pixels, alignment};
 
 use crate::chart::types::chart_type::ChartType;
 use crate::gui::components::tab::get_pages_tabs;
@@ -29,6 +29,7 @@
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::report::get_report_entries::get_searched_entries;
+use crate::report::types::export_format::ExportFormat;
 use crate::report::types::report_col::ReportCol;
 use crate::report::types::search_parameters::{FilterInputType, SearchParameters};
 use crate::translations::translations_2::{
@@ -36,6 +37,10 @@
     no_search_results_translation, only_show_favorites_translation, showing_results_translation,
 };
 use crate::translations::translations_3::filter_by_host_translation;
+use crate::translations::translations_4::{
+    blocklisted_host_translation, dns_log_translation, export_results_translation,
+    mpls_labels_translation, vlan_ids_translation,
+};
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, ReportSortType, RunningPage, Sniffer, StyleType};
 
@@ -93,12 +98,17 @@ pub fn inspect_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
             .class(ContainerType::BorderedRound),
         )
         .push(
-            Container::new(col_report)
-                .align_y(Alignment::Center)
-                .align_x(Alignment::Center)
-                .padding(Padding::new(7.0).top(10).bottom(3))
-                .width(1042)
-                .class(ContainerType::BorderedRound),
+            // the table is scrolled horizontally when the window is too narrow to show all its columns
+            Scrollable::with_direction(
+                Container::new(col_report)
+                    .align_y(Alignment::Center)
+                    .align_x(Alignment::Center)
+                    .padding(Padding::new(7.0).top(10).bottom(3))
+                    .width(1358)
+                    .class(ContainerType::BorderedRound),
+                Direction::Horizontal(ScrollbarType::properties()),
+            )
+            .height(Length::Fill),
         );
 
     Container::new(Column::new().push(tab_and_body.push(body))).height(Length::Fill)
@@ -122,12 +132,18 @@ fn report<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
     let end_entry_num = start_entry_num + search_results.len() - 1;
     for report_entry in search_results {
         scroll_report = scroll_report.push(
-            button(row_report_entry(&report_entry.0, &report_entry.1, font))
-                .padding(2)
-                .on_press(Message::ShowModal(MyModal::ConnectionDetails(
-                    report_entry.0,
-                )))
-                .class(ButtonType::Neutral),
+            button(row_report_entry(
+                &report_entry.0,
+                &report_entry.1,
+                sniffer,
+                font,
+                language,
+            ))
+            .padding(2)
+            .on_press(Message::ShowModal(MyModal::ConnectionDetails(
+                report_entry.0,
+            )))
+            .class(ButtonType::Neutral),
         );
     }
     if results_number > 0 {
@@ -208,7 +224,7 @@ fn report_header_row(
             .width(report_col.get_width())
             .height(56)
             .push(title_tooltip);
-        if report_col != ReportCol::Packets && report_col != ReportCol::Bytes {
+        if !report_col.is_sortable() {
             col_header = col_header.push(
                 Container::new(filter_input(
                     report_col.get_filter_input_type(),
@@ -284,7 +300,9 @@ fn sort_arrows<'a>(
 fn row_report_entry<'a>(
     key: &AddressPortPair,
     val: &InfoAddressPortPair,
+    sniffer: &Sniffer,
     font: Font,
+    language: Language,
 ) -> Row<'a, Message, StyleType> {
     let text_type = if val.traffic_direction == TrafficDirection::Outgoing {
         TextType::Outgoing
@@ -296,19 +314,55 @@ fn row_report_entry<'a>(
 
     for report_col in ReportCol::ALL {
         let max_chars = report_col.get_max_chars(None);
-        let col_value = report_col.get_value(key, val);
-        ret_val = ret_val.push(
-            Container::new(
-                Text::new(if col_value.len() <= max_chars {
-                    col_value
-                } else {
-                    [&col_value[..max_chars - 2], "…"].concat()
-                })
-                .font(font)
-                .class(text_type),
+        let mut col_value = report_col.get_value(key, val);
+        let address = match report_col {
+            ReportCol::SrcIp => Some((&key.address1, val.mac_address1.as_deref())),
+            ReportCol::DstIp => Some((&key.address2, val.mac_address2.as_deref())),
+            _ => None,
+        };
+        let mut tooltip_lines = Vec::new();
+        let mut class = text_type;
+        if let Some((address, mac)) = address {
+            // labels are displayed in place of addresses, which are moved to the tooltip
+            if let Some(label) = sniffer.address_label(address, mac) {
+                tooltip_lines.push(col_value);
+                col_value = label.text();
+            }
+            if sniffer.blocklisted_addresses.contains(address) {
+                tooltip_lines.push(blocklisted_host_translation(language).to_string());
+                class = TextType::Danger;
+            }
+        }
+        // wireless details refer to the station sending the frames
+        if report_col == ReportCol::SrcIp
+            && let Some(wifi) = &val.wifi
+        {
+            tooltip_lines.extend(wifi.to_lines(language));
+        }
+        let text = Text::new(if col_value.chars().count() <= max_chars {
+            col_value
+        } else {
+            let clipped: String = col_value.chars().take(max_chars - 2).collect();
+            [&clipped, "…"].concat()
+        })
+        .font(font)
+        .class(class);
+        let content: Element<'a, Message, StyleType> = if tooltip_lines.is_empty() {
+            text.into()
+        } else {
+            Tooltip::new(
+                text,
+                Text::new(tooltip_lines.join("\n")).font(font),
+                Position::FollowCursor,
             )
-            .align_x(Alignment::Center)
-            .width(report_col.get_width()),
+            .padding(5)
+            .class(ContainerType::Tooltip)
+            .into()
+        };
+        ret_val = ret_val.push(
+            Container::new(content)
+                .align_x(Alignment::Center)
+                .width(report_col.get_width()),
         );
     }
     ret_val
@@ -405,6 +459,39 @@ fn host_filters_col<'a>(
         .push(container_country)
         .push(container_as_name);
 
+    // not host-related, but connections are grouped by VLAN and MPLS label on trunk ports
+    let container_vlan = Row::new()
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .push(Text::new(format!("{}:", vlan_ids_translation(language))).font(font))
+        .push(
+            Container::new(filter_input(
+                FilterInputType::Vlan,
+                search_params.clone(),
+                font,
+            ))
+            .width(130),
+        );
+
+    let container_mpls = Row::new()
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .push(Text::new(format!("{}:", mpls_labels_translation(language))).font(font))
+        .push(
+            Container::new(filter_input(
+                FilterInputType::Mpls,
+                search_params.clone(),
+                font,
+            ))
+            .width(130),
+        );
+
+    let col3 = Column::new()
+        .align_x(Alignment::End)
+        .spacing(5)
+        .push(container_vlan)
+        .push(container_mpls);
+
     Column::new()
         .align_x(Alignment::Start)
         .push(title_row)
@@ -414,7 +501,8 @@ fn host_filters_col<'a>(
                 .align_y(Alignment::Center)
                 .spacing(30)
                 .push(col1)
-                .push(col2),
+                .push(col2)
+                .push(col3),
         )
 }
 
@@ -575,6 +663,7 @@ fn get_agglomerates_row<'a>(
         .push(bars)
         .push(bytes_col)
         .push(packets_col)
+        .push(Space::with_width(ReportCol::Rtt.get_width()))
 }
 
 fn get_change_page_row<'a>(
@@ -609,7 +698,51 @@ fn get_change_page_row<'a>(
         } else {
             Container::new(Space::with_width(25))
         })
-        .push(horizontal_space())
+        .push(
+            Container::new(get_export_buttons_row(font, language))
+                .width(Length::Fill)
+                .align_x(Alignment::End),
+        )
+}
+
+fn get_export_buttons_row<'a>(font: Font, language: Language) -> Row<'a, Message, StyleType> {
+    let button_dns_log = button(
+        Text::new(dns_log_translation(language))
+            .font(font)
+            .size(FONT_SIZE_FOOTER)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center),
+    )
+    .padding([2, 5])
+    .height(20)
+    .on_press(Message::ShowModal(MyModal::DnsLog));
+
+    let mut ret_val = Row::new()
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .push(button_dns_log)
+        .push(Space::with_width(10));
+    for format in ExportFormat::ALL {
+        let button = button(
+            Text::new(format.to_string())
+                .font(font)
+                .size(FONT_SIZE_FOOTER)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center),
+        )
+        .padding([2, 5])
+        .height(20)
+        .on_press(Message::ExportSearchResults(format));
+        ret_val = ret_val.push(
+            Tooltip::new(
+                button,
+                Text::new(export_results_translation(language)).font(font),
+                Position::Top,
+            )
+            .class(ContainerType::Tooltip),
+        );
+    }
+    ret_val.push(Space::with_width(5))
 }
 
 fn button_clear_filter<'a>(
@@ -4,8 +4,11 @@
 use crate::gui::components::header::get_button_settings;
 use crate::gui::components::tab::get_pages_tabs;
 use crate::gui::components::types::my_modal::MyModal;
-use crate::gui::pages::overview_page::{get_bars, get_bars_length, host_bar, service_bar};
+use crate::gui::pages::overview_page::{
+    get_bars, get_bars_length, host_bar, label_bar, service_bar,
+};
 use crate::gui::pages::types::settings_page::SettingsPage;
+use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
 use crate::gui::styles::scrollbar::ScrollbarType;
 use crate::gui::styles::style_constants::FONT_SIZE_FOOTER;
@@ -17,8 +20,10 @@
 use crate::networking::types::service::Service;
 use crate::networking::types::traffic_type::TrafficType;
 use crate::notifications::types::logged_notification::{
-    DataThresholdExceeded, FavoriteTransmitted, LoggedNotification,
+    AnomalyDetected, BlocklistMatch, DataThresholdExceeded, FavoriteTransmitted, FirstSeen,
+    LoggedNotification, NotificationKind, ScanDetected,
 };
+use crate::notifications::types::notifications_history::{HISTORY_PAGE_SIZE, HistoryFilter};
 use crate::report::types::sort_type::SortType;
 use crate::translations::translations::{
     bytes_exceeded_translation, clear_all_translation, favorite_transmitted_translation,
@@ -26,25 +31,36 @@
     only_last_30_translation, packets_exceeded_translation, per_second_translation,
     threshold_translation,
 };
+use crate::translations::translations_2::{
+    no_search_results_translation, showing_results_translation,
+};
+use crate::translations::translations_4::{
+    all_kinds_translation, anomaly_detected_translation, baseline_translation,
+    blocklisted_host_translation, current_capture_translation, distinct_hosts_translation,
+    distinct_ports_translation, from_date_translation, new_asn_translation,
+    new_country_translation, new_host_translation, notifications_history_translation,
+    scan_detected_translation, search_translation, to_date_translation, whole_traffic_translation,
+};
 use crate::utils::types::icon::Icon;
 use crate::{ByteMultiple, ConfigSettings, Language, RunningPage, Sniffer, StyleType};
 use iced::Length::FillPortion;
 use iced::widget::scrollable::Direction;
 use iced::widget::text::LineHeight;
 use iced::widget::tooltip::Position;
-use iced::widget::{Column, Container, Row, Rule, Scrollable, Text, Tooltip, horizontal_space};
+use iced::widget::{
+    Column, Container, Row, Rule, Scrollable, Text, TextInput, Tooltip, horizontal_space,
+};
 use iced::widget::{Space, button, vertical_space};
-use iced::{Alignment, Font, Length, Padding};
+use iced::{Alignment, Element, Font, Length, Padding};
 use std::cmp::max;
+use std::path::Path;
 
 /// Computes the body of gui notifications page
 pub fn notifications_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
     let ConfigSettings {
-        style,
-        language,
-        notifications,
-        ..
+        style, language, ..
     } = sniffer.configs.settings;
+    let notifications = &sniffer.configs.settings.notifications;
     let font = style.get_extension().font;
     let font_headers = style.get_extension().font_headers;
 
@@ -60,10 +76,23 @@ pub fn notifications_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
         sniffer.unread_notifications,
     );
 
-    tab_and_body = tab_and_body.push(tabs);
+    tab_and_body = tab_and_body
+        .push(tabs)
+        .push(history_switch(
+            sniffer.notifications_history.is_loaded(),
+            font,
+            language,
+        ))
+        .push(Space::with_height(5));
 
-    if notifications.data_notification.threshold.is_none()
+    if sniffer.notifications_history.is_loaded() {
+        tab_and_body = tab_and_body.push(history_body(sniffer, font, language));
+    } else if notifications.data_notification.threshold.is_none()
         && !notifications.favorite_notification.notify_on_favorite
+        && !notifications.first_seen_notification.is_active()
+        && !notifications.anomaly_notification.notify_on_anomaly
+        && !notifications.scan_notification.notify_on_scan
+        && !notifications.blocklist_notification.notify_on_blocklisted
         && sniffer.logged_notifications.0.is_empty()
     {
         let body = body_no_notifications_set(font, language);
@@ -104,6 +133,274 @@ pub fn notifications_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
     Container::new(Column::new().push(tab_and_body)).height(Length::Fill)
 }
 
+fn history_switch<'a>(
+    show_history: bool,
+    font: Font,
+    language: Language,
+) -> Row<'a, Message, StyleType> {
+    let switch_button = |label: &'static str, is_active: bool, show: bool| {
+        button(
+            Text::new(label)
+                .size(FONT_SIZE_FOOTER)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .font(font),
+        )
+        .padding(Padding::ZERO.left(15).right(15))
+        .height(25)
+        .class(if is_active {
+            ButtonType::BorderedRoundSelected
+        } else {
+            ButtonType::BorderedRound
+        })
+        .on_press(Message::ShowNotificationsHistory(show))
+    };
+    Row::new()
+        .padding(Padding::ZERO.top(10))
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(switch_button(
+            current_capture_translation(language),
+            !show_history,
+            false,
+        ))
+        .push(switch_button(
+            notifications_history_translation(language),
+            show_history,
+            true,
+        ))
+}
+
+fn history_body<'a>(
+    sniffer: &Sniffer,
+    font: Font,
+    language: Language,
+) -> Column<'a, Message, StyleType> {
+    let filter = &sniffer.history_filter;
+    let (entries, tot) = sniffer.notifications_history.search(filter, language);
+
+    let mut entries_col = Column::new()
+        .padding(Padding::ZERO.right(15))
+        .spacing(10)
+        .align_x(Alignment::Center);
+    for entry in &entries {
+        entries_col = entries_col.push(history_entry(entry, font, language));
+    }
+
+    let body: Element<'a, Message, StyleType> = if entries.is_empty() {
+        Column::new()
+            .push(vertical_space())
+            .push(Text::new(no_search_results_translation(language)).font(font))
+            .push(Space::with_height(FillPortion(2)))
+            .into()
+    } else {
+        Scrollable::with_direction(
+            entries_col,
+            Direction::Vertical(ScrollbarType::properties()),
+        )
+        .height(Length::Fill)
+        .into()
+    };
+
+    let start_entry = filter.page * HISTORY_PAGE_SIZE;
+    Column::new()
+        .padding(Padding::new(10.0).bottom(0))
+        .spacing(10)
+        .width(Length::Fixed(850.0))
+        .align_x(Alignment::Center)
+        .push(history_filter_inputs(filter, font, language))
+        .push(history_kind_buttons(filter, font, language))
+        .push(body)
+        .push_maybe((tot > 0).then(|| {
+            history_page_row(
+                filter,
+                start_entry + 1,
+                start_entry + entries.len(),
+                tot,
+                font,
+                language,
+            )
+        }))
+}
+
+fn history_filter_inputs<'a>(
+    filter: &HistoryFilter,
+    font: Font,
+    language: Language,
+) -> Row<'a, Message, StyleType> {
+    let input =
+        |placeholder: &str, value: &str, width: Length, update: fn(&mut HistoryFilter, String)| {
+            let filter = filter.clone();
+            TextInput::new(placeholder, value)
+                .on_input(move |new_value| {
+                    let mut new_filter = HistoryFilter {
+                        page: 0,
+                        ..filter.clone()
+                    };
+                    update(&mut new_filter, new_value);
+                    Message::SearchNotificationsHistory(new_filter)
+                })
+                .padding([2, 5])
+                .size(FONT_SIZE_FOOTER)
+                .font(font)
+                .width(width)
+        };
+    Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(input(
+            search_translation(language),
+            &filter.text,
+            Length::Fill,
+            |f, v| f.text = v,
+        ))
+        .push(Text::new(format!("{}:", from_date_translation(language))).font(font))
+        .push(input(
+            "YYYY/MM/DD",
+            &filter.from,
+            Length::Fixed(110.0),
+            |f, v| f.from = v,
+        ))
+        .push(Text::new(format!("{}:", to_date_translation(language))).font(font))
+        .push(input(
+            "YYYY/MM/DD",
+            &filter.to,
+            Length::Fixed(110.0),
+            |f, v| f.to = v,
+        ))
+}
+
+fn history_kind_buttons<'a>(
+    filter: &HistoryFilter,
+    font: Font,
+    language: Language,
+) -> Row<'a, Message, StyleType> {
+    let mut ret_val = Row::new().spacing(5).align_y(Alignment::Center);
+    for kind in std::iter::once(None).chain(NotificationKind::ALL.into_iter().map(Some)) {
+        let label = kind.map_or_else(
+            || all_kinds_translation(language),
+            |k| k.get_label(language),
+        );
+        ret_val = ret_val.push(
+            button(
+                Text::new(label)
+                    .size(FONT_SIZE_FOOTER)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .font(font),
+            )
+            .padding(Padding::ZERO.left(10).right(10))
+            .height(25)
+            .class(if filter.kind == kind {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::SearchNotificationsHistory(HistoryFilter {
+                kind,
+                page: 0,
+                ..filter.clone()
+            })),
+        );
+    }
+    ret_val
+}
+
+fn history_entry<'a>(
+    entry: &LoggedNotification,
+    font: Font,
+    language: Language,
+) -> Container<'a, Message, StyleType> {
+    let content = Row::new()
+        .spacing(20)
+        .align_y(Alignment::Center)
+        .push(
+            Row::new()
+                .width(180)
+                .spacing(8)
+                .push(Icon::Clock.to_text())
+                .push(Text::new(entry.timestamp().to_string()).font(font)),
+        )
+        .push(
+            Column::new()
+                .spacing(5)
+                .width(Length::Fill)
+                .push(
+                    Text::new(entry.title(language))
+                        .class(TextType::Title)
+                        .font(font),
+                )
+                .push(
+                    Text::new(entry.description(language))
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font),
+                ),
+        );
+    Container::new(content)
+        .width(Length::Fill)
+        .padding(10)
+        .class(ContainerType::BorderedRound)
+}
+
+fn history_page_row<'a>(
+    filter: &HistoryFilter,
+    start_entry_num: usize,
+    end_entry_num: usize,
+    results_number: usize,
+    font: Font,
+    language: Language,
+) -> Row<'a, Message, StyleType> {
+    let page_button = |increment: bool| {
+        let page = if increment {
+            filter.page + 1
+        } else {
+            filter.page.saturating_sub(1)
+        };
+        button(
+            if increment {
+                Icon::ArrowRight
+            } else {
+                Icon::ArrowLeft
+            }
+            .to_text()
+            .size(8.0)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center),
+        )
+        .padding(2)
+        .height(20)
+        .width(25)
+        .on_press(Message::SearchNotificationsHistory(HistoryFilter {
+            page,
+            ..filter.clone()
+        }))
+    };
+    Row::new()
+        .height(40)
+        .align_y(Alignment::Center)
+        .spacing(10)
+        .push(if filter.page > 0 {
+            Container::new(page_button(false))
+        } else {
+            Container::new(Space::with_width(25))
+        })
+        .push(
+            Text::new(showing_results_translation(
+                language,
+                start_entry_num,
+                end_entry_num,
+                results_number,
+            ))
+            .font(font),
+        )
+        .push(if end_entry_num < results_number {
+            Container::new(page_button(true))
+        } else {
+            Container::new(Space::with_width(25))
+        })
+}
+
 fn body_no_notifications_set<'a>(font: Font, language: Language) -> Column<'a, Message, StyleType> {
     Column::new()
         .padding(5)
@@ -197,7 +494,13 @@ fn data_notification_log<'a>(
                         .class(TextType::Subtitle)
                         .size(FONT_SIZE_FOOTER)
                         .font(font),
-                ),
+                )
+                .push_maybe(logged_notification.scope.as_ref().map(|scope| {
+                    Text::new(format!("{}: {}", scope.get_label(language), scope.value()))
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font)
+                })),
         )
         .push(threshold_bar(
             logged_notification,
@@ -228,6 +531,7 @@ fn favorite_notification_log<'a>(
 ) -> Container<'a, Message, StyleType> {
     let host_bar = host_bar(
         &logged_notification.host,
+        None,
         &logged_notification.data_info_host,
         chart_type,
         first_entry_data_info,
@@ -268,6 +572,258 @@ fn favorite_notification_log<'a>(
         .class(ContainerType::BorderedRound)
 }
 
+fn first_seen_notification_log<'a>(
+    logged_notification: &FirstSeen,
+    title: &'static str,
+    icon: Icon,
+    first_entry_data_info: DataInfo,
+    chart_type: ChartType,
+    language: Language,
+    font: Font,
+) -> Container<'a, Message, StyleType> {
+    let host_bar = host_bar(
+        &logged_notification.host,
+        None,
+        &logged_notification.data_info_host,
+        chart_type,
+        first_entry_data_info,
+        font,
+        language,
+    );
+
+    let content = Row::new()
+        .spacing(30)
+        .align_y(Alignment::Center)
+        .push(
+            icon.to_text()
+                .size(80)
+                .line_height(LineHeight::Relative(1.0)),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(8)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp.clone()).font(font)),
+                )
+                .push(Text::new(title).class(TextType::Title).font(font))
+                .push(
+                    Text::new(logged_notification.address.to_string())
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font),
+                ),
+        )
+        .push(host_bar);
+
+    Container::new(content)
+        .width(Length::Fill)
+        .padding(15)
+        .class(ContainerType::BorderedRound)
+}
+
+fn blocklist_notification_log<'a>(
+    logged_notification: &BlocklistMatch,
+    first_entry_data_info: DataInfo,
+    chart_type: ChartType,
+    language: Language,
+    font: Font,
+) -> Container<'a, Message, StyleType> {
+    let host_bar = host_bar(
+        &logged_notification.host,
+        None,
+        &logged_notification.data_info_host,
+        chart_type,
+        first_entry_data_info,
+        font,
+        language,
+    );
+    // the file name is enough to tell the selected lists apart
+    let blocklist = Path::new(&logged_notification.blocklist)
+        .file_name()
+        .map_or(logged_notification.blocklist.clone(), |name| {
+            name.to_string_lossy().to_string()
+        });
+
+    let content = Row::new()
+        .spacing(30)
+        .align_y(Alignment::Center)
+        .push(
+            Icon::Forbidden
+                .to_text()
+                .size(80)
+                .line_height(LineHeight::Relative(1.0)),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(8)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp.clone()).font(font)),
+                )
+                .push(
+                    Text::new(blocklisted_host_translation(language))
+                        .class(TextType::Title)
+                        .font(font),
+                )
+                .push(
+                    Text::new(logged_notification.address.to_string())
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font),
+                )
+                .push(
+                    Text::new(blocklist)
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font),
+                ),
+        )
+        .push(host_bar);
+
+    Container::new(content)
+        .width(Length::Fill)
+        .padding(15)
+        .class(ContainerType::BorderedRound)
+}
+
+fn anomaly_notification_log<'a>(
+    logged_notification: &AnomalyDetected,
+    first_entry_data_info: DataInfo,
+    language: Language,
+    font: Font,
+) -> Container<'a, Message, StyleType> {
+    // anomalies are always detected on bytes
+    let label = match &logged_notification.service {
+        Some(service) => service.to_string(),
+        None => whole_traffic_translation(language).to_string(),
+    };
+    let traffic_bar = label_bar(
+        label,
+        &logged_notification.data_info,
+        ChartType::Bytes,
+        first_entry_data_info,
+        font,
+    );
+    let baseline_str = format!(
+        "{}: {} {} (z = {:.1})",
+        baseline_translation(language),
+        ByteMultiple::formatted_string(logged_notification.baseline_bytes),
+        per_second_translation(language),
+        logged_notification.z_score
+    );
+
+    let content = Row::new()
+        .spacing(30)
+        .align_y(Alignment::Center)
+        .push(
+            Icon::Waves
+                .to_text()
+                .size(80)
+                .line_height(LineHeight::Relative(1.0)),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(8)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp.clone()).font(font)),
+                )
+                .push(
+                    Text::new(anomaly_detected_translation(language))
+                        .class(TextType::Title)
+                        .font(font),
+                )
+                .push(
+                    Text::new(baseline_str)
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font),
+                ),
+        )
+        .push(traffic_bar);
+
+    Container::new(content)
+        .width(Length::Fill)
+        .padding(15)
+        .class(ContainerType::BorderedRound)
+}
+
+fn scan_notification_log<'a>(
+    logged_notification: &ScanDetected,
+    language: Language,
+    font: Font,
+) -> Container<'a, Message, StyleType> {
+    let list = |label: &'static str, count: usize, list: String| {
+        Text::new(format!("{label} ({count}): {list}"))
+            .size(FONT_SIZE_FOOTER)
+            .font(font)
+    };
+
+    let content = Row::new()
+        .spacing(30)
+        .align_y(Alignment::Center)
+        .push(
+            Icon::Warning
+                .to_text()
+                .size(80)
+                .line_height(LineHeight::Relative(1.0)),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(8)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp.clone()).font(font)),
+                )
+                .push(
+                    Text::new(scan_detected_translation(language))
+                        .class(TextType::Title)
+                        .font(font),
+                )
+                .push(
+                    Text::new(logged_notification.address.to_string())
+                        .class(TextType::Subtitle)
+                        .size(FONT_SIZE_FOOTER)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push_maybe((!logged_notification.ports.is_empty()).then(|| {
+                    list(
+                        distinct_ports_translation(language),
+                        logged_notification.ports.len(),
+                        logged_notification.ports_list(),
+                    )
+                }))
+                .push(list(
+                    distinct_hosts_translation(language),
+                    logged_notification.hosts.len(),
+                    logged_notification.hosts_list(),
+                )),
+        );
+
+    Container::new(content)
+        .width(Length::Fill)
+        .padding(15)
+        .class(ContainerType::BorderedRound)
+}
+
 fn get_button_clear_all<'a>(font: Font, language: Language) -> Tooltip<'a, Message, StyleType> {
     let content = button(
         Icon::Bin
@@ -328,6 +884,46 @@ fn logged_notifications<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType>
                     font,
                 )
             }
+            LoggedNotification::NewHost(first_seen) => first_seen_notification_log(
+                first_seen,
+                new_host_translation(language),
+                Icon::News,
+                first_entry_data_info,
+                chart_type,
+                language,
+                font,
+            ),
+            LoggedNotification::NewAsn(first_seen) => first_seen_notification_log(
+                first_seen,
+                new_asn_translation(language),
+                Icon::News,
+                first_entry_data_info,
+                chart_type,
+                language,
+                font,
+            ),
+            LoggedNotification::NewCountry(first_seen) => first_seen_notification_log(
+                first_seen,
+                new_country_translation(language),
+                Icon::News,
+                first_entry_data_info,
+                chart_type,
+                language,
+                font,
+            ),
+            LoggedNotification::AnomalyDetected(anomaly_detected) => {
+                anomaly_notification_log(anomaly_detected, first_entry_data_info, language, font)
+            }
+            LoggedNotification::ScanDetected(scan_detected) => {
+                scan_notification_log(scan_detected, language, font)
+            }
+            LoggedNotification::Blocklisted(blocklist_match) => blocklist_notification_log(
+                blocklist_match,
+                first_entry_data_info,
+                chart_type,
+                language,
+                font,
+            ),
         });
     }
     ret_val
@@ -423,6 +1019,7 @@ fn data_notification_extra<'a>(
     for (host, data_info_host) in &logged_notification.hosts {
         let host_bar = host_bar(
             host,
+            None,
             data_info_host,
             logged_notification.chart_type,
             first_data_info_host,
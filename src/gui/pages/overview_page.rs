@@ -21,8 +21,11 @@
 use crate::networking::types::data_info_host::DataInfoHost;
 use crate::networking::types::filters::Filters;
 use crate::networking::types::host::Host;
+use crate::networking::types::host_label::HostLabel;
 use crate::networking::types::service::Service;
-use crate::report::get_report_entries::{get_host_entries, get_service_entries};
+use crate::report::get_report_entries::{
+    get_application_entries, get_host_entries, get_service_entries,
+};
 use crate::report::types::search_parameters::SearchParameters;
 use crate::report::types::sort_type::SortType;
 use crate::translations::translations::{
@@ -35,7 +38,10 @@
     only_top_30_items_translation,
 };
 use crate::translations::translations_3::{service_translation, unsupported_link_type_translation};
-use crate::translations::translations_4::{excluded_translation, reading_from_pcap_translation};
+use crate::translations::translations_4::{
+    application_translation, blocklisted_host_translation, excluded_translation,
+    network_adapters_translation, reading_from_pcap_translation, vlan_ids_translation,
+};
 use crate::utils::formatted_strings::get_active_filters_string;
 use crate::utils::types::icon::Icon;
 use crate::{ByteMultiple, ChartType, ConfigSettings, Language, RunningPage, StyleType};
@@ -222,7 +228,7 @@ fn row_report<'a>(sniffer: &Sniffer) -> Row<'a, Message, StyleType> {
     let col_host = col_host(sniffer);
     let col_service = col_service(sniffer);
 
-    Row::new()
+    let mut ret_val = Row::new()
         .spacing(10)
         .push(
             Container::new(col_host)
@@ -237,7 +243,41 @@ fn row_report<'a>(sniffer: &Sniffer) -> Row<'a, Message, StyleType> {
                 .height(Length::Fill)
                 .padding(Padding::new(10.0).top(0).bottom(5))
                 .class(ContainerType::BorderedRound),
-        )
+        );
+
+    if sniffer.info_traffic.interfaces.len() > 1 {
+        ret_val = ret_val.push(
+            Container::new(col_interface(sniffer))
+                .width(Length::FillPortion(2))
+                .height(Length::Fill)
+                .padding(Padding::new(10.0).top(0).bottom(5))
+                .class(ContainerType::BorderedRound),
+        );
+    }
+
+    // VLANs are only shown when tagged traffic is observed (e.g., on trunk ports)
+    if !sniffer.info_traffic.vlans.is_empty() {
+        ret_val = ret_val.push(
+            Container::new(col_vlan(sniffer))
+                .width(Length::FillPortion(2))
+                .height(Length::Fill)
+                .padding(Padding::new(10.0).top(0).bottom(5))
+                .class(ContainerType::BorderedRound),
+        );
+    }
+
+    // applications are only available when connections can be attributed to local processes
+    if !sniffer.info_traffic.applications.is_empty() {
+        ret_val = ret_val.push(
+            Container::new(col_application(sniffer))
+                .width(Length::FillPortion(2))
+                .height(Length::Fill)
+                .padding(Padding::new(10.0).top(0).bottom(5))
+                .class(ContainerType::BorderedRound),
+        );
+    }
+
+    ret_val
 }
 
 fn col_host<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
@@ -262,6 +302,7 @@ fn col_host<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
 
         let host_bar = host_bar(
             host,
+            sniffer.labeled_hosts.get(host),
             data_info_host,
             chart_type,
             first_entry_data_info,
@@ -381,8 +422,186 @@ fn col_service<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
         )
 }
 
+fn col_application<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.settings;
+    let font = style.get_extension().font;
+    let chart_type = sniffer.traffic_chart.chart_type;
+
+    let mut scroll_application = Column::new()
+        .padding(Padding::ZERO.right(11.0))
+        .align_x(Alignment::Center);
+    let entries = get_application_entries(
+        &sniffer.info_traffic,
+        chart_type,
+        sniffer.application_sort_type,
+    );
+    let first_entry_data_info = entries
+        .iter()
+        .map(|(_, d)| *d)
+        .max_by(|d1, d2| d1.compare(d2, SortType::Ascending, chart_type))
+        .unwrap_or_default();
+
+    for (application, data_info) in &entries {
+        let content = label_bar(
+            application.clone(),
+            data_info,
+            chart_type,
+            first_entry_data_info,
+            font,
+        );
+
+        scroll_application = scroll_application.push(
+            Container::new(content)
+                .padding(Padding::new(5.0).right(15).left(10))
+                .width(Length::Fill),
+        );
+    }
+
+    if entries.len() >= 30 {
+        scroll_application = scroll_application.push(Space::with_height(25)).push(
+            Text::new(only_top_30_items_translation(language))
+                .font(font)
+                .align_x(Alignment::Center),
+        );
+    }
+
+    Column::new()
+        .push(
+            Row::new()
+                .height(45)
+                .align_y(Alignment::Center)
+                .push(
+                    Text::new(application_translation(language))
+                        .font(font)
+                        .class(TextType::Title)
+                        .size(FONT_SIZE_TITLE),
+                )
+                .push(horizontal_space())
+                .push(sort_arrows(
+                    sniffer.application_sort_type,
+                    Message::ApplicationSortSelection,
+                )),
+        )
+        .push(
+            Scrollable::with_direction(
+                scroll_application,
+                Direction::Vertical(ScrollbarType::properties()),
+            )
+            .width(Length::Fill),
+        )
+}
+
+fn col_interface<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.settings;
+    let font = style.get_extension().font;
+    let chart_type = sniffer.traffic_chart.chart_type;
+
+    let mut scroll_interface = Column::new()
+        .padding(Padding::ZERO.right(11.0))
+        .align_x(Alignment::Center);
+    // sorted by name, to keep each adapter in the same position
+    let mut entries: Vec<(&String, &DataInfo)> = sniffer.info_traffic.interfaces.iter().collect();
+    entries.sort_by_key(|(name, _)| *name);
+    let first_entry_data_info = entries
+        .iter()
+        .map(|(_, d)| **d)
+        .max_by(|d1, d2| d1.compare(d2, SortType::Ascending, chart_type))
+        .unwrap_or_default();
+
+    for (interface, data_info) in entries {
+        let content = label_bar(
+            interface.clone(),
+            data_info,
+            chart_type,
+            first_entry_data_info,
+            font,
+        );
+
+        scroll_interface = scroll_interface.push(
+            Container::new(content)
+                .padding(Padding::new(5.0).right(15).left(10))
+                .width(Length::Fill),
+        );
+    }
+
+    Column::new()
+        .push(
+            Row::new().height(45).align_y(Alignment::Center).push(
+                Text::new(network_adapters_translation(language))
+                    .font(font)
+                    .class(TextType::Title)
+                    .size(FONT_SIZE_TITLE),
+            ),
+        )
+        .push(
+            Scrollable::with_direction(
+                scroll_interface,
+                Direction::Vertical(ScrollbarType::properties()),
+            )
+            .width(Length::Fill),
+        )
+}
+
+fn col_vlan<'a>(sniffer: &Sniffer) -> Column<'a, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.settings;
+    let font = style.get_extension().font;
+    let chart_type = sniffer.traffic_chart.chart_type;
+
+    let mut scroll_vlan = Column::new()
+        .padding(Padding::ZERO.right(11.0))
+        .align_x(Alignment::Center);
+    // sorted by ID, to keep each VLAN in the same position
+    let mut entries: Vec<(&u16, &DataInfo)> = sniffer.info_traffic.vlans.iter().collect();
+    entries.sort_by_key(|(vlan_id, _)| *vlan_id);
+    let first_entry_data_info = entries
+        .iter()
+        .map(|(_, d)| **d)
+        .max_by(|d1, d2| d1.compare(d2, SortType::Ascending, chart_type))
+        .unwrap_or_default();
+
+    for (vlan_id, data_info) in entries {
+        let content = label_bar(
+            vlan_id.to_string(),
+            data_info,
+            chart_type,
+            first_entry_data_info,
+            font,
+        );
+
+        scroll_vlan = scroll_vlan.push(
+            Container::new(content)
+                .padding(Padding::new(5.0).right(15).left(10))
+                .width(Length::Fill),
+        );
+    }
+
+    Column::new()
+        .push(
+            Row::new().height(45).align_y(Alignment::Center).push(
+                Text::new(vlan_ids_translation(language))
+                    .font(font)
+                    .class(TextType::Title)
+                    .size(FONT_SIZE_TITLE),
+            ),
+        )
+        .push(
+            Scrollable::with_direction(
+                scroll_vlan,
+                Direction::Vertical(ScrollbarType::properties()),
+            )
+            .width(Length::Fill),
+        )
+}
+
 pub fn host_bar<'a>(
     host: &Host,
+    label: Option<&HostLabel>,
     data_info_host: &DataInfoHost,
     chart_type: ChartType,
     first_entry_data_info: DataInfo,
@@ -411,6 +630,11 @@ pub fn host_bar<'a>(
                 .spacing(1)
                 .push(
                     Row::new()
+                        .push_maybe(label.map(|label| {
+                            Text::new(format!("{} - ", label.text()))
+                                .font(font)
+                                .class(TextType::Title)
+                        }))
                         .push(Text::new(host.domain.clone()).font(font))
                         .push(
                             Text::new(if host.asn.name.is_empty() {
@@ -420,6 +644,11 @@ pub fn host_bar<'a>(
                             })
                             .font(font),
                         )
+                        .push_maybe(
+                            data_info_host
+                                .is_blocklisted
+                                .then(|| blocklisted_badge(font, language)),
+                        )
                         .push(horizontal_space())
                         .push(
                             Text::new(if chart_type.eq(&ChartType::Packets) {
@@ -434,12 +663,45 @@ pub fn host_bar<'a>(
         )
 }
 
+/// Warning icon displayed next to the hosts matching the blocklists
+pub fn blocklisted_badge<'a>(font: Font, language: Language) -> Tooltip<'a, Message, StyleType> {
+    Tooltip::new(
+        Container::new(
+            Icon::Forbidden
+                .to_text()
+                .class(TextType::Danger)
+                .line_height(LineHeight::Relative(1.0)),
+        )
+        .padding(Padding::ZERO.left(7)),
+        Text::new(blocklisted_host_translation(language)).font(font),
+        Position::FollowCursor,
+    )
+    .padding(5)
+    .class(ContainerType::Tooltip)
+}
+
 pub fn service_bar<'a>(
     service: &Service,
     data_info: &DataInfo,
     chart_type: ChartType,
     first_entry_data_info: DataInfo,
     font: Font,
+) -> Row<'a, Message, StyleType> {
+    label_bar(
+        service.to_string(),
+        data_info,
+        chart_type,
+        first_entry_data_info,
+        font,
+    )
+}
+
+pub fn label_bar<'a>(
+    label: String,
+    data_info: &DataInfo,
+    chart_type: ChartType,
+    first_entry_data_info: DataInfo,
+    font: Font,
 ) -> Row<'a, Message, StyleType> {
     let (incoming_bar_len, outgoing_bar_len) =
         get_bars_length(chart_type, &first_entry_data_info, data_info);
@@ -453,7 +715,7 @@ pub fn service_bar<'a>(
                 .spacing(1)
                 .push(
                     Row::new()
-                        .push(Text::new(service.to_string()).font(font))
+                        .push(Text::new(label).font(font))
                         .push(horizontal_space())
                         .push(
                             Text::new(if chart_type.eq(&ChartType::Packets) {
@@ -474,7 +736,12 @@ fn col_info<'a>(sniffer: &Sniffer) -> Container<'a, Message, StyleType> {
     } = sniffer.configs.settings;
     let PaletteExtension { font, .. } = style.get_extension();
 
-    let col_device = col_device(language, font, &sniffer.capture_source);
+    let col_device = col_device(
+        language,
+        font,
+        &sniffer.capture_source,
+        &sniffer.additional_devices,
+    );
 
     let col_data_representation =
         col_data_representation(language, font, sniffer.traffic_chart.chart_type);
@@ -534,12 +801,19 @@ fn col_device<'a>(
     language: Language,
     font: Font,
     cs: &CaptureSource,
+    additional_devices: &[String],
 ) -> Column<'a, Message, StyleType> {
     let link_type = cs.get_link_type();
     #[cfg(not(target_os = "windows"))]
-    let cs_info = cs.get_name();
+    let mut cs_info = cs.get_name();
     #[cfg(target_os = "windows")]
-    let cs_info = cs.get_desc().unwrap_or(cs.get_name());
+    let mut cs_info = cs.get_desc().unwrap_or(cs.get_name());
+    if matches!(cs, CaptureSource::Device(_)) {
+        for name in additional_devices {
+            cs_info.push('\n');
+            cs_info.push_str(name);
+        }
+    }
 
     Column::new()
         .height(Length::Fill)
@@ -1,7 +1,9 @@
+use iced::widget::scrollable::Direction;
 use iced::widget::text::LineHeight;
 use iced::widget::tooltip::Position;
 use iced::widget::{
-    Column, Container, PickList, Row, Rule, Slider, Space, Text, Tooltip, button, vertical_space,
+    Button, Column, Container, PickList, Row, Rule, Scrollable, Slider, Space, Text, TextInput,
+    Tooltip, button, vertical_space,
 };
 use iced::{Alignment, Font, Length, Padding};
 
@@ -11,16 +13,26 @@
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
 use crate::gui::styles::style_constants::FONT_SIZE_SUBTITLE;
 use crate::gui::styles::text::TextType;
+use crate::gui::styles::text_input::TextInputType;
 use crate::gui::types::message::Message;
 use crate::mmdb::types::mmdb_reader::{MmdbReader, MmdbReaders};
+use crate::networking::types::blocklist::Blocklists;
+use crate::networking::types::host::Host;
+use crate::networking::types::host_label::HostLabel;
 use crate::translations::translations::language_translation;
 use crate::translations::translations_2::country_translation;
 use crate::translations::translations_3::{
     mmdb_files_translation, params_not_editable_translation, zoom_translation,
 };
-use crate::translations::translations_4::share_feedback_translation;
+use crate::translations::translations_4::{
+    blocklists_translation, entries_translation, export_favorites_translation,
+    favorite_hosts_translation, group_translation, host_labels_translation,
+    import_favorites_translation, label_target_translation, name_translation,
+    share_feedback_translation, unreadable_file_translation,
+};
 use crate::utils::formatted_strings::get_path_termination_string;
 use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::icon::Icon;
@@ -48,7 +60,10 @@ pub fn settings_general_page(sniffer: &Sniffer) -> Container<Message, StyleType>
         ))
         .push(get_settings_tabs(SettingsPage::General, font, language))
         .push(Space::with_height(10))
-        .push(column_all_general_setting(sniffer, font));
+        .push(Scrollable::with_direction(
+            column_all_general_setting(sniffer, font),
+            Direction::Vertical(ScrollbarType::properties()),
+        ));
 
     Container::new(content)
         .height(400)
@@ -83,13 +98,38 @@ fn column_all_general_setting(sniffer: &Sniffer, font: Font) -> Column<Message,
             .push(Space::with_height(10));
     }
 
-    column = column.push(mmdb_settings(
-        is_editable,
+    column = column.push(
+        Row::new()
+            .align_y(Alignment::Start)
+            .push(mmdb_settings(
+                is_editable,
+                language,
+                font,
+                &mmdb_country,
+                &mmdb_asn,
+                &sniffer.mmdb_readers,
+            ))
+            .push(Rule::vertical(25))
+            .push(blocklists_settings(
+                &sniffer.configs.settings.blocklists,
+                &sniffer.blocklists,
+                language,
+                font,
+            )),
+    );
+
+    column = column.push(Rule::horizontal(25)).push(host_labels_settings(
+        &sniffer.configs.settings.host_labels,
+        &sniffer.host_label_input,
+        language,
+        font,
+    ));
+
+    column = column.push(Rule::horizontal(25)).push(favorites_settings(
+        &sniffer.configs.favorites.hosts,
+        sniffer.favorites_import_failed,
         language,
         font,
-        &mmdb_country,
-        &mmdb_asn,
-        &sniffer.mmdb_readers,
     ));
 
     column
@@ -344,3 +384,236 @@ fn button_clear_mmdb<'a>(
 
     Tooltip::new(button, "", Position::Right)
 }
+
+fn blocklists_settings<'a>(
+    paths: &[String],
+    blocklists: &Blocklists,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let mut lists_col = Column::new().spacing(5).align_x(Alignment::Center);
+    for (i, path) in paths.iter().enumerate() {
+        let mut row = Row::new()
+            .align_y(Alignment::Center)
+            .spacing(5)
+            .push(Text::new(get_path_termination_string(path, 25)).font(font));
+        // the info is missing while the file is still being loaded
+        if let Some(blocklist) = blocklists.lists().iter().find(|list| &list.path == path) {
+            let (info, info_class) = if blocklist.is_error {
+                (
+                    unreadable_file_translation(language).to_string(),
+                    TextType::Danger,
+                )
+            } else {
+                (
+                    format!(
+                        "{} {}",
+                        blocklist.entries_count(),
+                        entries_translation(language)
+                    ),
+                    TextType::Subtitle,
+                )
+            };
+            row = row.push(Text::new(format!("({info})")).font(font).class(info_class));
+        }
+        lists_col = lists_col.push(row.push(button_remove(Message::RemoveBlocklist(i), font)));
+    }
+
+    Column::new()
+        .spacing(5)
+        .align_x(Alignment::Center)
+        .push(
+            Text::new(blocklists_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(
+            Scrollable::with_direction(lists_col, Direction::Vertical(ScrollbarType::properties()))
+                .height(Length::Shrink),
+        )
+        .push(button_open_file(
+            String::new(),
+            FileInfo::Blocklist,
+            language,
+            font,
+            true,
+            Message::AddBlocklist,
+        ))
+}
+
+fn favorites_settings<'a>(
+    favorites: &[Host],
+    import_failed: bool,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let mut hosts_col = Column::new().spacing(5);
+    for host in favorites {
+        let asn = if host.asn.name.is_empty() {
+            String::new()
+        } else {
+            format!("{} ({})", host.asn.name, host.asn.code)
+        };
+        hosts_col = hosts_col.push(
+            Row::new()
+                .align_y(Alignment::Center)
+                .spacing(10)
+                .push(Text::new(host.domain.clone()).font(font).width(300))
+                .push(
+                    Text::new(asn)
+                        .font(font)
+                        .class(TextType::Subtitle)
+                        .width(Length::Fill),
+                )
+                .push(button_remove(
+                    Message::AddOrRemoveFavorite(host.clone(), false),
+                    font,
+                )),
+        );
+    }
+
+    let text_button = |text: &'static str, message: Option<Message>| {
+        button(Text::new(text).font(font).align_x(Alignment::Center))
+            .padding([2, 15])
+            .class(ButtonType::BorderedRound)
+            .on_press_maybe(message)
+    };
+
+    Column::new()
+        .spacing(5)
+        .align_x(Alignment::Center)
+        .push(
+            Text::new(favorite_hosts_translation(language))
+                .font(font)
+                .class(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(
+            Scrollable::with_direction(hosts_col, Direction::Vertical(ScrollbarType::properties()))
+                .height(Length::Shrink),
+        )
+        .push(
+            Row::new()
+                .align_y(Alignment::Center)
+                .spacing(10)
+                .push(text_button(
+                    import_favorites_translation(language),
+                    Some(Message::OpenFile(
+                        String::new(),
+                        FileInfo::Favorites,
+                        Message::ImportFavorites,
+                    )),
+                ))
+                .push(text_button(
+                    export_favorites_translation(language),
+                    (!favorites.is_empty()).then_some(Message::ExportFavorites),
+                )),
+        )
+        .push_maybe(import_failed.then(|| {
+            Text::new(unreadable_file_translation(language))
+                .font(font)
+                .class(TextType::Danger)
+        }))
+}
+
+fn button_remove<'a>(message: Message, font: Font) -> Button<'a, Message, StyleType> {
+    button(
+        Text::new("×")
+            .font(font)
+            .align_y(Alignment::Center)
+            .align_x(Alignment::Center)
+            .size(15)
+            .line_height(LineHeight::Relative(1.0)),
+    )
+    .padding(2)
+    .height(20)
+    .width(20)
+    .on_press(message)
+}
+
+fn host_labels_settings<'a>(
+    host_labels: &[HostLabel],
+    host_label_input: &HostLabel,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let mut ret_val = Column::new().spacing(5).align_x(Alignment::Center).push(
+        Text::new(host_labels_translation(language))
+            .font(font)
+            .class(TextType::Subtitle)
+            .size(FONT_SIZE_SUBTITLE),
+    );
+
+    for (i, host_label) in host_labels.iter().enumerate() {
+        ret_val = ret_val.push(
+            Row::new()
+                .align_y(Alignment::Center)
+                .spacing(10)
+                .push(Text::new(host_label.target.clone()).font(font).width(250))
+                .push(Text::new(host_label.text()).font(font).width(Length::Fill))
+                .push(button_remove(Message::RemoveHostLabel(i), font)),
+        );
+    }
+
+    let input = |placeholder: &'static str, value: &str, update: fn(&mut HostLabel, String)| {
+        let host_label_input = host_label_input.clone();
+        TextInput::new(placeholder, value)
+            .on_input(move |value| {
+                let mut host_label = host_label_input.clone();
+                update(&mut host_label, value);
+                Message::HostLabelInput(host_label)
+            })
+            .padding([2, 5])
+            .font(font)
+    };
+    let is_target_error = !host_label_input.target.trim().is_empty()
+        && !HostLabel {
+            name: "-".to_string(),
+            ..host_label_input.clone()
+        }
+        .is_valid();
+
+    ret_val.push(
+        Row::new()
+            .align_y(Alignment::Center)
+            .spacing(10)
+            .push(
+                input(
+                    label_target_translation(language),
+                    &host_label_input.target,
+                    |l, v| l.target = v,
+                )
+                .width(250)
+                .class(if is_target_error {
+                    TextInputType::Error
+                } else {
+                    TextInputType::Standard
+                }),
+            )
+            .push(
+                input(
+                    name_translation(language),
+                    &host_label_input.name,
+                    |l, v| {
+                        l.name = v;
+                    },
+                )
+                .width(Length::Fill),
+            )
+            .push(
+                input(
+                    group_translation(language),
+                    &host_label_input.group,
+                    |l, v| l.group = v,
+                )
+                .width(Length::Fill),
+            )
+            .push(
+                button(Text::new("+").font(font).align_x(Alignment::Center))
+                    .padding([2, 15])
+                    .class(ButtonType::BorderedRound)
+                    .on_press_maybe(host_label_input.is_valid().then_some(Message::AddHostLabel)),
+            ),
+    )
+}
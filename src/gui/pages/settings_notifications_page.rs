@@ -14,16 +14,29 @@
 use crate::gui::styles::text::TextType;
 use crate::gui::styles::types::gradient_type::GradientType;
 use crate::gui::types::message::Message;
+use crate::notifications::types::notification_action::{ActionTrigger, NotificationAction};
 use crate::notifications::types::notifications::{
-    DataNotification, FavoriteNotification, Notification,
+    AlertConditions, AnomalyNotification, AnomalySensitivity, BlocklistNotification,
+    DataNotification, FavoriteNotification, FirstSeenNotification, HostIdentity, Notification,
+    ScanNotification, ScopedDataNotification, ThresholdScope,
 };
+use crate::notifications::types::scan_detector::SCAN_WINDOW;
 use crate::notifications::types::sound::Sound;
+use crate::translations::translations::address_translation;
 use crate::translations::translations::{
     favorite_transmitted_translation, notifications_title_translation, per_second_translation,
     settings_translation, sound_translation, threshold_translation, volume_translation,
 };
-use crate::translations::translations_2::data_representation_translation;
-use crate::translations::translations_4::data_exceeded_translation;
+use crate::translations::translations_2::{data_representation_translation, host_translation};
+use crate::translations::translations_4::{
+    action_translation, add_threshold_translation, anomaly_detected_translation,
+    blocklisted_host_translation, cooldown_translation, data_exceeded_translation,
+    desktop_notifications_translation, distinct_hosts_translation, distinct_ports_translation,
+    exceeded_for_translation, host_identity_translation, new_asn_translation,
+    new_country_translation, new_host_translation, rearm_below_translation,
+    scan_detected_translation, scoped_thresholds_translation, sensitivity_translation,
+    within_seconds_translation,
+};
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, Sniffer, StyleType};
 
@@ -32,9 +45,9 @@ pub fn settings_notifications_page<'a>(sniffer: &Sniffer) -> Container<'a, Messa
         style,
         language,
         color_gradient,
-        mut notifications,
         ..
     } = sniffer.configs.settings;
+    let mut notifications = sniffer.configs.settings.notifications.clone();
     let font = style.get_extension().font;
     let font_headers = style.get_extension().font_headers;
 
@@ -74,17 +87,58 @@ pub fn settings_notifications_page<'a>(sniffer: &Sniffer) -> Container<'a, Messa
         .align_x(Alignment::Center)
         .width(Length::Fill)
         .push(volume_slider(language, font, notifications.volume))
+        // desktop notifications are only supported on Linux for now
+        .push_maybe(cfg!(target_os = "linux").then(|| {
+            desktop_notifications_checkbox(language, font, notifications.desktop_notifications)
+        }))
         .push(Scrollable::with_direction(
             Column::new()
                 .align_x(Alignment::Center)
                 .width(Length::Fill)
                 .push(get_data_notify(
                     notifications.data_notification,
+                    &notifications.actions.data,
                     language,
                     font,
                 ))
                 .push(get_favorite_notify(
                     notifications.favorite_notification,
+                    &notifications.actions.favorite,
+                    language,
+                    font,
+                ))
+                .push(get_scoped_data_notify(
+                    &notifications.scoped_data_notifications,
+                    // the action for data notifications is already shown if the global threshold is set
+                    notifications
+                        .data_notification
+                        .threshold
+                        .is_none()
+                        .then_some(&notifications.actions.data),
+                    language,
+                    font,
+                ))
+                .push(get_first_seen_notify(
+                    notifications.first_seen_notification,
+                    &notifications.actions.first_seen,
+                    language,
+                    font,
+                ))
+                .push(get_anomaly_notify(
+                    notifications.anomaly_notification,
+                    &notifications.actions.anomaly,
+                    language,
+                    font,
+                ))
+                .push(get_scan_notify(
+                    notifications.scan_notification,
+                    &notifications.actions.scan,
+                    language,
+                    font,
+                ))
+                .push(get_blocklist_notify(
+                    notifications.blocklist_notification,
+                    &notifications.actions.blocklist,
                     language,
                     font,
                 )),
@@ -101,6 +155,7 @@ pub fn settings_notifications_page<'a>(sniffer: &Sniffer) -> Container<'a, Messa
 
 fn get_data_notify<'a>(
     data_notification: DataNotification,
+    action: &NotificationAction,
     language: Language,
     font: Font,
 ) -> Column<'a, Message, StyleType> {
@@ -140,18 +195,19 @@ fn get_data_notify<'a>(
                 .class(ContainerType::BorderedRound),
         )
     } else {
-        let data_representation_row = row_data_representation(
-            data_notification,
-            language,
-            font,
-            data_notification.chart_type,
-        );
-        let input_row = input_group_bytes(data_notification, font, language);
+        let to_message = |n| Message::UpdateNotificationSettings(Notification::Data(n), false);
+        let data_representation_row =
+            row_data_representation(data_notification, language, font, to_message);
+        let input_row = input_group_bytes(data_notification, font, language, to_message);
+        let alert_conditions_row =
+            row_alert_conditions(data_notification, font, language, to_message);
         let sound_row = sound_buttons(Notification::Data(data_notification), font, language);
         ret_val = ret_val
             .push(sound_row)
             .push(data_representation_row)
-            .push(input_row);
+            .push(input_row)
+            .push(alert_conditions_row)
+            .push(row_action(ActionTrigger::Data, action, language, font));
         Column::new().padding(5).push(
             Container::new(ret_val)
                 .padding(10)
@@ -163,6 +219,7 @@ fn get_data_notify<'a>(
 
 fn get_favorite_notify<'a>(
     favorite_notification: FavoriteNotification,
+    action: &NotificationAction,
     language: Language,
     font: Font,
 ) -> Column<'a, Message, StyleType> {
@@ -191,7 +248,12 @@ fn get_favorite_notify<'a>(
             font,
             language,
         );
-        ret_val = ret_val.push(sound_row);
+        ret_val = ret_val.push(sound_row).push(row_action(
+            ActionTrigger::Favorite,
+            action,
+            language,
+            font,
+        ));
         Column::new().padding(5).push(
             Container::new(ret_val)
                 .padding(10)
@@ -208,10 +270,477 @@ fn get_favorite_notify<'a>(
     }
 }
 
+fn get_scoped_data_notify<'a>(
+    scoped_data_notifications: &[ScopedDataNotification],
+    action: Option<&NotificationAction>,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let mut ret_val = Column::new()
+        .spacing(15)
+        .push(Text::new(scoped_thresholds_translation(language)).font(font));
+
+    for (index, rule) in scoped_data_notifications.iter().enumerate() {
+        let scope = rule.scope.clone();
+        let to_message = move |data_notification| {
+            Message::UpdateScopedThreshold(
+                index,
+                ScopedDataNotification {
+                    scope: scope.clone(),
+                    data_notification,
+                },
+            )
+        };
+        ret_val = ret_val.push(
+            Container::new(
+                Column::new()
+                    .spacing(10)
+                    .push(row_threshold_scope(index, rule, language, font))
+                    .push(row_data_representation(
+                        rule.data_notification,
+                        language,
+                        font,
+                        to_message.clone(),
+                    ))
+                    .push(input_group_bytes(
+                        rule.data_notification,
+                        font,
+                        language,
+                        to_message.clone(),
+                    ))
+                    .push(row_alert_conditions(
+                        rule.data_notification,
+                        font,
+                        language,
+                        to_message,
+                    )),
+            )
+            .padding(10)
+            .width(Length::Fill)
+            .class(ContainerType::BorderedRound),
+        );
+    }
+
+    ret_val = ret_val.push(
+        Button::new(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(Text::new("+").font(font))
+                .push(Text::new(add_threshold_translation(language)).font(font)),
+        )
+        .padding([2, 15])
+        .class(ButtonType::BorderedRound)
+        .on_press(Message::AddScopedThreshold),
+    );
+
+    if let Some(action) = action
+        && !scoped_data_notifications.is_empty()
+    {
+        ret_val = ret_val.push(row_action(ActionTrigger::Data, action, language, font));
+    }
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .class(ContainerType::BorderedRound),
+    )
+}
+
+fn row_threshold_scope<'a>(
+    index: usize,
+    rule: &ScopedDataNotification,
+    language: Language,
+    font: Font,
+) -> Row<'a, Message, StyleType> {
+    let mut ret_val = Row::new()
+        .align_y(Alignment::Center)
+        .spacing(5)
+        .push(Space::with_width(45));
+
+    for option in ThresholdScope::ALL {
+        let is_active = rule.scope.same_kind(&option);
+        ret_val = ret_val.push(
+            Button::new(
+                Text::new(option.get_label(language))
+                    .size(FONT_SIZE_FOOTER)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .font(font),
+            )
+            .padding(Padding::ZERO.left(15).right(15))
+            .height(25)
+            .class(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::UpdateScopedThreshold(
+                index,
+                ScopedDataNotification {
+                    scope: option.with_value(rule.scope.value().to_string()),
+                    ..rule.clone()
+                },
+            )),
+        );
+    }
+
+    let rule_clone = rule.clone();
+    ret_val
+        .push(
+            TextInput::new("", rule.scope.value())
+                .on_input(move |value| {
+                    Message::UpdateScopedThreshold(
+                        index,
+                        ScopedDataNotification {
+                            scope: rule_clone.scope.with_value(value),
+                            ..rule_clone.clone()
+                        },
+                    )
+                })
+                .padding([2, 5])
+                .font(font)
+                .width(Length::Fill),
+        )
+        .push(
+            Button::new(
+                Icon::Bin
+                    .to_text()
+                    .size(FONT_SIZE_FOOTER)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center),
+            )
+            .padding(0)
+            .height(25)
+            .width(40)
+            .on_press(Message::RemoveScopedThreshold(index)),
+        )
+}
+
+fn get_first_seen_notify<'a>(
+    first_seen_notification: FirstSeenNotification,
+    action: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let checkbox = |label: &'static str,
+                    is_checked: bool,
+                    update: fn(&mut FirstSeenNotification, bool)| {
+        Checkbox::new(label, is_checked)
+            .on_toggle(move |toggled| {
+                let mut notification = first_seen_notification;
+                update(&mut notification, toggled);
+                Message::UpdateNotificationSettings(Notification::FirstSeen(notification), false)
+            })
+            .size(18)
+            .font(font)
+    };
+
+    let mut ret_val = Column::new()
+        .spacing(15)
+        .push(checkbox(
+            new_host_translation(language),
+            first_seen_notification.notify_on_new_host,
+            |n, toggled| n.notify_on_new_host = toggled,
+        ))
+        .push(checkbox(
+            new_asn_translation(language),
+            first_seen_notification.notify_on_new_asn,
+            |n, toggled| n.notify_on_new_asn = toggled,
+        ))
+        .push(checkbox(
+            new_country_translation(language),
+            first_seen_notification.notify_on_new_country,
+            |n, toggled| n.notify_on_new_country = toggled,
+        ));
+
+    if first_seen_notification.notify_on_new_host {
+        ret_val = ret_val.push(row_host_identity(first_seen_notification, language, font));
+    }
+    if first_seen_notification.is_active() {
+        let sound_row = sound_buttons(
+            Notification::FirstSeen(first_seen_notification),
+            font,
+            language,
+        );
+        ret_val = ret_val.push(sound_row).push(row_action(
+            ActionTrigger::FirstSeen,
+            action,
+            language,
+            font,
+        ));
+    }
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .class(ContainerType::BorderedRound),
+    )
+}
+
+fn row_host_identity<'a>(
+    first_seen_notification: FirstSeenNotification,
+    language: Language,
+    font: Font,
+) -> Row<'a, Message, StyleType> {
+    let mut ret_val = Row::new()
+        .width(Length::Shrink)
+        .align_y(Alignment::Center)
+        .spacing(5)
+        .push(Space::with_width(45))
+        .push(Text::new(format!("{}:", host_identity_translation(language))).font(font));
+
+    for option in HostIdentity::ALL {
+        let is_active = first_seen_notification.host_identity.eq(&option);
+        let label = match option {
+            HostIdentity::Address => address_translation(language),
+            HostIdentity::Host => host_translation(language),
+        };
+        ret_val = ret_val.push(
+            Button::new(
+                Text::new(label)
+                    .size(FONT_SIZE_FOOTER)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .font(font),
+            )
+            .padding(Padding::ZERO.left(15).right(15))
+            .height(25)
+            .class(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::UpdateNotificationSettings(
+                Notification::FirstSeen(FirstSeenNotification {
+                    host_identity: option,
+                    ..first_seen_notification
+                }),
+                false,
+            )),
+        );
+    }
+    ret_val
+}
+
+fn get_anomaly_notify<'a>(
+    anomaly_notification: AnomalyNotification,
+    action: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        anomaly_detected_translation(language),
+        anomaly_notification.notify_on_anomaly,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            Notification::Anomaly(AnomalyNotification {
+                notify_on_anomaly: toggled,
+                ..anomaly_notification
+            }),
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(15).push(checkbox);
+
+    if anomaly_notification.notify_on_anomaly {
+        let sound_row = sound_buttons(Notification::Anomaly(anomaly_notification), font, language);
+        ret_val = ret_val
+            .push(row_sensitivity(anomaly_notification, language, font))
+            .push(sound_row)
+            .push(row_action(ActionTrigger::Anomaly, action, language, font));
+    }
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .class(ContainerType::BorderedRound),
+    )
+}
+
+fn get_scan_notify<'a>(
+    scan_notification: ScanNotification,
+    action: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        scan_detected_translation(language),
+        scan_notification.notify_on_scan,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            Notification::Scan(ScanNotification {
+                notify_on_scan: toggled,
+                ..scan_notification
+            }),
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(15).push(checkbox);
+
+    if scan_notification.notify_on_scan {
+        let sound_row = sound_buttons(Notification::Scan(scan_notification), font, language);
+        ret_val = ret_val
+            .push(row_scan_thresholds(scan_notification, language, font))
+            .push(sound_row)
+            .push(row_action(ActionTrigger::Scan, action, language, font));
+    }
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .class(ContainerType::BorderedRound),
+    )
+}
+
+fn get_blocklist_notify<'a>(
+    blocklist_notification: BlocklistNotification,
+    action: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Column<'a, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        blocklisted_host_translation(language),
+        blocklist_notification.notify_on_blocklisted,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            Notification::Blocklist(BlocklistNotification {
+                notify_on_blocklisted: toggled,
+                ..blocklist_notification
+            }),
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(15).push(checkbox);
+
+    if blocklist_notification.notify_on_blocklisted {
+        let sound_row = sound_buttons(
+            Notification::Blocklist(blocklist_notification),
+            font,
+            language,
+        );
+        ret_val = ret_val.push(sound_row).push(row_action(
+            ActionTrigger::Blocklist,
+            action,
+            language,
+            font,
+        ));
+    }
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .class(ContainerType::BorderedRound),
+    )
+}
+
+fn row_scan_thresholds<'a>(
+    scan_notification: ScanNotification,
+    language: Language,
+    font: Font,
+) -> Row<'a, Message, StyleType> {
+    let input = |value: u16, update: fn(&mut ScanNotification, u16)| {
+        TextInput::new("0", &value.to_string())
+            .on_input(move |new_value| {
+                let mut notification = scan_notification;
+                // keep the current value if the inserted one isn't valid
+                let new_value = new_value.trim();
+                if new_value.is_empty() {
+                    update(&mut notification, 0);
+                } else if let Ok(value) = new_value.parse() {
+                    update(&mut notification, value);
+                }
+                Message::UpdateNotificationSettings(Notification::Scan(notification), false)
+            })
+            .padding([2, 5])
+            .font(font)
+            .width(50)
+    };
+    let label = |text: &'static str| Text::new(format!("{text}:")).font(font);
+
+    Row::new()
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .push(Space::with_width(45))
+        .push(label(distinct_ports_translation(language)))
+        .push(input(scan_notification.ports_threshold, |n, v| {
+            n.ports_threshold = v;
+        }))
+        .push(Space::with_width(15))
+        .push(label(distinct_hosts_translation(language)))
+        .push(input(scan_notification.hosts_threshold, |n, v| {
+            n.hosts_threshold = v;
+        }))
+        .push(
+            Text::new(within_seconds_translation(language, SCAN_WINDOW))
+                .font(font)
+                .size(FONT_SIZE_FOOTER),
+        )
+}
+
+fn row_sensitivity<'a>(
+    anomaly_notification: AnomalyNotification,
+    language: Language,
+    font: Font,
+) -> Row<'a, Message, StyleType> {
+    let mut ret_val = Row::new()
+        .width(Length::Shrink)
+        .align_y(Alignment::Center)
+        .spacing(5)
+        .push(Space::with_width(45))
+        .push(Text::new(format!("{}:", sensitivity_translation(language))).font(font));
+
+    for option in AnomalySensitivity::ALL {
+        let is_active = anomaly_notification.sensitivity.eq(&option);
+        ret_val = ret_val.push(
+            Button::new(
+                Text::new(option.get_label(language))
+                    .size(FONT_SIZE_FOOTER)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .font(font),
+            )
+            .padding(Padding::ZERO.left(15).right(15))
+            .height(25)
+            .class(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::UpdateNotificationSettings(
+                Notification::Anomaly(AnomalyNotification {
+                    sensitivity: option,
+                    ..anomaly_notification
+                }),
+                false,
+            )),
+        );
+    }
+    ret_val
+}
+
 fn input_group_bytes<'a>(
     bytes_notification: DataNotification,
     font: Font,
     language: Language,
+    to_message: impl Fn(DataNotification) -> Message + 'a,
 ) -> Container<'a, Message, StyleType> {
     let mut curr_threshold_str = (bytes_notification.threshold.unwrap_or_default()
         / bytes_notification.byte_multiple.multiplier())
@@ -232,8 +761,7 @@ fn input_group_bytes<'a>(
                 },
             )
             .on_input(move |value| {
-                let bytes_notification = DataNotification::from(&value, Some(bytes_notification));
-                Message::UpdateNotificationSettings(Notification::Data(bytes_notification), false)
+                to_message(DataNotification::from(&value, Some(bytes_notification)))
             })
             .padding([2, 5])
             .font(font)
@@ -250,6 +778,79 @@ fn input_group_bytes<'a>(
         .align_y(Alignment::Center)
 }
 
+fn row_alert_conditions<'a>(
+    data_notification: DataNotification,
+    font: Font,
+    language: Language,
+    to_message: impl Fn(DataNotification) -> Message + Clone + 'a,
+) -> Row<'a, Message, StyleType> {
+    let conditions = data_notification.alert_conditions;
+    let input = |value: String, update: fn(&mut AlertConditions, &str) -> bool| {
+        let to_message = to_message.clone();
+        TextInput::new("", &value)
+            .on_input(move |new_value| {
+                let mut alert_conditions = conditions;
+                // keep the current value if the inserted one isn't valid
+                if !update(&mut alert_conditions, new_value.trim()) {
+                    alert_conditions = conditions;
+                }
+                to_message(DataNotification {
+                    alert_conditions,
+                    ..data_notification
+                })
+            })
+            .padding([2, 5])
+            .font(font)
+            .width(50)
+    };
+    let label = |text: String| Text::new(text).font(font);
+    let unit = |text: &'static str| Text::new(text).font(font).size(FONT_SIZE_FOOTER);
+
+    Row::new()
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .push(Space::with_width(45))
+        .push(label(format!("{}:", exceeded_for_translation(language))))
+        .push(input(conditions.sustained_seconds.to_string(), |c, v| {
+            if v.is_empty() {
+                c.sustained_seconds = 1;
+                return true;
+            }
+            v.parse().map(|s| c.sustained_seconds = s).is_ok()
+        }))
+        .push(unit("s"))
+        .push(Space::with_width(15))
+        .push(label(format!("{}:", rearm_below_translation(language))))
+        .push(input(
+            conditions
+                .rearm_percent
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+            |c, v| {
+                if v.is_empty() {
+                    c.rearm_percent = None;
+                    return true;
+                }
+                v.parse()
+                    .ok()
+                    .filter(|p| *p <= 100)
+                    .map(|p| c.rearm_percent = Some(p))
+                    .is_some()
+            },
+        ))
+        .push(unit("%"))
+        .push(Space::with_width(15))
+        .push(label(format!("{}:", cooldown_translation(language))))
+        .push(input(conditions.cooldown_seconds.to_string(), |c, v| {
+            if v.is_empty() {
+                c.cooldown_seconds = 0;
+                return true;
+            }
+            v.parse().map(|s| c.cooldown_seconds = s).is_ok()
+        }))
+        .push(unit("s"))
+}
+
 fn volume_slider<'a>(
     language: Language,
     font: Font,
@@ -291,6 +892,83 @@ fn volume_slider<'a>(
     .align_y(Alignment::Center)
 }
 
+fn desktop_notifications_checkbox<'a>(
+    language: Language,
+    font: Font,
+    desktop_notifications: bool,
+) -> Container<'a, Message, StyleType> {
+    Container::new(
+        Checkbox::new(
+            desktop_notifications_translation(language),
+            desktop_notifications,
+        )
+        .on_toggle(Message::ToggleDesktopNotifications)
+        .size(18)
+        .font(font),
+    )
+    .padding(5)
+    .width(Length::Fill)
+    .align_x(Alignment::Center)
+}
+
+fn row_action<'a>(
+    trigger: ActionTrigger,
+    action: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Row<'a, Message, StyleType> {
+    let mut ret_val = Row::new()
+        .align_y(Alignment::Center)
+        .spacing(5)
+        .push(Space::with_width(45))
+        .push(Text::new(format!("{}:", action_translation(language))).font(font));
+
+    for option in NotificationAction::ALL {
+        let is_active = action.same_kind(&option);
+        ret_val = ret_val.push(
+            Button::new(
+                option
+                    .get_text(font, language)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center),
+            )
+            .padding(Padding::ZERO.left(15).right(15))
+            .height(25)
+            .class(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::UpdateNotificationAction(
+                trigger,
+                option.with_value(action.value().to_string()),
+            )),
+        );
+    }
+
+    if !matches!(action, NotificationAction::None) {
+        let action_clone = action.clone();
+        ret_val = ret_val.push(
+            TextInput::new(
+                if matches!(action, NotificationAction::Webhook(_)) {
+                    "https://"
+                } else {
+                    ""
+                },
+                action.value(),
+            )
+            .on_input(move |value| {
+                Message::UpdateNotificationAction(trigger, action_clone.with_value(value))
+            })
+            .padding([2, 5])
+            .font(font)
+            .width(Length::Fill),
+        );
+    }
+
+    ret_val
+}
+
 fn sound_buttons<'a>(
     notification: Notification,
     font: Font,
@@ -299,6 +977,10 @@ fn sound_buttons<'a>(
     let current_sound = match notification {
         Notification::Data(n) => n.sound,
         Notification::Favorite(n) => n.sound,
+        Notification::FirstSeen(n) => n.sound,
+        Notification::Anomaly(n) => n.sound,
+        Notification::Scan(n) => n.sound,
+        Notification::Blocklist(n) => n.sound,
     };
 
     let mut ret_val = Row::new()
@@ -315,6 +997,16 @@ fn sound_buttons<'a>(
             Notification::Favorite(n) => {
                 Notification::Favorite(FavoriteNotification { sound: option, ..n })
             }
+            Notification::FirstSeen(n) => {
+                Notification::FirstSeen(FirstSeenNotification { sound: option, ..n })
+            }
+            Notification::Anomaly(n) => {
+                Notification::Anomaly(AnomalyNotification { sound: option, ..n })
+            }
+            Notification::Scan(n) => Notification::Scan(ScanNotification { sound: option, ..n }),
+            Notification::Blocklist(n) => {
+                Notification::Blocklist(BlocklistNotification { sound: option, ..n })
+            }
         };
         ret_val = ret_val.push(
             Button::new(
@@ -372,7 +1064,7 @@ fn row_data_representation<'a>(
     data_notification: DataNotification,
     language: Language,
     font: Font,
-    chart_type: ChartType,
+    to_message: impl Fn(DataNotification) -> Message,
 ) -> Row<'a, Message, StyleType> {
     let mut ret_val = Row::new()
         .width(Length::Shrink)
@@ -382,7 +1074,7 @@ fn row_data_representation<'a>(
         .push(Text::new(format!("{}:", data_representation_translation(language))).font(font));
 
     for option in ChartType::ALL {
-        let is_active = chart_type.eq(&option);
+        let is_active = data_notification.chart_type.eq(&option);
         ret_val = ret_val.push(
             Button::new(
                 Text::new(option.get_label(language).to_owned())
@@ -398,13 +1090,10 @@ fn row_data_representation<'a>(
             } else {
                 ButtonType::BorderedRound
             })
-            .on_press(Message::UpdateNotificationSettings(
-                Notification::Data(DataNotification {
-                    chart_type: option,
-                    ..data_notification
-                }),
-                false,
-            )),
+            .on_press(to_message(DataNotification {
+                chart_type: option,
+                ..data_notification
+            })),
         );
     }
     ret_val
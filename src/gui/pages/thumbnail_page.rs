@@ -1,4 +1,5 @@
 use std::cmp::min;
+use std::collections::HashMap;
 use std::net::IpAddr;
 
 use iced::widget::{Column, Container, Row, Rule, Space, Text, vertical_space};
@@ -13,6 +14,7 @@
 use crate::gui::styles::types::style_type::StyleType;
 use crate::gui::types::message::Message;
 use crate::networking::types::host::{Host, ThumbnailHost};
+use crate::networking::types::host_label::HostLabel;
 use crate::networking::types::info_traffic::InfoTraffic;
 use crate::report::get_report_entries::{get_host_entries, get_service_entries};
 use crate::report::types::sort_type::SortType;
@@ -71,6 +73,7 @@ pub fn thumbnail_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
         .align_y(Alignment::Start)
         .push(host_col(
             info_traffic,
+            &sniffer.labeled_hosts,
             chart_type,
             font,
             sniffer.host_sort_type,
@@ -93,6 +96,7 @@ pub fn thumbnail_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
 
 fn host_col<'a>(
     info_traffic: &InfoTraffic,
+    labeled_hosts: &HashMap<Host, HostLabel>,
     chart_type: ChartType,
     font: Font,
     sort_type: SortType,
@@ -105,7 +109,10 @@ fn host_col<'a>(
     let mut thumbnail_hosts = Vec::new();
 
     for (host, data_info_host) in &hosts {
-        let text = host_text(host);
+        let text = match labeled_hosts.get(host) {
+            Some(label) => clip_text(&label.text(), MAX_CHARS_HOST),
+            None => host_text(host),
+        };
         let country = host.country;
         let thumbnail_host = ThumbnailHost {
             country,
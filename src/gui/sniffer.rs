@@ -1,6 +1,6 @@
 //! Module defining the application structure: messages, updates, subscriptions.
 
-use async_channel::Receiver;
+use async_channel::{Receiver, Sender};
 use iced::Event::{Keyboard, Window};
 use iced::keyboard::key::Named;
 use iced::keyboard::{Event, Key, Modifiers};
@@ -11,20 +11,26 @@
 use pcap::Device;
 use rfd::FileHandle;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::File;
+use std::io::Write;
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
+use crate::configs::types::config_favorites::ConfigFavorites;
 use crate::configs::types::config_window::{
     ConfigWindow, PositionTuple, ScaleAndCheck, SizeTuple, ToPoint, ToSize,
 };
 use crate::gui::components::footer::footer;
 use crate::gui::components::header::header;
-use crate::gui::components::modal::{get_clear_all_overlay, get_exit_overlay, modal};
+use crate::gui::components::modal::{
+    get_clear_all_overlay, get_exit_overlay, get_save_failed_overlay, modal,
+};
 use crate::gui::components::types::my_modal::MyModal;
 use crate::gui::pages::connection_details_page::connection_details_page;
+use crate::gui::pages::dns_log_page::dns_log_page;
 use crate::gui::pages::initial_page::initial_page;
 use crate::gui::pages::inspect_page::inspect_page;
 use crate::gui::pages::notifications_page::notifications_page;
@@ -39,34 +45,49 @@
 use crate::gui::styles::types::palette::Palette;
 use crate::gui::types::export_pcap::ExportPcap;
 use crate::gui::types::message::Message;
+use crate::gui::types::session::Session;
 use crate::gui::types::timing_events::TimingEvents;
 use crate::mmdb::asn::ASN_MMDB;
 use crate::mmdb::country::COUNTRY_MMDB;
 use crate::mmdb::types::mmdb_reader::{MmdbReader, MmdbReaders};
 use crate::networking::parse_packets::BackendTrafficMessage;
-use crate::networking::parse_packets::parse_packets;
+use crate::networking::parse_packets::{merge_ticks, parse_packets};
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::blocklist::Blocklists;
 use crate::networking::types::capture_context::{CaptureContext, CaptureSource, MyPcapImport};
 use crate::networking::types::filters::Filters;
 use crate::networking::types::host::{Host, HostMessage};
 use crate::networking::types::host_data_states::HostDataStates;
+use crate::networking::types::host_label::{HostLabel, HostLabels};
+use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::networking::types::info_traffic::InfoTraffic;
 use crate::networking::types::ip_collection::AddressCollection;
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::port_collection::PortCollection;
-use crate::notifications::notify_and_log::notify_and_log;
+use crate::networking::types::vlan_collection::VlanCollection;
+use crate::notifications::notify_and_log::{
+    FirstSeenKind, get_blocklisted, get_first_seen, notify_and_log,
+};
 use crate::notifications::types::logged_notification::LoggedNotification;
-use crate::notifications::types::notifications::{DataNotification, Notification};
+use crate::notifications::types::notifications::{
+    DataNotification, Notification, NotificationsState, ScopedDataNotification, ThresholdScope,
+    ThresholdState,
+};
+use crate::notifications::types::notifications_history::{HistoryFilter, NotificationsHistory};
 use crate::notifications::types::sound::{Sound, play};
-use crate::report::get_report_entries::get_searched_entries;
+use crate::report::get_report_entries::{get_searched_connection_records, get_searched_entries};
 use crate::report::types::report_sort_type::ReportSortType;
 use crate::report::types::search_parameters::SearchParameters;
 use crate::report::types::sort_type::SortType;
+use crate::translations::translations_4::{
+    export_favorites_translation, export_results_translation, save_session_translation,
+};
 use crate::translations::types::language::Language;
 use crate::utils::check_updates::set_newer_release_status;
 use crate::utils::error_logger::{ErrorLogger, Location};
 use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::web_page::WebPage;
-use crate::{ConfigSettings, Configs, StyleType, TrafficChart, location};
+use crate::{ConfigSettings, Configs, SNIFFNET_LOWERCASE, StyleType, TrafficChart, location};
 
 pub const FONT_FAMILY_NAME: &str = "Sarasa Mono SC for Sniffnet";
 pub const ICON_FONT_FAMILY_NAME: &str = "Icons for Sniffnet";
@@ -83,7 +104,7 @@ pub struct Sniffer {
     pub info_traffic: InfoTraffic,
     /// Map of the resolved addresses with their full rDNS value and the corresponding host
     pub addresses_resolved: HashMap<IpAddr, (String, Host)>,
-    /// Collection of the favorite hosts
+    /// Collection of the favorite hosts (kept in sync with `configs.favorites`)
     pub favorite_hosts: HashSet<Host>,
     /// Log of the displayed notifications, with the total number of notifications for this capture
     pub logged_notifications: (VecDeque<LoggedNotification>, usize),
@@ -107,6 +128,8 @@ pub struct Sniffer {
     pub host_sort_type: SortType,
     /// Service sort type (overview page)
     pub service_sort_type: SortType,
+    /// Application sort type (overview page)
+    pub application_sort_type: SortType,
     /// Currently displayed modal; None if no modal is displayed
     pub modal: Option<MyModal>,
     /// Currently displayed settings page; None if settings is closed
@@ -135,6 +158,32 @@ pub struct Sniffer {
     pub host_data_states: HostDataStates,
     /// Import path for PCAP file
     pub import_pcap_path: String,
+    /// Capture source selected before opening a session file, restored when leaving the session
+    pub capture_source_before_session: Option<CaptureSource>,
+    /// Notifications emitted in all the live captures
+    pub notifications_history: NotificationsHistory,
+    /// Search parameters of the notifications history
+    pub history_filter: HistoryFilter,
+    /// Runtime state of the data thresholds and of the traffic baseline
+    pub notifications_state: NotificationsState,
+    /// Blocklists loaded from the files selected in settings
+    pub blocklists: Blocklists,
+    /// Resolved addresses matching the blocklists
+    pub blocklisted_addresses: HashSet<IpAddr>,
+    /// Labels defined by the user
+    pub host_labels: HostLabels,
+    /// Observed hosts matching the labels
+    pub labeled_hosts: HashMap<Host, HostLabel>,
+    /// MAC addresses observed for each IP address (only collected if some labels target MAC addresses)
+    pub mac_addresses: HashMap<IpAddr, String>,
+    /// Host label being defined in settings
+    pub host_label_input: HostLabel,
+    /// Whether the last file selected to import favorites couldn't be read
+    pub favorites_import_failed: bool,
+    /// Names of the network devices to capture along with the selected one
+    pub additional_devices: Vec<String>,
+    /// Whether wireless devices are captured in monitor mode
+    pub monitor_mode: bool,
 }
 
 impl Sniffer {
@@ -144,15 +193,17 @@ pub fn new(configs: Configs) -> Self {
             language,
             mmdb_country,
             mmdb_asn,
+            host_labels,
             ..
         } = configs.settings.clone();
         let device = configs.device.to_my_device();
+        let favorite_hosts = configs.favorites.hosts.iter().cloned().collect();
         Self {
             configs,
             current_capture_rx: (0, None),
             info_traffic: InfoTraffic::default(),
             addresses_resolved: HashMap::new(),
-            favorite_hosts: HashSet::new(),
+            favorite_hosts,
             logged_notifications: (VecDeque::new(), 0),
             newer_release_available: None,
             capture_source: CaptureSource::Device(device),
@@ -164,6 +215,7 @@ pub fn new(configs: Configs) -> Self {
             report_sort_type: ReportSortType::default(),
             host_sort_type: SortType::default(),
             service_sort_type: SortType::default(),
+            application_sort_type: SortType::default(),
             modal: None,
             settings_page: None,
             last_opened_setting: SettingsPage::Notifications,
@@ -181,6 +233,19 @@ pub fn new(configs: Configs) -> Self {
             id: None,
             host_data_states: HostDataStates::default(),
             import_pcap_path: String::new(),
+            capture_source_before_session: None,
+            notifications_history: NotificationsHistory::default(),
+            history_filter: HistoryFilter::default(),
+            notifications_state: NotificationsState::default(),
+            blocklists: Blocklists::default(),
+            blocklisted_addresses: HashSet::new(),
+            host_labels: HostLabels::new(&host_labels),
+            labeled_hosts: HashMap::new(),
+            mac_addresses: HashMap::new(),
+            host_label_input: HostLabel::default(),
+            favorites_import_failed: false,
+            additional_devices: Vec::new(),
+            monitor_mode: false,
         }
     }
 
@@ -267,17 +332,28 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
                 return Task::batch([
                     Sniffer::register_sigint_handler(),
                     Task::perform(set_newer_release_status(), Message::SetNewerReleaseStatus),
+                    self.load_blocklists(),
                 ]);
             }
             Message::TickRun(cap_id, msg, host_msgs, no_more_packets) => {
                 if cap_id == self.current_capture_rx.0 {
+                    self.learn_mac_addresses(&msg.map);
+                    let first_seen = self.get_first_seen(&host_msgs);
+                    let blocklisted = self.get_blocklisted(&host_msgs);
                     for host_msg in host_msgs {
                         self.handle_new_host(host_msg);
                     }
-                    self.refresh_data(msg, no_more_packets);
+                    self.refresh_data(msg, first_seen, blocklisted, no_more_packets);
                 }
             }
             Message::DeviceSelection(name) => self.set_device(&name),
+            Message::AdditionalDeviceSelection(name, insert) => {
+                self.additional_devices.retain(|n| n != &name);
+                if insert && name != self.capture_source.get_name() {
+                    self.additional_devices.push(name);
+                }
+            }
+            Message::MonitorMode(monitor_mode) => self.monitor_mode = monitor_mode,
             Message::IpVersionSelection(version, insert) => {
                 if insert {
                     self.filters.ip_versions.insert(version);
@@ -304,6 +380,16 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
                 }
                 self.filters.port_str = value;
             }
+            Message::VlanFilter(value) => {
+                if let Some(collection) = VlanCollection::new(&value) {
+                    self.filters.vlan_collection = collection;
+                }
+                self.filters.vlan_str = value;
+            }
+            Message::BpfFilter(value) => {
+                self.filters
+                    .set_bpf(value, self.capture_source.get_link_type());
+            }
             Message::ChartSelection(unit) => self.traffic_chart.change_kind(unit),
             Message::ReportSortSelection(sort) => {
                 self.page_number = 1;
@@ -361,6 +447,27 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
                 play(Sound::Pop, volume);
                 self.configs.settings.notifications.volume = volume;
             }
+            Message::ToggleDesktopNotifications(desktop_notifications) => {
+                self.configs.settings.notifications.desktop_notifications = desktop_notifications;
+            }
+            Message::UpdateNotificationAction(trigger, action) => {
+                self.configs
+                    .settings
+                    .notifications
+                    .actions
+                    .set(trigger, action);
+            }
+            Message::ShowNotificationsHistory(show) => {
+                if show {
+                    self.notifications_history.load();
+                    self.history_filter = HistoryFilter::default();
+                } else {
+                    self.notifications_history.unload();
+                }
+            }
+            Message::SearchNotificationsHistory(history_filter) => {
+                self.history_filter = history_filter;
+            }
             Message::ClearAllNotifications => {
                 self.logged_notifications.0 = VecDeque::new();
                 self.modal = None;
@@ -439,6 +546,42 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
                 self.configs.settings.mmdb_asn.clone_from(&db);
                 self.mmdb_readers.asn = Arc::new(MmdbReader::from(&db, ASN_MMDB));
             }
+            Message::AddBlocklist(path) => {
+                let blocklists = &mut self.configs.settings.blocklists;
+                if !path.is_empty() && !blocklists.contains(&path) {
+                    blocklists.push(path);
+                    return self.load_blocklists();
+                }
+            }
+            Message::HostLabelInput(host_label) => self.host_label_input = host_label,
+            Message::AddHostLabel => {
+                if self.host_label_input.is_valid() {
+                    let mut host_label = std::mem::take(&mut self.host_label_input);
+                    host_label.target = host_label.target.trim().to_string();
+                    self.configs.settings.host_labels.push(host_label);
+                    self.refresh_labels();
+                }
+            }
+            Message::RemoveHostLabel(index) => {
+                if index < self.configs.settings.host_labels.len() {
+                    self.configs.settings.host_labels.remove(index);
+                    self.refresh_labels();
+                }
+            }
+            Message::RemoveBlocklist(index) => {
+                let blocklists = &mut self.configs.settings.blocklists;
+                if index < blocklists.len() {
+                    blocklists.remove(index);
+                    return self.load_blocklists();
+                }
+            }
+            Message::BlocklistsLoaded(blocklists) => {
+                // the selected files may have changed again while loading
+                if blocklists.is_loaded_from(&self.configs.settings.blocklists) {
+                    self.blocklists = blocklists;
+                    self.refresh_blocklisted();
+                }
+            }
             Message::QuitWrapper => return self.quit_wrapper(),
             Message::Quit => {
                 let _ = self.configs.clone().store();
@@ -460,6 +603,9 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
             Message::ServiceSortSelection(sort_type) => {
                 self.service_sort_type = sort_type;
             }
+            Message::ApplicationSortSelection(sort_type) => {
+                self.application_sort_type = sort_type;
+            }
             Message::ToggleExportPcap => {
                 self.export_pcap.toggle();
             }
@@ -532,13 +678,24 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
                 if !path.is_empty() {
                     self.import_pcap_path.clone_from(&path);
                     self.capture_source = CaptureSource::File(MyPcapImport::new(path));
+                    self.filters
+                        .validate_bpf(self.capture_source.get_link_type());
                 }
             }
             Message::PendingHosts(cap_id, host_msgs) => {
                 if cap_id == self.current_capture_rx.0 {
+                    let first_seen = self.get_first_seen(&host_msgs);
+                    let blocklisted = self.get_blocklisted(&host_msgs);
                     for host_msg in host_msgs {
                         self.handle_new_host(host_msg);
                     }
+                    if !first_seen.is_empty() || !blocklisted.is_empty() {
+                        let msg = InfoTraffic {
+                            last_packet_timestamp: self.info_traffic.last_packet_timestamp,
+                            ..InfoTraffic::default()
+                        };
+                        self.log_notifications(&msg, first_seen, blocklisted);
+                    }
                 }
             }
             Message::OfflineGap(cap_id, gap) => {
@@ -561,6 +718,75 @@ pub fn update(&mut self, message: Message) -> Task<Message> {
                     n.expand(expand);
                 }
             }
+            Message::ExportSearchResults(format) => {
+                let content = format.serialize(&get_searched_connection_records(self));
+                let file_name = format!("{SNIFFNET_LOWERCASE}_connections.{}", format.extension());
+                let title = export_results_translation(self.configs.settings.language);
+                return Task::perform(
+                    Self::save_file(file_name, content, title),
+                    Message::FileSaved,
+                );
+            }
+            Message::AddScopedThreshold => {
+                let data_notification = DataNotification::default();
+                self.configs
+                    .settings
+                    .notifications
+                    .scoped_data_notifications
+                    .push(ScopedDataNotification {
+                        scope: ThresholdScope::default(),
+                        data_notification: DataNotification {
+                            threshold: Some(data_notification.previous_threshold),
+                            ..data_notification
+                        },
+                    });
+            }
+            Message::UpdateScopedThreshold(index, scoped_data_notification) => {
+                if let Some(rule) = self
+                    .configs
+                    .settings
+                    .notifications
+                    .scoped_data_notifications
+                    .get_mut(index)
+                {
+                    *rule = scoped_data_notification;
+                }
+            }
+            Message::RemoveScopedThreshold(index) => {
+                let rules = &mut self
+                    .configs
+                    .settings
+                    .notifications
+                    .scoped_data_notifications;
+                if index < rules.len() {
+                    rules.remove(index);
+                }
+            }
+            Message::SaveSession => {
+                let content = Session::new(self).to_json();
+                let file_name = format!("{SNIFFNET_LOWERCASE}_session.json");
+                let title = save_session_translation(self.configs.settings.language);
+                return Task::perform(
+                    Self::save_file(file_name, content, title),
+                    Message::FileSaved,
+                );
+            }
+            Message::OpenSession(path) => self.open_session(path),
+            Message::ImportFavorites(path) => self.import_favorites(&path),
+            Message::ExportFavorites => {
+                let content = self.configs.favorites.to_toml();
+                let file_name = format!("{SNIFFNET_LOWERCASE}_favorites.toml");
+                let title = export_favorites_translation(self.configs.settings.language);
+                return Task::perform(
+                    Self::save_file(file_name, content, title),
+                    Message::FileSaved,
+                );
+            }
+            Message::FileSaved(result) => {
+                if let Err(e) = result {
+                    self.modal = Some(MyModal::SaveFailed(e));
+                }
+            }
         }
         Task::none()
     }
@@ -636,6 +862,10 @@ pub fn view(&self) -> Element<Message, StyleType> {
                         get_clear_all_overlay(color_gradient, font, font_headers, language)
                     }
                     MyModal::ConnectionDetails(key) => connection_details_page(self, key),
+                    MyModal::DnsLog => dns_log_page(self),
+                    MyModal::SaveFailed(error) => {
+                        get_save_failed_overlay(error, color_gradient, font, font_headers, language)
+                    }
                 }
                 .into();
 
@@ -684,21 +914,18 @@ fn update_threshold(&mut self) {
         }
     }
 
-    fn refresh_data(&mut self, mut msg: InfoTraffic, no_more_packets: bool) {
+    fn refresh_data(
+        &mut self,
+        mut msg: InfoTraffic,
+        first_seen: Vec<(FirstSeenKind, HostMessage)>,
+        blocklisted: Vec<(HostMessage, String)>,
+        no_more_packets: bool,
+    ) {
         self.info_traffic.refresh(&mut msg);
         if self.info_traffic.tot_data_info.tot_packets() == 0 {
             return;
         }
-        let emitted_notifications = notify_and_log(
-            &mut self.logged_notifications,
-            self.configs.settings.notifications,
-            &msg,
-            &self.favorite_hosts,
-            &self.capture_source,
-        );
-        if self.thumbnail || self.running_page.ne(&RunningPage::Notifications) {
-            self.unread_notifications += emitted_notifications;
-        }
+        self.log_notifications(&msg, first_seen, blocklisted);
         self.traffic_chart.update_charts_data(&msg, no_more_packets);
 
         if let CaptureSource::Device(device) = &self.capture_source {
@@ -713,6 +940,47 @@ fn refresh_data(&mut self, mut msg: InfoTraffic, no_more_packets: bool) {
         self.host_data_states.update_states(&self.search);
     }
 
+    fn log_notifications(
+        &mut self,
+        msg: &InfoTraffic,
+        first_seen: Vec<(FirstSeenKind, HostMessage)>,
+        blocklisted: Vec<(HostMessage, String)>,
+    ) {
+        let emitted = notify_and_log(
+            &mut self.logged_notifications,
+            &self.configs.settings.notifications,
+            &mut self.notifications_state,
+            msg,
+            first_seen,
+            blocklisted,
+            &self.favorite_hosts,
+            &self.capture_source,
+            self.configs.settings.language,
+        );
+        if matches!(self.capture_source, CaptureSource::Device(_)) {
+            self.notifications_history.append(emitted.iter());
+        }
+        if self.thumbnail || self.running_page.ne(&RunningPage::Notifications) {
+            self.unread_notifications += emitted.len();
+        }
+    }
+
+    fn get_first_seen(&self, host_msgs: &[HostMessage]) -> Vec<(FirstSeenKind, HostMessage)> {
+        get_first_seen(
+            host_msgs,
+            &self.info_traffic.hosts,
+            self.configs.settings.notifications.first_seen_notification,
+        )
+    }
+
+    fn get_blocklisted(&self, host_msgs: &[HostMessage]) -> Vec<(HostMessage, String)> {
+        get_blocklisted(
+            host_msgs,
+            &self.blocklists,
+            self.configs.settings.notifications.blocklist_notification,
+        )
+    }
+
     fn open_web(web_page: &WebPage) {
         let url = web_page.get_url();
 
@@ -739,35 +1007,73 @@ fn start(&mut self) -> Task<Message> {
             let current_device_name = &self.capture_source.get_name();
             self.set_device(current_device_name);
         }
-        let pcap_path = self.export_pcap.full_path();
-        let capture_context = CaptureContext::new(&self.capture_source, pcap_path.as_ref());
-        self.pcap_error = capture_context.error().map(ToString::to_string);
+        let additional_sources = self.additional_capture_sources();
+        let pcap_path = if additional_sources.is_empty() {
+            self.export_pcap.full_path()
+        } else {
+            // each adapter is exported to its own file
+            self.export_pcap
+                .full_path_for_interface(&self.capture_source.get_name())
+        };
+        let capture_context = CaptureContext::new(
+            &self.capture_source,
+            pcap_path.as_ref(),
+            &self.filters,
+            self.monitor_mode,
+        );
+        let additional_contexts: Vec<(CaptureSource, CaptureContext)> = additional_sources
+            .into_iter()
+            .map(|source| {
+                let pcap_path = self.export_pcap.full_path_for_interface(&source.get_name());
+                let context = CaptureContext::new(
+                    &source,
+                    pcap_path.as_ref(),
+                    &self.filters,
+                    self.monitor_mode,
+                );
+                (source, context)
+            })
+            .collect();
+        self.pcap_error = capture_context
+            .error()
+            .map(ToString::to_string)
+            .or_else(|| {
+                additional_contexts.iter().find_map(|(source, context)| {
+                    context
+                        .error()
+                        .map(|e| format!("{}: {e}", source.get_name()))
+                })
+            });
         self.running_page = RunningPage::Overview;
 
-        if capture_context.error().is_none() {
+        if self.pcap_error.is_none() {
             // no pcap error
             let curr_cap_id = self.current_capture_rx.0;
-            let filters = self.filters.clone();
-            let mmdb_readers = self.mmdb_readers.clone();
             self.capture_source
                 .set_link_type(capture_context.my_link_type());
             let capture_source = self.capture_source.clone();
             self.traffic_chart
                 .change_capture_source(matches!(capture_source, CaptureSource::Device(_)));
             let (tx, rx) = async_channel::unbounded();
-            let _ = thread::Builder::new()
-                .name("thread_parse_packets".to_string())
-                .spawn(move || {
-                    parse_packets(
-                        curr_cap_id,
-                        capture_source,
-                        &filters,
-                        &mmdb_readers,
-                        capture_context,
-                        &tx,
-                    );
-                })
-                .log_err(location!());
+            if additional_contexts.is_empty() {
+                self.spawn_parse_packets(curr_cap_id, capture_source, capture_context, tx);
+            } else {
+                // one capture per adapter, whose messages are merged before reaching the UI
+                let mut receivers = Vec::new();
+                for (source, context) in
+                    std::iter::once((capture_source, capture_context)).chain(additional_contexts)
+                {
+                    let (tx_interface, rx_interface) = async_channel::unbounded();
+                    self.spawn_parse_packets(curr_cap_id, source, context, tx_interface);
+                    receivers.push(rx_interface);
+                }
+                let _ = thread::Builder::new()
+                    .name("thread_merge_ticks".to_string())
+                    .spawn(move || {
+                        merge_ticks(curr_cap_id, &receivers, &tx);
+                    })
+                    .log_err(location!());
+            }
             self.current_capture_rx.1 = Some(rx.clone());
             return Task::run(rx, |backend_msg| match backend_msg {
                 BackendTrafficMessage::TickRun(cap_id, msg, host_msg, no_more_packets) => {
@@ -782,6 +1088,43 @@ fn start(&mut self) -> Task<Message> {
         Task::none()
     }
 
+    fn spawn_parse_packets(
+        &self,
+        cap_id: usize,
+        mut capture_source: CaptureSource,
+        capture_context: CaptureContext,
+        tx: Sender<BackendTrafficMessage>,
+    ) {
+        let filters = self.filters.clone();
+        let mmdb_readers = self.mmdb_readers.clone();
+        capture_source.set_link_type(capture_context.my_link_type());
+        let _ = thread::Builder::new()
+            .name("thread_parse_packets".to_string())
+            .spawn(move || {
+                parse_packets(
+                    cap_id,
+                    capture_source,
+                    &filters,
+                    &mmdb_readers,
+                    capture_context,
+                    &tx,
+                );
+            })
+            .log_err(location!());
+    }
+
+    /// Network devices to capture along with the selected one (only for live captures)
+    fn additional_capture_sources(&self) -> Vec<CaptureSource> {
+        if !matches!(self.capture_source, CaptureSource::Device(_)) {
+            return Vec::new();
+        }
+        self.additional_devices
+            .iter()
+            .filter_map(|name| self.my_devices.iter().find(|dev| dev.get_name() == name))
+            .map(|dev| CaptureSource::Device(dev.clone()))
+            .collect()
+    }
+
     fn reset(&mut self) {
         // close capture channel to kill previous captures
         if let Some(rx) = &self.current_capture_rx.1 {
@@ -794,13 +1137,17 @@ fn reset(&mut self) {
         self.current_capture_rx = (self.current_capture_rx.0 + 1, None);
         self.info_traffic = InfoTraffic::default();
         self.addresses_resolved = HashMap::new();
-        self.favorite_hosts = HashSet::new();
         self.logged_notifications = (VecDeque::new(), 0);
+        self.notifications_state = NotificationsState::default();
+        self.blocklisted_addresses = HashSet::new();
+        self.labeled_hosts = HashMap::new();
+        self.mac_addresses = HashMap::new();
         self.pcap_error = None;
         self.traffic_chart = TrafficChart::new(style, language);
         self.report_sort_type = ReportSortType::default();
         self.host_sort_type = SortType::default();
         self.service_sort_type = SortType::default();
+        self.application_sort_type = SortType::default();
         self.modal = None;
         self.settings_page = None;
         self.running_page = RunningPage::Init;
@@ -809,12 +1156,80 @@ fn reset(&mut self) {
         self.page_number = 1;
         self.thumbnail = false;
         self.host_data_states = HostDataStates::default();
+        if let Some(capture_source) = self.capture_source_before_session.take() {
+            self.capture_source = capture_source;
+        }
+        // the link type of the source may be known now
+        self.filters
+            .validate_bpf(self.capture_source.get_link_type());
+    }
+
+    fn open_session(&mut self, path: String) {
+        if path.is_empty() {
+            return;
+        }
+        let session = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| Session::from_json(&json));
+        self.reset();
+        let capture_source = std::mem::replace(
+            &mut self.capture_source,
+            CaptureSource::File(MyPcapImport::new(path)),
+        );
+        self.capture_source_before_session = Some(capture_source);
+        self.running_page = RunningPage::Overview;
+
+        let session = match session {
+            Ok(session) => session,
+            Err(e) => {
+                self.pcap_error = Some(e);
+                return;
+            }
+        };
+        let ConfigSettings {
+            style, language, ..
+        } = self.configs.settings;
+        self.traffic_chart = session.traffic_chart;
+        self.traffic_chart.change_style(style);
+        self.traffic_chart.change_language(language);
+        let mut info_traffic = session.info_traffic;
+        for (host, data_info_host) in &mut info_traffic.hosts {
+            // favorites of the session are only displayed (they aren't added to the user's ones),
+            // and the current favorites are marked in the session
+            data_info_host.is_favorite |= self.favorite_hosts.contains(host);
+            self.host_data_states.data.update(host);
+        }
+        self.info_traffic = info_traffic;
+        self.addresses_resolved = session.addresses_resolved;
+        self.refresh_blocklisted();
+        self.refresh_labels();
+        self.logged_notifications = (session.logged_notifications, session.notifications_count);
+        self.host_data_states.update_states(&self.search);
+    }
+
+    fn import_favorites(&mut self, path: &str) {
+        if path.is_empty() {
+            return;
+        }
+        let imported = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| ConfigFavorites::from_toml(&content));
+        self.favorites_import_failed = imported.is_err();
+        for host in imported
+            .map(|favorites| favorites.hosts)
+            .unwrap_or_default()
+        {
+            self.add_or_remove_favorite(&host, true);
+        }
     }
 
     fn set_device(&mut self, name: &str) {
         for my_dev in &self.my_devices {
             if my_dev.get_name().eq(&name) {
                 self.capture_source = CaptureSource::Device(my_dev.clone());
+                self.additional_devices.retain(|n| n != name);
+                self.filters
+                    .validate_bpf(self.capture_source.get_link_type());
                 break;
             }
         }
@@ -845,8 +1260,10 @@ fn add_or_remove_favorite(&mut self, host: &Host, add: bool) {
         let info_traffic = &mut self.info_traffic;
         if add {
             self.favorite_hosts.insert(host.clone());
+            self.configs.favorites.add(host);
         } else {
             self.favorite_hosts.remove(host);
+            self.configs.favorites.remove(host);
         }
         if let Some(host_info) = info_traffic.hosts.get_mut(host) {
             host_info.is_favorite = add;
@@ -865,7 +1282,7 @@ fn close_settings(&mut self) {
     /// Threshold adjustments are saved in `self.timing_events.threshold_adjust` and then applied
     /// after timeout
     fn update_notifications_settings(&mut self, notification: Notification, emit_sound: bool) {
-        let notifications = self.configs.settings.notifications;
+        let data_notification = self.configs.settings.notifications.data_notification;
         let sound = match notification {
             Notification::Data(DataNotification {
                 chart_type,
@@ -873,6 +1290,7 @@ fn update_notifications_settings(&mut self, notification: Notification, emit_sou
                 byte_multiple,
                 sound,
                 previous_threshold,
+                alert_conditions,
             }) => {
                 let mut temp_threshold = self.get_temp_threshold();
                 if temp_threshold.threshold != threshold
@@ -885,10 +1303,11 @@ fn update_notifications_settings(&mut self, notification: Notification, emit_sou
                         byte_multiple,
                         sound,
                         previous_threshold,
+                        alert_conditions,
                     };
                     self.timing_events.threshold_adjust_now(temp_threshold);
                 }
-                if threshold.is_some() != notifications.data_notification.threshold.is_some() {
+                if threshold.is_some() != data_notification.threshold.is_some() {
                     self.configs
                         .settings
                         .notifications
@@ -906,6 +1325,21 @@ fn update_notifications_settings(&mut self, notification: Notification, emit_sou
                         .previous_threshold = previous_threshold;
                 }
                 self.configs.settings.notifications.data_notification.sound = sound;
+                if alert_conditions
+                    != self
+                        .configs
+                        .settings
+                        .notifications
+                        .data_notification
+                        .alert_conditions
+                {
+                    self.configs
+                        .settings
+                        .notifications
+                        .data_notification
+                        .alert_conditions = alert_conditions;
+                    self.notifications_state.thresholds.global = ThresholdState::default();
+                }
                 self.configs
                     .settings
                     .notifications
@@ -917,6 +1351,23 @@ fn update_notifications_settings(&mut self, notification: Notification, emit_sou
                 self.configs.settings.notifications.favorite_notification = favorite_notification;
                 favorite_notification.sound
             }
+            Notification::FirstSeen(first_seen_notification) => {
+                self.configs.settings.notifications.first_seen_notification =
+                    first_seen_notification;
+                first_seen_notification.sound
+            }
+            Notification::Anomaly(anomaly_notification) => {
+                self.configs.settings.notifications.anomaly_notification = anomaly_notification;
+                anomaly_notification.sound
+            }
+            Notification::Scan(scan_notification) => {
+                self.configs.settings.notifications.scan_notification = scan_notification;
+                scan_notification.sound
+            }
+            Notification::Blocklist(blocklist_notification) => {
+                self.configs.settings.notifications.blocklist_notification = blocklist_notification;
+                blocklist_notification.sound
+            }
         };
         if emit_sound {
             play(sound, self.configs.settings.notifications.volume);
@@ -928,8 +1379,7 @@ fn get_temp_threshold(&self) -> DataNotification {
         if let Some(temp_threshold) = self.timing_events.temp_threshold() {
             temp_threshold
         } else {
-            let notifications = self.configs.settings.notifications;
-            notifications.data_notification
+            self.configs.settings.notifications.data_notification
         }
     }
 
@@ -1047,39 +1497,167 @@ async fn open_file(old_file: String, file_info: FileInfo, language: Language) ->
             dialog.pick_folder().await
         } else {
             let extensions = file_info.get_extensions();
-            dialog
-                .add_filter(format!("{extensions:?}"), &extensions)
-                .pick_file()
-                .await
+            if extensions.is_empty() {
+                dialog.pick_file().await
+            } else {
+                dialog
+                    .add_filter(format!("{extensions:?}"), &extensions)
+                    .pick_file()
+                    .await
+            }
         }
         .unwrap_or_else(|| FileHandle::from(PathBuf::from(&old_file)));
 
         picked.path().to_string_lossy().to_string()
     }
 
+    /// Asks the user where to save the given content, and writes it there on a separate thread,
+    /// not to block the GUI while writing large files
+    ///
+    /// Returns the error to show to the user if the file couldn't be written
+    async fn save_file(
+        file_name: String,
+        content: String,
+        title: &'static str,
+    ) -> Result<(), String> {
+        let picked = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .set_directory(std::env::var("HOME").unwrap_or_default())
+            .set_file_name(file_name)
+            .save_file()
+            .await;
+
+        let Some(file) = picked else {
+            return Ok(());
+        };
+        let path = file.path().to_path_buf();
+        let (tx, rx) = async_channel::bounded(1);
+        thread::Builder::new()
+            .name("thread_save_file".to_string())
+            .spawn(move || {
+                let result = File::create(&path)
+                    .and_then(|mut f| f.write_all(content.as_bytes()))
+                    .map_err(|e| format!("{}: {e}", path.display()));
+                let _ = tx.send_blocking(result);
+            })
+            .map_err(|e| e.to_string())?;
+        rx.recv().await.map_err(|e| e.to_string())?
+    }
+
     fn handle_new_host(&mut self, host_msg: HostMessage) {
         let HostMessage {
             host,
-            data_info_host,
+            mut data_info_host,
             address_to_lookup,
             rdns,
         } = host_msg;
 
+        let is_blocklisted = self.blocklists.matches(&address_to_lookup, &rdns);
+        if is_blocklisted {
+            self.blocklisted_addresses.insert(address_to_lookup);
+        }
+        data_info_host.is_blocklisted = is_blocklisted;
+        data_info_host.is_favorite = self.favorite_hosts.contains(&host);
         self.info_traffic
             .hosts
             .entry(host.clone())
             .and_modify(|d| {
                 d.refresh(&data_info_host);
+                d.is_blocklisted |= is_blocklisted;
             })
             .or_insert(data_info_host);
 
         self.addresses_resolved
             .insert(address_to_lookup, (rdns, host.clone()));
+        self.label_address(address_to_lookup);
 
         // update host data states including the new host
         self.host_data_states.data.update(&host);
     }
 
+    /// Loads the blocklists selected in settings, without blocking the UI while reading the files
+    fn load_blocklists(&self) -> Task<Message> {
+        Task::perform(
+            Blocklists::load_in_background(self.configs.settings.blocklists.clone()),
+            Message::BlocklistsLoaded,
+        )
+    }
+
+    /// Matches again all the resolved addresses against the blocklists, after they have changed
+    fn refresh_blocklisted(&mut self) {
+        self.blocklisted_addresses.clear();
+        for data_info_host in self.info_traffic.hosts.values_mut() {
+            data_info_host.is_blocklisted = false;
+        }
+        for (address, (rdns, host)) in &self.addresses_resolved {
+            if self.blocklists.matches(address, rdns) {
+                self.blocklisted_addresses.insert(*address);
+                if let Some(data_info_host) = self.info_traffic.hosts.get_mut(host) {
+                    data_info_host.is_blocklisted = true;
+                }
+            }
+        }
+    }
+
+    /// Returns the label matching the given address.
+    ///
+    /// The MAC address is only considered for hosts in the local network,
+    /// since the others are all reached through the same gateway.
+    pub fn address_label(&self, address: &IpAddr, mac: Option<&str>) -> Option<&HostLabel> {
+        let is_local = self
+            .addresses_resolved
+            .get(address)
+            .and_then(|(_, host)| self.info_traffic.hosts.get(host))
+            .is_some_and(|data_info_host| data_info_host.is_local);
+        self.host_labels.get(address, mac.filter(|_| is_local))
+    }
+
+    /// Associates the label matching the given resolved address (if any) to its host
+    fn label_address(&mut self, address: IpAddr) {
+        let Some((_, host)) = self.addresses_resolved.get(&address) else {
+            return;
+        };
+        let mac = self.mac_addresses.get(&address).map(String::as_str);
+        if let Some(label) = self.address_label(&address, mac) {
+            let label = label.clone();
+            self.labeled_hosts.entry(host.clone()).or_insert(label);
+        }
+    }
+
+    /// Records the MAC addresses of the given connections, labeling the hosts they belong to
+    fn learn_mac_addresses(&mut self, map: &HashMap<AddressPortPair, InfoAddressPortPair>) {
+        if !self.host_labels.has_mac_targets() {
+            return;
+        }
+        for (key, val) in map {
+            for (address, mac) in [
+                (key.address1, &val.mac_address1),
+                (key.address2, &val.mac_address2),
+            ] {
+                if let Some(mac) = mac
+                    && !self.mac_addresses.contains_key(&address)
+                {
+                    self.mac_addresses.insert(address, mac.clone());
+                    self.label_address(address);
+                }
+            }
+        }
+    }
+
+    /// Matches again all the observed hosts against the labels, after they have changed
+    fn refresh_labels(&mut self) {
+        self.host_labels = HostLabels::new(&self.configs.settings.host_labels);
+        self.labeled_hosts.clear();
+        self.mac_addresses.clear();
+        let map = std::mem::take(&mut self.info_traffic.map);
+        self.learn_mac_addresses(&map);
+        self.info_traffic.map = map;
+        let addresses: Vec<IpAddr> = self.addresses_resolved.keys().copied().collect();
+        for address in addresses {
+            self.label_address(address);
+        }
+    }
+
     fn register_sigint_handler() -> Task<Message> {
         let (tx, rx) = async_channel::bounded(1);
 
@@ -1099,11 +1677,14 @@ mod tests {
 
     use std::collections::{HashSet, VecDeque};
     use std::fs::remove_file;
+    use std::net::IpAddr;
     use std::path::Path;
     use std::time::Duration;
 
+    use pcap::{Device, DeviceFlags};
     use serial_test::{parallel, serial};
 
+    use crate::configs::types::config_favorites::ConfigFavorites;
     use crate::configs::types::config_window::{PositionTuple, SizeTuple};
     use crate::countries::types::country::Country;
     use crate::gui::components::types::my_modal::MyModal;
@@ -1111,16 +1692,31 @@ mod tests {
     use crate::gui::styles::types::custom_palette::ExtraStyles;
     use crate::gui::styles::types::gradient_type::GradientType;
     use crate::gui::types::message::Message;
+    use crate::gui::types::session::Session;
     use crate::gui::types::timing_events::TimingEvents;
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::blocklist::Blocklists;
+    use crate::networking::types::capture_context::CaptureSource;
     use crate::networking::types::data_info::DataInfo;
-    use crate::networking::types::host::Host;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::host::{Host, HostMessage};
+    use crate::networking::types::host_label::HostLabel;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::networking::types::info_traffic::InfoTraffic;
+    use crate::networking::types::my_device::MyDevice;
     use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::notifications::notify_and_log::FirstSeenKind;
     use crate::notifications::types::logged_notification::{
         DataThresholdExceeded, LoggedNotification,
     };
+    use crate::notifications::types::notification_action::{
+        ActionTrigger, NotificationAction, NotificationActions,
+    };
     use crate::notifications::types::notifications::{
-        DataNotification, FavoriteNotification, Notification, Notifications,
+        AlertConditions, DataNotification, FavoriteNotification, Notification, Notifications,
+        ScopedDataNotification, ThresholdScope,
     };
+    use crate::notifications::types::notifications_history::{HistoryFilter, NotificationsHistory};
     use crate::notifications::types::sound::Sound;
     use crate::report::types::report_col::ReportCol;
     use crate::report::types::sort_type::SortType;
@@ -1149,6 +1745,12 @@ fn drop(&mut self) {
             if window_path.exists() {
                 remove_file(ConfigWindow::test_path()).unwrap();
             }
+
+            let favorites_path_str = ConfigFavorites::test_path();
+            let favorites_path = Path::new(&favorites_path_str);
+            if favorites_path.exists() {
+                remove_file(ConfigFavorites::test_path()).unwrap();
+            }
         }
     }
 
@@ -1217,6 +1819,7 @@ fn test_correctly_update_report_sort_kind() {
         let sort = ReportSortType {
             byte_sort: SortType::Neutral,
             packet_sort: SortType::Neutral,
+            rtt_sort: SortType::Neutral,
         };
 
         assert_eq!(sniffer.report_sort_type, sort);
@@ -1227,7 +1830,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
         sniffer.update(Message::ReportSortSelection(
@@ -1238,7 +1842,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Ascending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
         sniffer.update(Message::ReportSortSelection(
@@ -1249,7 +1854,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                rtt_sort: SortType::Neutral,
             }
         );
         sniffer.update(Message::ReportSortSelection(
@@ -1261,7 +1867,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
     }
@@ -1310,6 +1917,28 @@ fn test_correctly_update_service_sort_kind() {
         assert_eq!(sniffer.service_sort_type, SortType::Neutral);
     }
 
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_correctly_update_application_sort_kind() {
+        let mut sniffer = Sniffer::new(Configs::default());
+
+        let mut sort = SortType::Neutral;
+
+        assert_eq!(sniffer.application_sort_type, sort);
+
+        sort = sort.next_sort();
+        sniffer.update(Message::ApplicationSortSelection(sort));
+        assert_eq!(sniffer.application_sort_type, SortType::Descending);
+
+        sort = sort.next_sort();
+        sniffer.update(Message::ApplicationSortSelection(sort));
+        assert_eq!(sniffer.application_sort_type, SortType::Ascending);
+
+        sort = sort.next_sort();
+        sniffer.update(Message::ApplicationSortSelection(sort));
+        assert_eq!(sniffer.application_sort_type, SortType::Neutral);
+    }
+
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
     fn test_correctly_update_style() {
@@ -1667,6 +2296,7 @@ fn expire_notifications_timeout(sniffer: &mut Sniffer) {
             byte_multiple: ByteMultiple::KB,
             sound: Sound::Pop,
             previous_threshold: 800000,
+            alert_conditions: AlertConditions::default(),
         };
 
         let bytes_notification_toggled_on = DataNotification {
@@ -1675,6 +2305,7 @@ fn expire_notifications_timeout(sniffer: &mut Sniffer) {
             byte_multiple: ByteMultiple::GB,
             sound: Sound::Pop,
             previous_threshold: 800_000,
+            alert_conditions: AlertConditions::default(),
         };
 
         let bytes_notification_adjusted_threshold_sound_off = DataNotification {
@@ -1683,6 +2314,7 @@ fn expire_notifications_timeout(sniffer: &mut Sniffer) {
             byte_multiple: ByteMultiple::KB,
             sound: Sound::None,
             previous_threshold: 3,
+            alert_conditions: AlertConditions::default(),
         };
 
         let bytes_notification_sound_off_only = DataNotification {
@@ -1691,6 +2323,7 @@ fn expire_notifications_timeout(sniffer: &mut Sniffer) {
             byte_multiple: ByteMultiple::GB,
             sound: Sound::None,
             previous_threshold: 800_000,
+            alert_conditions: AlertConditions::default(),
         };
 
         let fav_notification_init = FavoriteNotification {
@@ -1798,6 +2431,46 @@ fn expire_notifications_timeout(sniffer: &mut Sniffer) {
         );
     }
 
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_history_keeps_all_emitted_notifications() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_sniffer_history_test_{}.jsonl",
+            std::process::id()
+        ));
+        let mut sniffer = Sniffer::new(Configs::default());
+        assert!(matches!(sniffer.capture_source, CaptureSource::Device(_)));
+        sniffer.notifications_history = NotificationsHistory::from_path(Some(path.clone()));
+
+        // more notifications than the ones kept in the log are emitted in a single tick
+        let first_seen = (1..=35)
+            .map(|i| {
+                let host_msg = HostMessage {
+                    host: Host {
+                        domain: format!("host{i}"),
+                        asn: Default::default(),
+                        country: Country::ZZ,
+                    },
+                    data_info_host: DataInfoHost::default(),
+                    address_to_lookup: IpAddr::from([10, 0, 0, i]),
+                    rdns: format!("host{i}"),
+                };
+                (FirstSeenKind::Host, host_msg)
+            })
+            .collect();
+        sniffer.log_notifications(&InfoTraffic::default(), first_seen, Vec::new());
+        assert_eq!(sniffer.logged_notifications.0.len(), 30);
+        assert_eq!(sniffer.unread_notifications, 35);
+
+        sniffer.notifications_history.load();
+        let (entries, tot) = sniffer
+            .notifications_history
+            .search(&HistoryFilter::default(), Language::EN);
+        assert_eq!(tot, 35);
+        assert_eq!(entries[0].id(), 35);
+        remove_file(&path).unwrap();
+    }
+
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
     fn test_clear_all_notifications() {
@@ -1813,6 +2486,7 @@ fn test_clear_all_notifications() {
                     services: Vec::new(),
                     hosts: Vec::new(),
                     is_expanded: false,
+                    scope: None,
                 },
             )]);
 
@@ -1825,6 +2499,449 @@ fn test_clear_all_notifications() {
         assert_eq!(sniffer.logged_notifications.0.len(), 0);
     }
 
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_add_update_and_remove_scoped_thresholds() {
+        let mut sniffer = Sniffer::new(Configs::default());
+        assert!(
+            sniffer
+                .configs
+                .settings
+                .notifications
+                .scoped_data_notifications
+                .is_empty()
+        );
+
+        sniffer.update(Message::AddScopedThreshold);
+        sniffer.update(Message::AddScopedThreshold);
+        let rules = &sniffer
+            .configs
+            .settings
+            .notifications
+            .scoped_data_notifications;
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].scope, ThresholdScope::Host(String::new()));
+        assert_eq!(rules[0].data_notification.threshold, Some(800_000));
+
+        let updated = ScopedDataNotification {
+            scope: ThresholdScope::Country("IT".to_string()),
+            data_notification: DataNotification {
+                threshold: Some(10_000_000),
+                byte_multiple: ByteMultiple::MB,
+                ..DataNotification::default()
+            },
+        };
+        sniffer.update(Message::UpdateScopedThreshold(1, updated.clone()));
+        // out of bounds indexes are ignored
+        sniffer.update(Message::UpdateScopedThreshold(5, updated.clone()));
+        sniffer.update(Message::RemoveScopedThreshold(5));
+        sniffer.update(Message::RemoveScopedThreshold(0));
+        assert_eq!(
+            sniffer
+                .configs
+                .settings
+                .notifications
+                .scoped_data_notifications,
+            vec![updated]
+        );
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_blocklisted_hosts() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_sniffer_blocklist_test_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "# test list\n203.0.113.0/24\nexample.com\n").unwrap();
+        let path = path.to_string_lossy().to_string();
+        let host_msg = |address: IpAddr, domain: &str| HostMessage {
+            host: Host {
+                domain: domain.to_string(),
+                asn: Default::default(),
+                country: Country::ZZ,
+            },
+            data_info_host: DataInfoHost::default(),
+            address_to_lookup: address,
+            rdns: domain.to_string(),
+        };
+        let listed_address = IpAddr::from([203, 0, 113, 9]);
+        let listed_domain = IpAddr::from([93, 184, 216, 34]);
+        let other = IpAddr::from([8, 8, 8, 8]);
+
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.handle_new_host(host_msg(listed_address, "203.0.113.9"));
+        sniffer.handle_new_host(host_msg(listed_domain, "www.example.com"));
+        sniffer.handle_new_host(host_msg(other, "dns.google"));
+        assert!(sniffer.blocklisted_addresses.is_empty());
+
+        // adding a blocklist flags the hosts already observed, once it's loaded
+        sniffer.update(Message::AddBlocklist(path.clone()));
+        sniffer.update(Message::AddBlocklist(path.clone()));
+        assert_eq!(sniffer.configs.settings.blocklists, vec![path.clone()]);
+        assert!(sniffer.blocklists.lists().is_empty());
+        // loads that don't match the selected files anymore are discarded
+        sniffer.update(Message::BlocklistsLoaded(Blocklists::default()));
+        assert!(sniffer.blocklists.lists().is_empty());
+        sniffer.update(Message::BlocklistsLoaded(Blocklists::load(
+            std::slice::from_ref(&path),
+        )));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(sniffer.blocklists.lists()[0].entries_count(), 2);
+        assert_eq!(
+            sniffer.blocklisted_addresses,
+            HashSet::from([listed_address, listed_domain])
+        );
+        let is_blocklisted = |sniffer: &Sniffer, domain: &str| {
+            sniffer
+                .info_traffic
+                .hosts
+                .iter()
+                .find(|(host, _)| host.domain == domain)
+                .is_some_and(|(_, data_info_host)| data_info_host.is_blocklisted)
+        };
+        assert!(is_blocklisted(&sniffer, "203.0.113.9"));
+        assert!(is_blocklisted(&sniffer, "www.example.com"));
+        assert!(!is_blocklisted(&sniffer, "dns.google"));
+
+        // and new hosts are checked as soon as they're resolved
+        sniffer.handle_new_host(host_msg(IpAddr::from([203, 0, 113, 10]), "other.net"));
+        assert!(is_blocklisted(&sniffer, "other.net"));
+
+        sniffer.update(Message::RemoveBlocklist(0));
+        assert!(sniffer.configs.settings.blocklists.is_empty());
+        sniffer.update(Message::BlocklistsLoaded(Blocklists::default()));
+        assert!(sniffer.blocklisted_addresses.is_empty());
+        assert!(!is_blocklisted(&sniffer, "203.0.113.9"));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_host_labels() {
+        let host_msg = |address: IpAddr, domain: &str, is_local: bool| HostMessage {
+            host: Host {
+                domain: domain.to_string(),
+                asn: Default::default(),
+                country: Country::ZZ,
+            },
+            data_info_host: DataInfoHost {
+                is_local,
+                ..DataInfoHost::default()
+            },
+            address_to_lookup: address,
+            rdns: domain.to_string(),
+        };
+        let host = |domain: &str| Host {
+            domain: domain.to_string(),
+            asn: Default::default(),
+            country: Country::ZZ,
+        };
+        let label = |target: &str, name: &str, group: &str| HostLabel {
+            target: target.to_string(),
+            name: name.to_string(),
+            group: group.to_string(),
+        };
+        let nas = IpAddr::from([192, 168, 1, 10]);
+        let printer = IpAddr::from([192, 168, 1, 20]);
+        let remote = IpAddr::from([8, 8, 8, 8]);
+
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.handle_new_host(host_msg(nas, "nas.local", true));
+        sniffer.handle_new_host(host_msg(printer, "printer.local", true));
+        sniffer.handle_new_host(host_msg(remote, "dns.google", false));
+        // both hosts sent traffic through the same gateway
+        for (address, mac) in [
+            (printer, "aa:bb:cc:dd:ee:ff"),
+            (remote, "00:11:22:33:44:55"),
+        ] {
+            sniffer.info_traffic.map.insert(
+                AddressPortPair {
+                    address1: address,
+                    port1: Some(443),
+                    address2: nas,
+                    port2: Some(50_000),
+                    protocol: Protocol::TCP,
+                },
+                InfoAddressPortPair {
+                    mac_address1: Some(mac.to_string()),
+                    ..InfoAddressPortPair::default()
+                },
+            );
+        }
+
+        // invalid labels can't be added
+        sniffer.update(Message::HostLabelInput(label("nas", "NAS", "")));
+        sniffer.update(Message::AddHostLabel);
+        assert!(sniffer.configs.settings.host_labels.is_empty());
+
+        sniffer.update(Message::HostLabelInput(label(
+            " 192.168.1.10 ",
+            "NAS",
+            "home",
+        )));
+        sniffer.update(Message::AddHostLabel);
+        sniffer.update(Message::HostLabelInput(label(
+            "AA-BB-CC-DD-EE-FF",
+            "",
+            "printers",
+        )));
+        sniffer.update(Message::AddHostLabel);
+        sniffer.update(Message::HostLabelInput(label(
+            "00:11:22:33:44:55",
+            "gw",
+            "",
+        )));
+        sniffer.update(Message::AddHostLabel);
+        assert_eq!(sniffer.host_label_input, HostLabel::default());
+        assert_eq!(sniffer.configs.settings.host_labels.len(), 3);
+        assert_eq!(
+            sniffer.configs.settings.host_labels[0].target,
+            "192.168.1.10"
+        );
+        assert_eq!(sniffer.labeled_hosts.len(), 2);
+        assert_eq!(
+            sniffer
+                .labeled_hosts
+                .get(&host("nas.local"))
+                .unwrap()
+                .text(),
+            "NAS [home]"
+        );
+        assert_eq!(
+            sniffer
+                .labeled_hosts
+                .get(&host("printer.local"))
+                .unwrap()
+                .text(),
+            "[printers]"
+        );
+        // the MAC address of a remote host isn't its own
+        assert!(!sniffer.labeled_hosts.contains_key(&host("dns.google")));
+
+        sniffer.update(Message::RemoveHostLabel(0));
+        assert_eq!(sniffer.configs.settings.host_labels.len(), 2);
+        assert_eq!(sniffer.labeled_hosts.len(), 1);
+        assert!(!sniffer.labeled_hosts.contains_key(&host("nas.local")));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_additional_devices_selection() {
+        let device = |name: &str| {
+            MyDevice::from_pcap_device(Device {
+                name: name.to_string(),
+                desc: None,
+                addresses: vec![],
+                flags: DeviceFlags::empty(),
+            })
+        };
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.my_devices = vec![device("eth0"), device("eth1"), device("wg0")];
+        sniffer.update(Message::DeviceSelection("eth0".to_string()));
+
+        sniffer.update(Message::AdditionalDeviceSelection("wg0".to_string(), true));
+        sniffer.update(Message::AdditionalDeviceSelection("eth1".to_string(), true));
+        sniffer.update(Message::AdditionalDeviceSelection("wg0".to_string(), true));
+        // the selected device is always captured
+        sniffer.update(Message::AdditionalDeviceSelection("eth0".to_string(), true));
+        assert_eq!(sniffer.additional_devices, vec!["eth1", "wg0"]);
+        let names = |sniffer: &Sniffer| -> Vec<String> {
+            sniffer
+                .additional_capture_sources()
+                .iter()
+                .map(CaptureSource::get_name)
+                .collect()
+        };
+        assert_eq!(names(&sniffer), vec!["eth1", "wg0"]);
+
+        // selecting an additional device makes it the main one
+        sniffer.update(Message::DeviceSelection("wg0".to_string()));
+        assert_eq!(sniffer.capture_source.get_name(), "wg0");
+        assert_eq!(sniffer.additional_devices, vec!["eth1"]);
+
+        // devices not available anymore aren't captured
+        sniffer.my_devices.pop();
+        sniffer.my_devices.pop();
+        assert!(names(&sniffer).is_empty());
+
+        sniffer.update(Message::AdditionalDeviceSelection(
+            "eth1".to_string(),
+            false,
+        ));
+        assert!(sniffer.additional_devices.is_empty());
+
+        // additional devices are ignored when reading a file
+        sniffer.my_devices = vec![device("eth0"), device("eth1")];
+        sniffer.update(Message::AdditionalDeviceSelection("eth1".to_string(), true));
+        sniffer.update(Message::SetPcapImport("capture.pcap".to_string()));
+        assert!(names(&sniffer).is_empty());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_favorites_are_kept() {
+        let host = |domain: &str| Host {
+            domain: domain.to_string(),
+            asn: Default::default(),
+            country: Country::US,
+        };
+        let host_msg = |domain: &str, address: IpAddr| HostMessage {
+            host: host(domain),
+            data_info_host: DataInfoHost::default(),
+            address_to_lookup: address,
+            rdns: domain.to_string(),
+        };
+
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.update(Message::AddOrRemoveFavorite(host("a.com"), true));
+        sniffer.update(Message::AddOrRemoveFavorite(host("b.com"), true));
+        sniffer.update(Message::AddOrRemoveFavorite(host("a.com"), false));
+        sniffer.update(Message::AddOrRemoveFavorite(host("c.com"), true));
+        assert_eq!(
+            sniffer.configs.favorites.hosts,
+            vec![host("b.com"), host("c.com")]
+        );
+        // favorites survive the end of a capture...
+        sniffer.update(Message::Reset);
+        assert_eq!(
+            sniffer.favorite_hosts,
+            HashSet::from([host("b.com"), host("c.com")])
+        );
+
+        // ...and are restored from the configs, marking the hosts as soon as they're observed
+        let mut restored = Sniffer::new(sniffer.configs.clone());
+        assert_eq!(restored.favorite_hosts, sniffer.favorite_hosts);
+        restored.handle_new_host(host_msg("b.com", IpAddr::from([1, 1, 1, 1])));
+        restored.handle_new_host(host_msg("d.com", IpAddr::from([2, 2, 2, 2])));
+        assert!(restored.info_traffic.hosts[&host("b.com")].is_favorite);
+        assert!(!restored.info_traffic.hosts[&host("d.com")].is_favorite);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_import_favorites() {
+        let host = |domain: &str| Host {
+            domain: domain.to_string(),
+            asn: Default::default(),
+            country: Country::IT,
+        };
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_favorites_test_{}.toml",
+            std::process::id()
+        ));
+        let exported = ConfigFavorites {
+            hosts: vec![host("a.com"), host("b.com")],
+        };
+        std::fs::write(&path, exported.to_toml()).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.update(Message::AddOrRemoveFavorite(host("b.com"), true));
+        sniffer.update(Message::ImportFavorites(path.clone()));
+        remove_file(&path).unwrap();
+        assert!(!sniffer.favorites_import_failed);
+        // already present favorites aren't duplicated
+        assert_eq!(
+            sniffer.configs.favorites.hosts,
+            vec![host("b.com"), host("a.com")]
+        );
+        assert_eq!(
+            sniffer.favorite_hosts,
+            HashSet::from([host("a.com"), host("b.com")])
+        );
+
+        // a dismissed file dialog is ignored
+        sniffer.update(Message::ImportFavorites(String::new()));
+        assert!(!sniffer.favorites_import_failed);
+
+        sniffer.update(Message::ImportFavorites("Cargo.toml".to_string()));
+        assert!(sniffer.favorites_import_failed);
+        assert_eq!(sniffer.configs.favorites.hosts.len(), 2);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_file_saved() {
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.settings_page = Some(SettingsPage::General);
+
+        // saved or dismissed file dialog
+        sniffer.update(Message::FileSaved(Ok(())));
+        assert_eq!(sniffer.modal, None);
+
+        // the error is shown even if settings are opened, and they're restored once it's hidden
+        sniffer.update(Message::FileSaved(Err("permission denied".to_string())));
+        assert_eq!(
+            sniffer.modal,
+            Some(MyModal::SaveFailed("permission denied".to_string()))
+        );
+        sniffer.update(Message::HideModal);
+        assert_eq!(sniffer.modal, None);
+        assert_eq!(sniffer.settings_page, Some(SettingsPage::General));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_save_and_open_session() {
+        let host = Host {
+            domain: "example.com".to_string(),
+            asn: Default::default(),
+            country: Country::US,
+        };
+        let address = IpAddr::from([93, 184, 216, 34]);
+
+        let mut sniffer = Sniffer::new(Configs::default());
+        sniffer.info_traffic.all_packets = 10;
+        sniffer.info_traffic.hosts.insert(
+            host.clone(),
+            DataInfoHost {
+                data_info: DataInfo::new_for_tests(4, 6, 400, 600),
+                is_favorite: true,
+                is_bogon: Some("loopback"),
+                is_blocklisted: true,
+                ..DataInfoHost::default()
+            },
+        );
+        sniffer
+            .addresses_resolved
+            .insert(address, ("example.com".to_string(), host.clone()));
+        sniffer.logged_notifications.1 = 3;
+        let path = std::env::temp_dir().join("sniffnet_test_session.json");
+        std::fs::write(&path, Session::new(&sniffer).to_json()).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let mut restored = Sniffer::new(Configs::default());
+        let device_name = restored.capture_source.get_name();
+        restored.update(Message::OpenSession(path.clone()));
+        remove_file(&path).unwrap();
+        assert_eq!(restored.running_page, RunningPage::Overview);
+        assert_eq!(restored.pcap_error, None);
+        assert_eq!(restored.info_traffic.all_packets, 10);
+        let data_info_host = restored.info_traffic.hosts.get(&host).unwrap();
+        assert_eq!(data_info_host.data_info.tot_bytes(), 1000);
+        assert!(data_info_host.is_favorite);
+        assert_eq!(data_info_host.is_bogon, Some("loopback"));
+        // blocklist matches are computed again against the currently loaded blocklists
+        assert!(!data_info_host.is_blocklisted);
+        assert_eq!(restored.addresses_resolved, sniffer.addresses_resolved);
+        // the favorites of the session don't change the user's ones
+        assert!(restored.favorite_hosts.is_empty());
+        assert!(restored.configs.favorites.hosts.is_empty());
+        assert_eq!(restored.logged_notifications.1, 3);
+        assert_eq!(restored.capture_source.get_name(), path);
+
+        // the previously selected device is selected again when leaving the session
+        restored.update(Message::Reset);
+        assert_eq!(restored.running_page, RunningPage::Init);
+        assert_eq!(restored.capture_source.get_name(), device_name);
+
+        // invalid session files are reported
+        restored.update(Message::OpenSession("Cargo.toml".to_string()));
+        assert_eq!(restored.running_page, RunningPage::Overview);
+        assert!(restored.pcap_error.is_some());
+        assert_eq!(restored.info_traffic.all_packets, 0);
+    }
+
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
     fn test_correctly_switch_running_and_settings_pages() {
@@ -1908,11 +3025,20 @@ fn test_config_settings() {
                 scale_factor: 1.0,
                 mmdb_country: "".to_string(),
                 mmdb_asn: "".to_string(),
+                blocklists: vec![],
+                host_labels: vec![],
                 style_path: "".to_string(),
                 notifications: Notifications {
                     volume: 60,
                     data_notification: Default::default(),
-                    favorite_notification: Default::default()
+                    scoped_data_notifications: Vec::new(),
+                    favorite_notification: Default::default(),
+                    first_seen_notification: Default::default(),
+                    anomaly_notification: Default::default(),
+                    scan_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    desktop_notifications: false,
+                    actions: Default::default()
                 },
                 style: StyleType::Custom(ExtraStyles::A11yDark)
             }
@@ -1930,6 +3056,11 @@ fn test_config_settings() {
         )));
         sniffer.update(Message::Style(StyleType::Custom(ExtraStyles::DraculaDark)));
         sniffer.update(Message::ChangeVolume(100));
+        sniffer.update(Message::ToggleDesktopNotifications(true));
+        sniffer.update(Message::UpdateNotificationAction(
+            ActionTrigger::Favorite,
+            NotificationAction::Webhook("http://localhost:8080/hook".to_string()),
+        ));
 
         // quit the app by sending a CloseRequested message
         sniffer.update(Message::Quit);
@@ -1946,6 +3077,8 @@ fn test_config_settings() {
                 scale_factor: 1.0,
                 mmdb_country: "countrymmdb".to_string(),
                 mmdb_asn: "asnmmdb".to_string(),
+                blocklists: vec![],
+                host_labels: vec![],
                 style_path: format!(
                     "{}/resources/themes/catppuccin.toml",
                     env!("CARGO_MANIFEST_DIR")
@@ -1953,7 +3086,19 @@ fn test_config_settings() {
                 notifications: Notifications {
                     volume: 100,
                     data_notification: Default::default(),
-                    favorite_notification: Default::default()
+                    scoped_data_notifications: Vec::new(),
+                    favorite_notification: Default::default(),
+                    first_seen_notification: Default::default(),
+                    anomaly_notification: Default::default(),
+                    scan_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    desktop_notifications: true,
+                    actions: NotificationActions {
+                        favorite: NotificationAction::Webhook(
+                            "http://localhost:8080/hook".to_string()
+                        ),
+                        ..Default::default()
+                    }
                 },
                 style: StyleType::Custom(ExtraStyles::DraculaDark)
             }
@@ -48,6 +48,28 @@ pub fn full_path(&self) -> Option<String> {
             None
         }
     }
+
+    /// Path of the file where the packets of the given adapter are exported,
+    /// when capturing from more than one adapter at once
+    pub fn full_path_for_interface(&self, interface: &str) -> Option<String> {
+        let full_path = PathBuf::from(self.full_path()?);
+        // adapter names can contain characters not allowed in file names
+        let interface: String = interface
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let stem = full_path.file_stem().unwrap_or_default().to_string_lossy();
+        let file_name = match full_path.extension() {
+            Some(extension) => format!("{stem}_{interface}.{}", extension.to_string_lossy()),
+            None => format!("{stem}_{interface}"),
+        };
+        Some(
+            full_path
+                .with_file_name(file_name)
+                .to_string_lossy()
+                .to_string(),
+        )
+    }
 }
 
 impl Default for ExportPcap {
@@ -163,4 +185,27 @@ fn test_full_path() {
         export_pcap.set_directory("".to_string());
         assert_eq!(export_pcap.full_path(), Some("sniffnet.pcap".to_string()));
     }
+
+    #[test]
+    fn test_full_path_for_interface() {
+        let mut export_pcap = ExportPcap::default();
+        assert_eq!(export_pcap.full_path_for_interface("eth0"), None);
+
+        export_pcap.toggle();
+        export_pcap.set_directory("/tmp".to_string());
+        let mut full_path = PathBuf::from("/tmp");
+        full_path.push("sniffnet_eth0.pcap");
+        assert_eq!(
+            export_pcap.full_path_for_interface("eth0"),
+            Some(full_path.to_string_lossy().to_string())
+        );
+
+        export_pcap.set_file_name("capture");
+        let mut full_path = PathBuf::from("/tmp");
+        full_path.push("capture__Device_NPF__1A2B_");
+        assert_eq!(
+            export_pcap.full_path_for_interface("\\Device\\NPF_{1A2B}"),
+            Some(full_path.to_string_lossy().to_string())
+        );
+    }
 }
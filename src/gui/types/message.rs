@@ -5,9 +5,14 @@
 use crate::gui::pages::types::running_page::RunningPage;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::types::gradient_type::GradientType;
+use crate::networking::types::blocklist::Blocklists;
 use crate::networking::types::host::{Host, HostMessage};
+use crate::networking::types::host_label::HostLabel;
 use crate::networking::types::info_traffic::InfoTraffic;
-use crate::notifications::types::notifications::Notification;
+use crate::notifications::types::notification_action::{ActionTrigger, NotificationAction};
+use crate::notifications::types::notifications::{Notification, ScopedDataNotification};
+use crate::notifications::types::notifications_history::HistoryFilter;
+use crate::report::types::export_format::ExportFormat;
 use crate::report::types::search_parameters::SearchParameters;
 use crate::report::types::sort_type::SortType;
 use crate::utils::types::file_info::FileInfo;
@@ -16,6 +21,7 @@
 
 #[derive(Debug, Clone)]
 /// Messages types that permit reacting to application interactions/subscriptions
+#[allow(clippy::large_enum_variant)]
 pub enum Message {
     /// Run tasks to initialize the app
     StartApp(Option<window::Id>),
@@ -23,6 +29,10 @@ pub enum Message {
     TickRun(usize, InfoTraffic, Vec<HostMessage>, bool),
     /// Select network device
     DeviceSelection(String),
+    /// Add or remove a network device to capture along with the selected one
+    AdditionalDeviceSelection(String, bool),
+    /// Whether to capture wireless devices in monitor mode
+    MonitorMode(bool),
     /// Select IP filter
     IpVersionSelection(IpVersion, bool),
     /// Select protocol filter
@@ -31,6 +41,10 @@ pub enum Message {
     AddressFilter(String),
     /// Changed port filter
     PortFilter(String),
+    /// Changed VLAN IDs filter
+    VlanFilter(String),
+    /// Changed BPF filter expression
+    BpfFilter(String),
     /// Select chart type to be displayed
     ChartSelection(ChartType),
     /// Select report sort type to be displayed (inspect page)
@@ -39,6 +53,8 @@ pub enum Message {
     HostSortSelection(SortType),
     /// Select service sort type to be displayed (overview page)
     ServiceSortSelection(SortType),
+    /// Select application sort type to be displayed (overview page)
+    ApplicationSortSelection(SortType),
     /// Adds or removes the given host into/from the favorites
     AddOrRemoveFavorite(Host, bool),
     /// Open the supplied web page
@@ -69,8 +85,16 @@ pub enum Message {
     UpdateNotificationSettings(Notification, bool),
     /// Clear all received notifications
     ClearAllNotifications,
+    /// Browse the notifications history (true) or the notifications of the current capture (false)
+    ShowNotificationsHistory(bool),
+    /// Search the notifications history
+    SearchNotificationsHistory(HistoryFilter),
     /// Set notifications volume
     ChangeVolume(u8),
+    /// Enable or disable desktop notifications
+    ToggleDesktopNotifications(bool),
+    /// Set the action executed when a notification of the given type is emitted
+    UpdateNotificationAction(ActionTrigger, NotificationAction),
     /// Switch from a page to the next (previous) one if true (false), when the tab (shift+tab) key is pressed.
     SwitchPage(bool),
     /// The enter (return) key has been pressed
@@ -101,6 +125,18 @@ pub enum Message {
     CustomCountryDb(String),
     /// The ASN MMDB custom path has been updated
     CustomAsnDb(String),
+    /// Add the blocklist file at the given path
+    AddBlocklist(String),
+    /// Remove the blocklist at the given index
+    RemoveBlocklist(usize),
+    /// The blocklists have been loaded from the selected files
+    BlocklistsLoaded(Blocklists),
+    /// Edit the host label being defined in settings
+    HostLabelInput(HostLabel),
+    /// Add the host label being defined in settings
+    AddHostLabel,
+    /// Remove the host label at the given index
+    RemoveHostLabel(usize),
     /// Wrapper around the Quit message
     QuitWrapper,
     /// Save the configurations of the app and quit
@@ -135,4 +171,22 @@ pub enum Message {
     Periodic,
     /// Expand or collapse the given logged notification
     ExpandNotification(usize, bool),
+    /// Export the connections matching the current search parameters to a file in the given format
+    ExportSearchResults(ExportFormat),
+    /// Add a new data threshold restricted to specific traffic
+    AddScopedThreshold,
+    /// Update the scoped data threshold at the given index
+    UpdateScopedThreshold(usize, ScopedDataNotification),
+    /// Remove the scoped data threshold at the given index
+    RemoveScopedThreshold(usize),
+    /// Save the current capture data to a session file
+    SaveSession,
+    /// Restore the capture data stored in the session file at the given path
+    OpenSession(String),
+    /// Add the favorite hosts listed in the file at the given path
+    ImportFavorites(String),
+    /// Save the favorite hosts to a file
+    ExportFavorites,
+    /// The outcome of saving a file, with the error to show to the user in case of failure
+    FileSaved(Result<(), String>),
 }
@@ -1,3 +1,4 @@
 pub mod export_pcap;
 pub mod message;
+pub mod session;
 pub mod timing_events;
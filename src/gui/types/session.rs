@@ -0,0 +1,49 @@
+//! Module defining the `Session` struct, a snapshot of a capture that can be saved to a file and restored later.
+
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::chart::types::traffic_chart::TrafficChart;
+use crate::gui::sniffer::Sniffer;
+use crate::networking::types::host::Host;
+use crate::networking::types::info_traffic::InfoTraffic;
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::utils::map_as_vec;
+
+/// Data of a capture, as needed to restore the overview and inspect pages without parsing packets again
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    /// Capture data
+    pub info_traffic: InfoTraffic,
+    /// Map of the resolved addresses with their full rDNS value and the corresponding host
+    #[serde(with = "map_as_vec")]
+    pub addresses_resolved: HashMap<IpAddr, (String, Host)>,
+    /// Chart data
+    pub traffic_chart: TrafficChart,
+    /// Log of the displayed notifications
+    pub logged_notifications: VecDeque<LoggedNotification>,
+    /// Total number of notifications emitted during the capture
+    pub notifications_count: usize,
+}
+
+impl Session {
+    pub fn new(sniffer: &Sniffer) -> Self {
+        Self {
+            info_traffic: sniffer.info_traffic.clone(),
+            addresses_resolved: sniffer.addresses_resolved.clone(),
+            traffic_chart: sniffer.traffic_chart.clone(),
+            logged_notifications: sniffer.logged_notifications.0.clone(),
+            notifications_count: sniffer.logged_notifications.1,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
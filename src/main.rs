@@ -23,6 +23,7 @@
 use networking::types::ip_version::IpVersion;
 use networking::types::protocol::Protocol;
 use networking::types::service::Service;
+use notifications::types::notifications_history::NotificationsHistory;
 use report::types::report_sort_type::ReportSortType;
 use translations::types::language::Language;
 use utils::formatted_strings::print_cli_welcome_message;
@@ -115,5 +116,10 @@ pub fn main() -> iced::Result {
         .subscription(Sniffer::subscription)
         .theme(Sniffer::theme)
         .scale_factor(Sniffer::scale_factor)
-        .run_with(move || (Sniffer::new(configs), boot_task_chain))
+        .run_with(move || {
+            let mut sniffer = Sniffer::new(configs);
+            sniffer.notifications_history =
+                NotificationsHistory::from_path(NotificationsHistory::default_path());
+            (sniffer, boot_task_chain)
+        })
 }
@@ -1,19 +1,25 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-use etherparse::{EtherType, LaxPacketHeaders, LinkHeader, NetHeaders, TransportHeader};
+use etherparse::{
+    EtherType, LaxPacketHeaders, LaxPayloadSlice, LinkExtHeader, LinkHeader, NetHeaders,
+    SingleVlanHeader, TransportHeader,
+};
 use pcap::Address;
 
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::arp_type::ArpType;
 use crate::networking::types::bogon::is_bogon;
 use crate::networking::types::capture_context::CaptureSource;
+use crate::networking::types::dns_message::DnsMessage;
 use crate::networking::types::icmp_type::{IcmpType, IcmpTypeV4, IcmpTypeV6};
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::networking::types::info_traffic::InfoTraffic;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
 use crate::networking::types::service::Service;
 use crate::networking::types::service_query::ServiceQuery;
+use crate::networking::types::tcp_flags::{TcpFlags, TcpFlagsCount, TcpSegment};
+use crate::networking::types::tcp_rtt::TcpRtt;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::networking::types::traffic_type::TrafficType;
 use crate::{IpVersion, Protocol};
@@ -21,6 +27,20 @@
 
 include!(concat!(env!("OUT_DIR"), "/services.rs"));
 
+/// Returns the static name of a known service given its textual representation
+pub fn get_service_name(name: &str) -> Option<&'static str> {
+    static NAMES: std::sync::LazyLock<HashSet<&'static str>> = std::sync::LazyLock::new(|| {
+        SERVICES
+            .values()
+            .filter_map(|service| match service {
+                Service::Name(name) => Some(*name),
+                _ => None,
+            })
+            .collect()
+    });
+    NAMES.get(name).copied()
+}
+
 /// Calls methods to analyze link, network, and transport headers.
 /// Returns the relevant collected information.
 pub fn analyze_headers(
@@ -29,16 +49,20 @@ pub fn analyze_headers(
     exchanged_bytes: &mut u128,
     icmp_type: &mut IcmpType,
     arp_type: &mut ArpType,
+    tcp_segment: &mut TcpSegment,
     packet_filters_fields: &mut PacketFiltersFields,
 ) -> Option<AddressPortPair> {
     analyze_link_header(
         headers.link,
+        &headers.link_exts,
         &mut mac_addresses.0,
         &mut mac_addresses.1,
         exchanged_bytes,
+        &mut packet_filters_fields.vlan_ids,
     );
 
     let is_arp = matches!(&headers.net, Some(NetHeaders::Arp(_)));
+    let ip_payload_len = get_ip_payload_len(headers.net.as_ref());
 
     if !analyze_network_header(
         headers.net,
@@ -58,6 +82,8 @@ pub fn analyze_headers(
             &mut packet_filters_fields.dport,
             &mut packet_filters_fields.protocol,
             icmp_type,
+            tcp_segment,
+            ip_payload_len,
         )
     {
         return None;
@@ -77,9 +103,11 @@ pub fn analyze_headers(
 /// Returns false if packet has to be skipped.
 fn analyze_link_header(
     link_header: Option<LinkHeader>,
+    link_exts: &[LinkExtHeader],
     mac_address1: &mut Option<String>,
     mac_address2: &mut Option<String>,
     exchanged_bytes: &mut u128,
+    vlan_ids: &mut Vec<u16>,
 ) {
     if let Some(LinkHeader::Ethernet2(header)) = link_header {
         *exchanged_bytes += 14;
@@ -89,6 +117,15 @@ fn analyze_link_header(
         *mac_address1 = None;
         *mac_address2 = None;
     }
+
+    // 802.1Q tags, possibly stacked (QinQ)
+    vlan_ids.clear();
+    for link_ext in link_exts {
+        if let LinkExtHeader::Vlan(vlan_header) = link_ext {
+            *exchanged_bytes += SingleVlanHeader::LEN as u128;
+            vlan_ids.push(vlan_header.vlan_id.value());
+        }
+    }
 }
 
 /// This function analyzes the network layer header passed as parameter and updates variables
@@ -155,6 +192,39 @@ fn analyze_network_header(
     }
 }
 
+/// Returns the transport layer payload of a packet (empty if not UDP or TCP)
+pub fn get_transport_payload<'a>(headers: &LaxPacketHeaders<'a>) -> &'a [u8] {
+    match headers.payload {
+        LaxPayloadSlice::Udp { payload, .. } | LaxPayloadSlice::Tcp { payload, .. } => payload,
+        _ => &[],
+    }
+}
+
+/// Returns the DNS message carried by a packet, if any
+pub fn get_dns_message(key: &AddressPortPair, transport_payload: &[u8]) -> Option<DnsMessage> {
+    if key.port1 != Some(53) && key.port2 != Some(53) {
+        return None;
+    }
+    match key.protocol {
+        Protocol::UDP => DnsMessage::parse(transport_payload),
+        // over TCP, each message is prefixed by its length
+        Protocol::TCP => DnsMessage::parse(transport_payload.get(2..)?),
+        _ => None,
+    }
+}
+
+/// Returns the length in bytes of the network layer payload (0 if not IP)
+fn get_ip_payload_len(network_header: Option<&NetHeaders>) -> usize {
+    match network_header {
+        Some(NetHeaders::Ipv4(ipv4header, ipv4_exts)) => usize::from(ipv4header.total_len)
+            .saturating_sub(ipv4header.header_len() + ipv4_exts.header_len()),
+        Some(NetHeaders::Ipv6(ipv6header, ipv6_exts)) => {
+            usize::from(ipv6header.payload_length).saturating_sub(ipv6_exts.header_len())
+        }
+        _ => 0,
+    }
+}
+
 /// This function analyzes the transport layer header passed as parameter and updates variables
 /// passed by reference on the basis of the packet header content.
 /// Returns false if packet has to be skipped.
@@ -164,6 +234,8 @@ fn analyze_transport_header(
     port2: &mut Option<u16>,
     protocol: &mut Protocol,
     icmp_type: &mut IcmpType,
+    tcp_segment: &mut TcpSegment,
+    ip_payload_len: usize,
 ) -> bool {
     match transport_header {
         Some(TransportHeader::Udp(udp_header)) => {
@@ -176,6 +248,7 @@ fn analyze_transport_header(
             *port1 = Some(tcp_header.source_port);
             *port2 = Some(tcp_header.destination_port);
             *protocol = Protocol::TCP;
+            *tcp_segment = TcpSegment::from_etherparse(&tcp_header, ip_payload_len);
             true
         }
         Some(TransportHeader::Icmpv4(icmpv4_header)) => {
@@ -248,6 +321,7 @@ pub fn get_service(
 }
 
 /// Function to insert the source and destination of a packet into the map containing the analyzed traffic
+#[allow(clippy::too_many_arguments)]
 pub fn modify_or_insert_in_map(
     info_traffic_msg: &mut InfoTraffic,
     key: &AddressPortPair,
@@ -255,7 +329,9 @@ pub fn modify_or_insert_in_map(
     mac_addresses: (Option<String>, Option<String>),
     icmp_type: IcmpType,
     arp_type: ArpType,
+    tcp_flags: TcpFlags,
     exchanged_bytes: u128,
+    link_direction: Option<TrafficDirection>,
 ) -> (TrafficDirection, Service) {
     let mut traffic_direction = TrafficDirection::default();
     let mut service = Service::Unknown;
@@ -264,16 +340,18 @@ pub fn modify_or_insert_in_map(
         // first occurrence of key (in this time interval)
 
         let my_interface_addresses = cs.get_addresses();
-        // determine traffic direction
+        // determine traffic direction, unless it was already recorded at the link layer
         let source_ip = &key.address1;
         let destination_ip = &key.address2;
-        traffic_direction = get_traffic_direction(
-            source_ip,
-            destination_ip,
-            key.port1,
-            key.port2,
-            my_interface_addresses,
-        );
+        traffic_direction = link_direction.unwrap_or_else(|| {
+            get_traffic_direction(
+                source_ip,
+                destination_ip,
+                key.port1,
+                key.port2,
+                my_interface_addresses,
+            )
+        });
         // determine upper layer service
         service = get_service(key, traffic_direction, my_interface_addresses);
     }
@@ -298,6 +376,9 @@ pub fn modify_or_insert_in_map(
                     .and_modify(|n| *n += 1)
                     .or_insert(1);
             }
+            if key.protocol.eq(&Protocol::TCP) {
+                info.tcp_flags.add(tcp_flags, timestamp);
+            }
         })
         .or_insert_with(|| InfoAddressPortPair {
             mac_address1: mac_addresses.0,
@@ -318,6 +399,23 @@ pub fn modify_or_insert_in_map(
             } else {
                 HashMap::new()
             },
+            tcp_flags: {
+                let mut tcp_flags_count = TcpFlagsCount::default();
+                if key.protocol.eq(&Protocol::TCP) {
+                    tcp_flags_count.add(tcp_flags, timestamp);
+                }
+                tcp_flags_count
+            },
+            tcp_rtt: TcpRtt::default(),
+            tls_client_hello: None,
+            process: None,
+            interfaces: match cs {
+                CaptureSource::Device(device) => BTreeSet::from([device.get_name().clone()]),
+                CaptureSource::File(_) => BTreeSet::new(),
+            },
+            wifi: None,
+            vlan_ids: BTreeSet::new(),
+            mpls_labels: BTreeSet::new(),
         });
 
     (new_info.traffic_direction, new_info.service)
@@ -488,7 +586,7 @@ pub fn is_my_address(local_address: &IpAddr, my_interface_addresses: &Vec<Addres
 }
 
 /// Converts a MAC address in its hexadecimal form
-fn mac_from_dec_to_hex(mac_dec: [u8; 6]) -> String {
+pub fn mac_from_dec_to_hex(mac_dec: [u8; 6]) -> String {
     let mut mac_hex = String::new();
     for n in &mac_dec {
         let _ = write!(mac_hex, "{n:02x}:");
@@ -514,7 +612,7 @@ mod tests {
     use crate::Protocol;
     use crate::Service;
     use crate::networking::manage_packets::{
-        get_service, get_traffic_direction, get_traffic_type, is_local_connection,
+        get_dns_message, get_service, get_traffic_direction, get_traffic_type, is_local_connection,
         mac_from_dec_to_hex,
     };
     use crate::networking::types::address_port_pair::AddressPortPair;
@@ -1896,4 +1994,35 @@ fn test_other_service_names() {
             &Service::Name("murmur")
         );
     }
+
+    #[test]
+    fn test_get_dns_message() {
+        // query for example.com (A)
+        let query = [
+            0x12, 0x34, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, b'e',
+            b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00, 0x00, 0x01, 0x00,
+            0x01,
+        ];
+        let mut tcp_query = vec![0x00, 0x1D];
+        tcp_query.extend_from_slice(&query);
+
+        let key = |port2, protocol| {
+            AddressPortPair::new(
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+                Some(55555),
+                IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)),
+                Some(port2),
+                protocol,
+            )
+        };
+
+        let udp_msg = get_dns_message(&key(53, Protocol::UDP), &query).unwrap();
+        assert_eq!(udp_msg.query_name, "example.com");
+        assert_eq!(
+            get_dns_message(&key(53, Protocol::TCP), &tcp_query),
+            Some(udp_msg)
+        );
+        assert_eq!(get_dns_message(&key(5353, Protocol::UDP), &query), None);
+        assert_eq!(get_dns_message(&key(53, Protocol::UDP), &[]), None);
+    }
 }
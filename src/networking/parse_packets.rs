@@ -1,12 +1,13 @@
 //! Module containing functions executed by the thread in charge of parsing sniffed packets
 
+use crate::Protocol;
 use crate::location;
 use crate::mmdb::asn::get_asn;
 use crate::mmdb::country::get_country;
 use crate::mmdb::types::mmdb_reader::MmdbReaders;
 use crate::networking::manage_packets::{
-    analyze_headers, get_address_to_lookup, get_traffic_type, is_local_connection,
-    modify_or_insert_in_map,
+    analyze_headers, get_address_to_lookup, get_dns_message, get_traffic_type,
+    get_transport_payload, is_local_connection, mac_from_dec_to_hex, modify_or_insert_in_map,
 };
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::arp_type::ArpType;
@@ -14,23 +15,32 @@
 use crate::networking::types::capture_context::{CaptureContext, CaptureSource};
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dns_log_entry::DnsLogEntry;
+use crate::networking::types::dns_message::DnsResponseCode;
+use crate::networking::types::dns_resolutions::DnsResolutions;
 use crate::networking::types::filters::Filters;
 use crate::networking::types::host::{Host, HostMessage};
 use crate::networking::types::icmp_type::IcmpType;
 use crate::networking::types::info_traffic::InfoTraffic;
+use crate::networking::types::linux_sll_header::LinuxSllHeader;
 use crate::networking::types::my_link_type::MyLinkType;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
+use crate::networking::types::process::ProcessResolver;
+use crate::networking::types::tcp_flags::TcpSegment;
+use crate::networking::types::tcp_rtt::TcpRttTracker;
+use crate::networking::types::tls_client_hello::TlsClientHelloTracker;
 use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::networking::types::wifi_frame::WifiFrame;
 use crate::utils::error_logger::{ErrorLogger, Location};
 use crate::utils::formatted_strings::get_domain_from_r_dns;
 use crate::utils::types::timestamp::Timestamp;
-use async_channel::Sender;
+use async_channel::{Receiver, Sender};
 use dns_lookup::lookup_addr;
 use etherparse::err::ip::{HeaderError, LaxHeaderSliceError};
 use etherparse::err::{Layer, LenError};
-use etherparse::{LaxPacketHeaders, LenSource};
+use etherparse::{EtherType, LaxPacketHeaders, LaxPayloadSlice, LenSource};
 use pcap::{Address, Device, Packet};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::net::IpAddr;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -49,6 +59,11 @@ pub fn parse_packets(
     let (mut cap, mut savefile) = capture_context.consume();
 
     let mut info_traffic_msg = InfoTraffic::default();
+    let mut tcp_rtt_tracker = TcpRttTracker::default();
+    let mut tls_client_hello_tracker = TlsClientHelloTracker::default();
+    // processes can only be attributed in live captures
+    let process_resolver = matches!(cs, CaptureSource::Device(_)).then(ProcessResolver::new);
+    let mut dns_resolutions = DnsResolutions::default();
     let resolutions_state = Arc::new(Mutex::new(AddressesResolutionState::default()));
     // list of newly resolved hosts to be sent (batched to avoid UI updates too often)
     let new_hosts_to_send = Arc::new(Mutex::new(Vec::new()));
@@ -97,7 +112,10 @@ pub fn parse_packets(
                 }
             }
             Ok(packet) => {
-                if let Ok(headers) = get_sniffable_headers(&packet, my_link_type) {
+                if let Ok(Some((headers, link_header))) =
+                    get_sniffable_headers(&packet, my_link_type)
+                {
+                    let (headers, mpls_labels) = decode_mpls(headers);
                     #[allow(clippy::useless_conversion)]
                     let secs = i64::from(packet.header.ts.tv_sec);
                     #[allow(clippy::useless_conversion)]
@@ -122,7 +140,14 @@ pub fn parse_packets(
                     let mut mac_addresses = (None, None);
                     let mut icmp_type = IcmpType::default();
                     let mut arp_type = ArpType::default();
+                    let mut tcp_segment = TcpSegment::default();
                     let mut packet_filters_fields = PacketFiltersFields::default();
+                    let transport_payload = get_transport_payload(&headers);
+                    let (linux_sll_header, wifi_frame) = match link_header {
+                        LinkHeader::Decoded => (None, None),
+                        LinkHeader::LinuxSll(linux_sll_header) => (Some(linux_sll_header), None),
+                        LinkHeader::Wifi(wifi_frame) => (None, Some(wifi_frame)),
+                    };
 
                     let key_option = analyze_headers(
                         headers,
@@ -130,12 +155,27 @@ pub fn parse_packets(
                         &mut exchanged_bytes,
                         &mut icmp_type,
                         &mut arp_type,
+                        &mut tcp_segment,
                         &mut packet_filters_fields,
                     );
 
                     let Some(key) = key_option else {
                         continue;
                     };
+                    // 4 bytes for each label stack entry
+                    exchanged_bytes += 4 * mpls_labels.len() as u128;
+
+                    // cooked captures don't include an Ethernet header, but record the sender address
+                    if let Some(linux_sll_header) = &linux_sll_header {
+                        mac_addresses.0 = linux_sll_header.source_mac();
+                    }
+                    // the same goes for 802.11 frames, whose addresses depend on the distribution system
+                    if let Some(wifi_frame) = &wifi_frame {
+                        mac_addresses = (
+                            Some(mac_from_dec_to_hex(wifi_frame.mac_addresses.0)),
+                            Some(mac_from_dec_to_hex(wifi_frame.mac_addresses.1)),
+                        );
+                    }
 
                     let passed_filters = filters.matches(&packet_filters_fields);
                     if passed_filters {
@@ -151,12 +191,119 @@ pub fn parse_packets(
                             mac_addresses,
                             icmp_type,
                             arp_type,
+                            tcp_segment.flags,
                             exchanged_bytes,
+                            linux_sll_header
+                                .as_ref()
+                                .and_then(LinuxSllHeader::traffic_direction),
                         );
 
+                        if key.protocol.eq(&Protocol::TCP) {
+                            let tcp_rtt =
+                                tcp_rtt_tracker.update(&key, &tcp_segment, next_packet_timestamp);
+                            tls_client_hello_tracker.update(
+                                &key,
+                                &tcp_segment,
+                                transport_payload,
+                                next_packet_timestamp,
+                            );
+                            if let Some(info) = info_traffic_msg.map.get_mut(&key) {
+                                info.tcp_rtt.refresh(&tcp_rtt);
+                                if info.tls_client_hello.is_none() {
+                                    info.tls_client_hello =
+                                        tls_client_hello_tracker.get(&key).cloned();
+                                }
+                            }
+                        }
+
+                        // keep track of the signal and channel of the last frame (only in monitor mode)
+                        if let Some(wifi_frame) = &wifi_frame
+                            && let Some(info) = info_traffic_msg.map.get_mut(&key)
+                        {
+                            info.wifi = Some(wifi_frame.wifi_info.clone());
+                        }
+
+                        // keep track of the VLANs and MPLS labels the connection is carried over
+                        if let Some(info) = info_traffic_msg.map.get_mut(&key) {
+                            info.vlan_ids.extend(&packet_filters_fields.vlan_ids);
+                            info.mpls_labels.extend(&mpls_labels);
+                        }
+
+                        // attribute the connection to a local process
+                        if let Some(process_resolver) = &process_resolver
+                            && let Some(info) = info_traffic_msg.map.get_mut(&key)
+                        {
+                            if info.process.is_none() {
+                                info.process = process_resolver.get(
+                                    &key,
+                                    traffic_direction,
+                                    cs.get_addresses(),
+                                );
+                            }
+                            if let Some(process) = &info.process {
+                                info_traffic_msg
+                                    .applications
+                                    .entry(process.name.clone())
+                                    .and_modify(|data_info| {
+                                        data_info.add_packet(exchanged_bytes, traffic_direction);
+                                    })
+                                    .or_insert_with(|| {
+                                        DataInfo::new_with_first_packet(
+                                            exchanged_bytes,
+                                            traffic_direction,
+                                        )
+                                    });
+                            }
+                        }
+
+                        if let Some(dns_message) = get_dns_message(&key, transport_payload)
+                            && dns_message.is_response
+                        {
+                            if dns_message.response_code == DnsResponseCode::NoError {
+                                for answer in &dns_message.answers {
+                                    dns_resolutions.insert(*answer, dns_message.query_name.clone());
+                                }
+                            }
+                            info_traffic_msg.dns_log.push_back(DnsLogEntry::new(
+                                next_packet_timestamp,
+                                &key,
+                                dns_message,
+                            ));
+                        }
+
                         info_traffic_msg
                             .tot_data_info
                             .add_packet(exchanged_bytes, traffic_direction);
+                        if let CaptureSource::Device(device) = &cs {
+                            if let Some(data_info) =
+                                info_traffic_msg.interfaces.get_mut(device.get_name())
+                            {
+                                data_info.add_packet(exchanged_bytes, traffic_direction);
+                            } else {
+                                info_traffic_msg.interfaces.insert(
+                                    device.get_name().clone(),
+                                    DataInfo::new_with_first_packet(
+                                        exchanged_bytes,
+                                        traffic_direction,
+                                    ),
+                                );
+                            }
+                        }
+                        // the same VLAN ID could be repeated in stacked tags
+                        for vlan_id in BTreeSet::from_iter(&packet_filters_fields.vlan_ids) {
+                            info_traffic_msg
+                                .vlans
+                                .entry(*vlan_id)
+                                .and_modify(|data_info| {
+                                    data_info.add_packet(exchanged_bytes, traffic_direction);
+                                })
+                                .or_insert_with(|| {
+                                    DataInfo::new_with_first_packet(
+                                        exchanged_bytes,
+                                        traffic_direction,
+                                    )
+                                });
+                        }
 
                         // check the rDNS status of this address and act accordingly
                         let address_to_lookup = get_address_to_lookup(&key, traffic_direction);
@@ -186,27 +333,41 @@ pub fn parse_packets(
                                 );
                                 drop(resolutions_lock);
 
-                                // launch new thread to resolve host name
-                                let key2 = key;
-                                let resolutions_state2 = resolutions_state.clone();
-                                let new_hosts_to_send2 = new_hosts_to_send.clone();
-                                let interface_addresses = cs.get_addresses().clone();
-                                let mmdb_readers_2 = mmdb_readers.clone();
-                                let tx2 = tx.clone();
-                                let _ = thread::Builder::new()
-                                    .name("thread_reverse_dns_lookup".to_string())
-                                    .spawn(move || {
-                                        reverse_dns_lookup(
-                                            &resolutions_state2,
-                                            &new_hosts_to_send2,
-                                            &key2,
-                                            traffic_direction,
-                                            &interface_addresses,
-                                            &mmdb_readers_2,
-                                            &tx2,
-                                        );
-                                    })
-                                    .log_err(location!());
+                                if let Some(query_name) = dns_resolutions.get(&address_to_lookup) {
+                                    // the name this address was queried for has been observed in a DNS response:
+                                    // use it, instead of performing a rDNS lookup
+                                    resolve_address(
+                                        &resolutions_state,
+                                        &new_hosts_to_send,
+                                        address_to_lookup,
+                                        query_name.clone(),
+                                        traffic_direction,
+                                        cs.get_addresses(),
+                                        mmdb_readers,
+                                    );
+                                } else {
+                                    // launch new thread to resolve host name
+                                    let key2 = key;
+                                    let resolutions_state2 = resolutions_state.clone();
+                                    let new_hosts_to_send2 = new_hosts_to_send.clone();
+                                    let interface_addresses = cs.get_addresses().clone();
+                                    let mmdb_readers_2 = mmdb_readers.clone();
+                                    let tx2 = tx.clone();
+                                    let _ = thread::Builder::new()
+                                        .name("thread_reverse_dns_lookup".to_string())
+                                        .spawn(move || {
+                                            reverse_dns_lookup(
+                                                &resolutions_state2,
+                                                &new_hosts_to_send2,
+                                                &key2,
+                                                traffic_direction,
+                                                &interface_addresses,
+                                                &mmdb_readers_2,
+                                                &tx2,
+                                            );
+                                        })
+                                        .log_err(location!());
+                                }
                             }
                             (true, false) => {
                                 // waiting for a previously requested rDNS resolution
@@ -258,6 +419,7 @@ pub fn parse_packets(
                                             is_loopback,
                                             is_local,
                                             is_bogon,
+                                            is_blocklisted: false,
                                             traffic_type,
                                         }
                                     });
@@ -289,19 +451,121 @@ pub fn parse_packets(
     }
 }
 
+/// Link layer header of a packet
+enum LinkHeader<'a> {
+    /// The link layer (if any) is decoded by etherparse itself
+    Decoded,
+    /// Linux cooked capture header
+    LinuxSll(LinuxSllHeader),
+    /// IEEE 802.11 data frame
+    Wifi(WifiFrame<'a>),
+}
+
+/// Returns the headers of the given packet, together with the link layer header etherparse doesn't support.
+///
+/// Returns `Ok(None)` if the packet doesn't carry anything to analyse (e.g., 802.11 management frames).
 fn get_sniffable_headers<'a>(
     packet: &'a Packet,
     my_link_type: MyLinkType,
-) -> Result<LaxPacketHeaders<'a>, LaxHeaderSliceError> {
-    match my_link_type {
+) -> Result<Option<(LaxPacketHeaders<'a>, LinkHeader<'a>)>, LaxHeaderSliceError> {
+    let headers = match my_link_type {
         MyLinkType::Ethernet(_) | MyLinkType::Unsupported(_) | MyLinkType::NotYetAssigned => {
-            LaxPacketHeaders::from_ethernet(packet).map_err(LaxHeaderSliceError::Len)
+            LaxPacketHeaders::from_ethernet(packet).map_err(LaxHeaderSliceError::Len)?
         }
         MyLinkType::RawIp(_) | MyLinkType::IPv4(_) | MyLinkType::IPv6(_) => {
-            LaxPacketHeaders::from_ip(packet)
+            LaxPacketHeaders::from_ip(packet)?
+        }
+        MyLinkType::Null(_) | MyLinkType::Loop(_) => from_null(packet)?,
+        MyLinkType::LinuxSll(_) | MyLinkType::LinuxSll2(_) => {
+            let (headers, linux_sll_header) = from_linux_sll(packet, my_link_type)?;
+            return Ok(Some((headers, LinkHeader::LinuxSll(linux_sll_header))));
+        }
+        MyLinkType::Ieee80211(_) | MyLinkType::Radiotap(_) => {
+            return Ok(from_wifi(packet, my_link_type)
+                .map(|(headers, wifi_frame)| (headers, LinkHeader::Wifi(wifi_frame))));
+        }
+    };
+    Ok(Some((headers, LinkHeader::Decoded)))
+}
+
+/// Decodes the MPLS label stack following the link layer, since etherparse doesn't support it.
+///
+/// Returns the headers of the IP packet carried over MPLS, and the labels of the stack (top first).
+fn decode_mpls(headers: LaxPacketHeaders<'_>) -> (LaxPacketHeaders<'_>, Vec<u32>) {
+    const MPLS_UNICAST: u16 = 0x8847;
+    const MPLS_MULTICAST: u16 = 0x8848;
+
+    let LaxPayloadSlice::Ether(ether_payload) = &headers.payload else {
+        return (headers, Vec::new());
+    };
+    if headers.net.is_some() || !matches!(ether_payload.ether_type.0, MPLS_UNICAST | MPLS_MULTICAST)
+    {
+        return (headers, Vec::new());
+    }
+
+    let mut labels = Vec::new();
+    let mut rest = ether_payload.payload;
+    loop {
+        let Some((entry, tail)) = rest.split_first_chunk::<4>() else {
+            // truncated label stack
+            return (headers, Vec::new());
+        };
+        let entry = u32::from_be_bytes(*entry);
+        labels.push(entry >> 12);
+        rest = tail;
+        // bottom of stack
+        if entry & 0x100 != 0 {
+            break;
         }
-        MyLinkType::Null(_) | MyLinkType::Loop(_) => from_null(packet),
     }
+
+    // MPLS doesn't identify its payload: only IP packets are analysed (e.g., not Ethernet pseudowires)
+    match LaxPacketHeaders::from_ip(rest) {
+        Ok(ip_headers) => (
+            LaxPacketHeaders {
+                link: headers.link,
+                link_exts: headers.link_exts,
+                ..ip_headers
+            },
+            labels,
+        ),
+        Err(_) => (headers, labels),
+    }
+}
+
+/// Returns `None` for management and control frames, as well as encrypted data,
+/// since they don't carry any analysable packet
+fn from_wifi(
+    packet: &[u8],
+    my_link_type: MyLinkType,
+) -> Option<(LaxPacketHeaders<'_>, WifiFrame<'_>)> {
+    let wifi_frame = WifiFrame::from_packet(packet, my_link_type)?;
+    let headers =
+        LaxPacketHeaders::from_ether_type(EtherType(wifi_frame.ether_type), wifi_frame.payload);
+    Some((headers, wifi_frame))
+}
+
+fn from_linux_sll(
+    packet: &[u8],
+    my_link_type: MyLinkType,
+) -> Result<(LaxPacketHeaders<'_>, LinuxSllHeader), LaxHeaderSliceError> {
+    let Some(header) = LinuxSllHeader::from_packet(packet, my_link_type) else {
+        return Err(LaxHeaderSliceError::Len(LenError {
+            required_len: if matches!(my_link_type, MyLinkType::LinuxSll2(_)) {
+                LinuxSllHeader::SLL2_LEN
+            } else {
+                LinuxSllHeader::SLL_LEN
+            },
+            len: packet.len(),
+            len_source: LenSource::Slice,
+            layer: Layer::LinuxSllHeader,
+            layer_start_offset: 0,
+        }));
+    };
+
+    let headers =
+        LaxPacketHeaders::from_ether_type(EtherType(header.protocol_type), &packet[header.len..]);
+    Ok((headers, header))
 }
 
 fn from_null(packet: &[u8]) -> Result<LaxPacketHeaders, LaxHeaderSliceError> {
@@ -356,13 +620,6 @@ fn reverse_dns_lookup(
     // perform rDNS lookup
     let lookup_result = lookup_addr(&address_to_lookup);
 
-    // get new host info and build the new host
-    let traffic_type = get_traffic_type(&address_to_lookup, interface_addresses, traffic_direction);
-    let is_loopback = address_to_lookup.is_loopback();
-    let is_local = is_local_connection(&address_to_lookup, interface_addresses);
-    let is_bogon = is_bogon(&address_to_lookup);
-    let country = get_country(&address_to_lookup, &mmdb_readers.country);
-    let asn = get_asn(&address_to_lookup, &mmdb_readers.asn);
     let rdns = if let Ok(result) = lookup_result {
         if result.is_empty() {
             address_to_lookup.to_string()
@@ -372,6 +629,36 @@ fn reverse_dns_lookup(
     } else {
         address_to_lookup.to_string()
     };
+
+    resolve_address(
+        resolutions_state,
+        new_hosts_to_send,
+        address_to_lookup,
+        rdns,
+        traffic_direction,
+        interface_addresses,
+        mmdb_readers,
+    );
+}
+
+/// Builds the host corresponding to an address given its name,
+/// and adds it to the resolved hosts and to the list of hosts to be sent
+fn resolve_address(
+    resolutions_state: &Arc<Mutex<AddressesResolutionState>>,
+    new_hosts_to_send: &Arc<Mutex<Vec<HostMessage>>>,
+    address_to_lookup: IpAddr,
+    rdns: String,
+    traffic_direction: TrafficDirection,
+    interface_addresses: &Vec<Address>,
+    mmdb_readers: &MmdbReaders,
+) {
+    // get new host info and build the new host
+    let traffic_type = get_traffic_type(&address_to_lookup, interface_addresses, traffic_direction);
+    let is_loopback = address_to_lookup.is_loopback();
+    let is_local = is_local_connection(&address_to_lookup, interface_addresses);
+    let is_bogon = is_bogon(&address_to_lookup);
+    let country = get_country(&address_to_lookup, &mmdb_readers.country);
+    let asn = get_asn(&address_to_lookup, &mmdb_readers.asn);
     let new_host = Host {
         domain: get_domain_from_r_dns(rdns.clone()),
         asn,
@@ -395,6 +682,7 @@ fn reverse_dns_lookup(
         is_favorite: false,
         is_local,
         is_bogon,
+        is_blocklisted: false,
         is_loopback,
         traffic_type,
     };
@@ -425,6 +713,77 @@ pub enum BackendTrafficMessage {
     OfflineGap(usize, u32),
 }
 
+/// The calling thread enters a loop in which it merges the messages sent by the captures
+/// running on different network adapters, forwarding them as a single message every second
+///
+/// Each capture sends a message every second, but the captures aren't in phase:
+/// every merged message includes at most one message from each capture, so that it always
+/// refers to a single second of traffic.
+pub fn merge_ticks(
+    cap_id: usize,
+    receivers: &[Receiver<BackendTrafficMessage>],
+    tx: &Sender<BackendTrafficMessage>,
+) {
+    // dropped packets are counted separately by each capture
+    let mut dropped_packets = vec![0; receivers.len()];
+    let mut last_packet_timestamp = Timestamp::default();
+    // messages received from each capture and not forwarded yet, with the instant of their arrival
+    let mut pending_ticks: Vec<VecDeque<(Instant, InfoTraffic, Vec<HostMessage>)>> =
+        receivers.iter().map(|_| VecDeque::new()).collect();
+
+    loop {
+        if tx.is_closed() {
+            // let the captures know they must stop
+            for rx in receivers {
+                rx.close();
+            }
+            return;
+        }
+
+        for (rx, pending) in receivers.iter().zip(pending_ticks.iter_mut()) {
+            while let Ok(msg) = rx.try_recv() {
+                // live captures only send ticks
+                if let BackendTrafficMessage::TickRun(_, msg, host_msgs, _) = msg {
+                    pending.push_back((Instant::now(), msg, host_msgs));
+                }
+            }
+        }
+
+        // wait for a message from each capture, but not longer than a second
+        // (captures that haven't seen any packet yet don't send messages)
+        let all_received = pending_ticks.iter().all(|pending| !pending.is_empty());
+        let oldest_expired = pending_ticks
+            .iter()
+            .filter_map(|pending| pending.front().map(|(instant, ..)| *instant))
+            .min()
+            .is_some_and(|instant| instant.elapsed() >= Duration::from_millis(1000));
+        if !all_received && !oldest_expired {
+            thread::sleep(Duration::from_millis(50));
+            continue;
+        }
+
+        let mut info_traffic_msg = InfoTraffic::default();
+        let mut new_hosts = Vec::new();
+        for (pending, dropped) in pending_ticks.iter_mut().zip(dropped_packets.iter_mut()) {
+            if let Some((_, msg, host_msgs)) = pending.pop_front() {
+                *dropped = msg.dropped_packets;
+                last_packet_timestamp = last_packet_timestamp.max(msg.last_packet_timestamp);
+                info_traffic_msg.merge(&msg);
+                new_hosts.extend(host_msgs);
+            }
+        }
+        info_traffic_msg.dropped_packets = dropped_packets.iter().sum();
+        info_traffic_msg.last_packet_timestamp = last_packet_timestamp;
+
+        let _ = tx.send_blocking(BackendTrafficMessage::TickRun(
+            cap_id,
+            info_traffic_msg,
+            new_hosts,
+            false,
+        ));
+    }
+}
+
 fn maybe_send_tick_run_live(
     cap_id: usize,
     info_traffic_msg: &mut InfoTraffic,
@@ -479,3 +838,273 @@ fn maybe_send_tick_run_offline(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use etherparse::{PacketBuilder, VlanId};
+    use pcap::Linktype;
+
+    use super::*;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+
+    /// Tick sent by the capture running on the given adapter, including a single connection
+    fn tick(
+        interface: &str,
+        bytes: u128,
+        dropped_packets: u32,
+        secs: i64,
+    ) -> BackendTrafficMessage {
+        let data_info = DataInfo::new_for_tests(1, 0, bytes, 0);
+        let key = AddressPortPair {
+            address1: IpAddr::from([10, 0, 0, 1]),
+            port1: Some(443),
+            address2: IpAddr::from([10, 0, 0, 2]),
+            port2: Some(50_000),
+            protocol: Protocol::TCP,
+        };
+        let info = InfoAddressPortPair {
+            transmitted_bytes: bytes,
+            transmitted_packets: 1,
+            interfaces: BTreeSet::from([interface.to_string()]),
+            ..InfoAddressPortPair::default()
+        };
+        let info_traffic = InfoTraffic {
+            tot_data_info: data_info,
+            all_packets: 1,
+            all_bytes: bytes,
+            dropped_packets,
+            last_packet_timestamp: Timestamp::new(secs, 0),
+            map: HashMap::from([(key, info)]),
+            interfaces: HashMap::from([(interface.to_string(), data_info)]),
+            ..InfoTraffic::default()
+        };
+        BackendTrafficMessage::TickRun(0, info_traffic, Vec::new(), false)
+    }
+
+    #[test]
+    fn test_linux_cooked_capture_headers() {
+        let mut ip_packet = Vec::new();
+        PacketBuilder::ipv4([192, 168, 1, 2], [9, 9, 9, 9], 64)
+            .udp(50_000, 53)
+            .write(&mut ip_packet, &[0; 10])
+            .unwrap();
+        // outgoing packet captured on an Ethernet interface, preceded by a SLL2 header
+        let mut data = vec![0x08, 0x00, 0, 0, 0, 0, 0, 3, 0, 1, 4, 6];
+        data.extend([0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f, 0, 0]);
+        data.extend(&ip_packet);
+        let my_link_type = MyLinkType::from_pcap_link_type(Linktype::LINUX_SLL2);
+
+        let (headers, linux_sll_header) = from_linux_sll(&data, my_link_type).unwrap();
+        let mut packet_filters_fields = PacketFiltersFields::default();
+        let key = analyze_headers(
+            headers,
+            &mut (None, None),
+            &mut 0,
+            &mut IcmpType::default(),
+            &mut ArpType::default(),
+            &mut TcpSegment::default(),
+            &mut packet_filters_fields,
+        )
+        .unwrap();
+        assert_eq!(key.address1, IpAddr::from([192, 168, 1, 2]));
+        assert_eq!(key.port2, Some(53));
+        assert_eq!(key.protocol, Protocol::UDP);
+        assert_eq!(
+            linux_sll_header.traffic_direction(),
+            Some(TrafficDirection::Outgoing)
+        );
+
+        // truncated cooked header
+        assert!(from_linux_sll(&data[..10], my_link_type).is_err());
+    }
+
+    #[test]
+    fn test_wifi_headers() {
+        let mut ip_packet = Vec::new();
+        PacketBuilder::ipv4([192, 168, 1, 2], [9, 9, 9, 9], 64)
+            .udp(50_000, 53)
+            .write(&mut ip_packet, &[0; 10])
+            .unwrap();
+        // data frame sent by a station to the access point, followed by the LLC/SNAP header
+        let mut data = vec![0x08, 0x01, 0, 0];
+        data.extend([[2; 6], [1; 6], [3; 6]].concat());
+        data.extend([0, 0]);
+        data.extend([0xaa, 0xaa, 0x03, 0, 0, 0, 0x08, 0x00]);
+        data.extend(&ip_packet);
+        let my_link_type = MyLinkType::from_pcap_link_type(Linktype::IEEE802_11);
+
+        let (headers, wifi_frame) = from_wifi(&data, my_link_type).unwrap();
+        assert_eq!(wifi_frame.mac_addresses, ([1; 6], [3; 6]));
+        let key = analyze_headers(
+            headers,
+            &mut (None, None),
+            &mut 0,
+            &mut IcmpType::default(),
+            &mut ArpType::default(),
+            &mut TcpSegment::default(),
+            &mut PacketFiltersFields::default(),
+        )
+        .unwrap();
+        assert_eq!(key.address2, IpAddr::from([9, 9, 9, 9]));
+        assert_eq!(key.protocol, Protocol::UDP);
+
+        // beacons and protected frames are skipped, rather than reported as malformed
+        let mut beacon = data.clone();
+        beacon[0] = 0x80;
+        assert!(from_wifi(&beacon, my_link_type).is_none());
+        let mut protected = data;
+        protected[1] |= 0x40;
+        assert!(from_wifi(&protected, my_link_type).is_none());
+    }
+
+    #[test]
+    fn test_vlan_and_mpls_headers() {
+        let mut ip_packet = Vec::new();
+        PacketBuilder::ipv4([10, 0, 0, 1], [10, 0, 0, 2], 64)
+            .tcp(50_000, 443, 1, 1000)
+            .write(&mut ip_packet, &[0; 10])
+            .unwrap();
+
+        // QinQ: outer tag with VLAN 100, inner tag with VLAN 20
+        let mut data = Vec::new();
+        PacketBuilder::ethernet2([1; 6], [2; 6])
+            .double_vlan(VlanId::try_new(100).unwrap(), VlanId::try_new(20).unwrap())
+            .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 64)
+            .tcp(50_000, 443, 1, 1000)
+            .write(&mut data, &[0; 10])
+            .unwrap();
+        let (headers, mpls_labels) = decode_mpls(LaxPacketHeaders::from_ethernet(&data).unwrap());
+        assert!(mpls_labels.is_empty());
+        let mut packet_filters_fields = PacketFiltersFields::default();
+        let mut exchanged_bytes = 0;
+        let key = analyze_headers(
+            headers,
+            &mut (None, None),
+            &mut exchanged_bytes,
+            &mut IcmpType::default(),
+            &mut ArpType::default(),
+            &mut TcpSegment::default(),
+            &mut packet_filters_fields,
+        )
+        .unwrap();
+        assert_eq!(key.port2, Some(443));
+        assert_eq!(packet_filters_fields.vlan_ids, vec![100, 20]);
+        assert_eq!(exchanged_bytes, data.len() as u128);
+
+        // two MPLS labels (the second one is the bottom of the stack) over Ethernet
+        let mut data = vec![2; 6];
+        data.extend([1; 6]);
+        data.extend([0x88, 0x47]);
+        data.extend((16_u32 << 12 | 64).to_be_bytes());
+        data.extend((1_000_u32 << 12 | 0x100 | 64).to_be_bytes());
+        data.extend(&ip_packet);
+        let (headers, mpls_labels) = decode_mpls(LaxPacketHeaders::from_ethernet(&data).unwrap());
+        assert_eq!(mpls_labels, vec![16, 1_000]);
+        assert!(headers.link.is_some());
+        let mut packet_filters_fields = PacketFiltersFields::default();
+        let key = analyze_headers(
+            headers,
+            &mut (None, None),
+            &mut 0,
+            &mut IcmpType::default(),
+            &mut ArpType::default(),
+            &mut TcpSegment::default(),
+            &mut packet_filters_fields,
+        )
+        .unwrap();
+        assert_eq!(key.address2, IpAddr::from([10, 0, 0, 2]));
+        assert!(packet_filters_fields.vlan_ids.is_empty());
+
+        // truncated label stack
+        let (headers, mpls_labels) =
+            decode_mpls(LaxPacketHeaders::from_ethernet(&data[..20]).unwrap());
+        assert!(mpls_labels.is_empty());
+        assert!(headers.net.is_none());
+    }
+
+    #[test]
+    fn test_merge_ticks() {
+        let (tx_wan, rx_wan) = async_channel::unbounded();
+        let (tx_lan, rx_lan) = async_channel::unbounded();
+        let (tx, rx) = async_channel::unbounded();
+        tx_wan.send_blocking(tick("wan", 100, 2, 10)).unwrap();
+        tx_lan.send_blocking(tick("lan", 1_000, 4, 9)).unwrap();
+        let merger = thread::spawn(move || merge_ticks(7, &[rx_wan, rx_lan], &tx));
+
+        let Ok(BackendTrafficMessage::TickRun(cap_id, msg, host_msgs, no_more_packets)) =
+            rx.recv_blocking()
+        else {
+            panic!("expected a tick");
+        };
+        assert_eq!(cap_id, 7);
+        assert!(host_msgs.is_empty());
+        assert!(!no_more_packets);
+        assert_eq!(msg.all_packets, 2);
+        assert_eq!(msg.tot_data_info.tot_bytes(), 1_100);
+        // the latest count of each adapter
+        assert_eq!(msg.dropped_packets, 6);
+        assert_eq!(msg.last_packet_timestamp, Timestamp::new(10, 0));
+        assert_eq!(msg.interfaces["wan"].tot_bytes(), 100);
+        assert_eq!(msg.interfaces["lan"].tot_bytes(), 1_000);
+        assert_eq!(msg.map.len(), 1);
+        let info = msg.map.values().next().unwrap();
+        assert_eq!(info.transmitted_bytes, 1_100);
+        assert_eq!(
+            info.interfaces,
+            BTreeSet::from(["lan".to_string(), "wan".to_string()])
+        );
+        rx.close();
+        merger.join().unwrap();
+    }
+
+    #[test]
+    fn test_merge_ticks_out_of_phase() {
+        let (tx_wan, rx_wan) = async_channel::unbounded();
+        let (tx_lan, rx_lan) = async_channel::unbounded();
+        let (tx, rx) = async_channel::unbounded();
+        let next_tick = || {
+            let Ok(BackendTrafficMessage::TickRun(_, msg, _, _)) = rx.recv_blocking() else {
+                panic!("expected a tick");
+            };
+            msg
+        };
+        // the WAN capture is a whole tick ahead of the LAN one
+        tx_wan.send_blocking(tick("wan", 100, 2, 10)).unwrap();
+        tx_wan.send_blocking(tick("wan", 50, 3, 11)).unwrap();
+        tx_lan.send_blocking(tick("lan", 1_000, 4, 10)).unwrap();
+        let merger = thread::spawn(move || merge_ticks(7, &[rx_wan, rx_lan], &tx));
+
+        // a single second of data from each adapter
+        let msg = next_tick();
+        assert_eq!(msg.all_packets, 2);
+        assert_eq!(msg.interfaces["wan"].tot_bytes(), 100);
+        assert_eq!(msg.interfaces["lan"].tot_bytes(), 1_000);
+        assert_eq!(msg.dropped_packets, 6);
+
+        // the pending WAN tick is merged with the next LAN one
+        thread::sleep(Duration::from_millis(300));
+        tx_lan.send_blocking(tick("lan", 500, 5, 11)).unwrap();
+        let msg = next_tick();
+        assert_eq!(msg.all_packets, 2);
+        assert_eq!(msg.interfaces["wan"].tot_bytes(), 50);
+        assert_eq!(msg.interfaces["lan"].tot_bytes(), 500);
+        assert_eq!(msg.dropped_packets, 8);
+        assert_eq!(msg.last_packet_timestamp, Timestamp::new(11, 0));
+
+        // ticks of a single adapter are forwarded after a second at most
+        tx_wan.send_blocking(tick("wan", 10, 3, 12)).unwrap();
+        let msg = next_tick();
+        assert_eq!(msg.all_packets, 1);
+        assert_eq!(msg.interfaces["wan"].tot_bytes(), 10);
+        assert!(!msg.interfaces.contains_key("lan"));
+        assert_eq!(msg.dropped_packets, 8);
+
+        // the captures are stopped as soon as nobody is listening
+        rx.close();
+        merger.join().unwrap();
+        assert!(tx_wan.is_closed());
+        assert!(tx_lan.is_closed());
+    }
+}
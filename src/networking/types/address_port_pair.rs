@@ -1,10 +1,11 @@
 //! Module defining the `AddressPortPair` struct, which represents a network address:port pair.
 
 use crate::Protocol;
+use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
 
 /// Struct representing a network address:port pair.
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct AddressPortPair {
     /// Network layer IPv4 or IPv6 source address.
     pub address1: IpAddr,
@@ -15,6 +16,7 @@ pub struct AddressPortPair {
     /// Transport layer destination port number (in the range 0..=65535).
     pub port2: Option<u16>,
     ///  Transport layer protocol carried through the associate address:port pair (TCP or UPD).
+    #[serde(with = "protocol_name")]
     pub protocol: Protocol,
 }
 
@@ -41,4 +43,36 @@ pub fn new(
             protocol,
         }
     }
+
+    /// Returns the address:port pair of the opposite direction.
+    pub fn reversed(&self) -> Self {
+        AddressPortPair {
+            address1: self.address2,
+            port1: self.port2,
+            address2: self.address1,
+            port2: self.port1,
+            protocol: self.protocol,
+        }
+    }
+}
+
+/// (De)serializes a protocol by its name
+/// (`Protocol` can't derive serde traits, since it's also used by the build script)
+mod protocol_name {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Protocol;
+
+    pub fn serialize<S: Serializer>(protocol: &Protocol, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(protocol)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Protocol, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Protocol::ALL
+            .into_iter()
+            .find(|protocol| protocol.to_string() == name)
+            .ok_or_else(|| D::Error::custom(format!("unknown protocol: {name}")))
+    }
 }
@@ -0,0 +1,258 @@
+//! Module defining the `Blocklists` struct, used to flag the hosts listed in local blocklist files.
+
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::thread;
+
+use crate::location;
+use crate::networking::types::ip_collection::{AddressCollection, AddressIndex};
+use crate::utils::error_logger::{ErrorLogger, Location};
+
+/// Entries of a blocklist file.
+///
+/// Files are in plain text, with one entry per line: an IP, an IP range, a CIDR block, or a domain.
+/// Lines in the hosts file format (e.g., `0.0.0.0 example.com`) are also supported,
+/// and everything following a `#` is ignored.
+#[derive(Debug, Clone)]
+pub struct Blocklist {
+    /// Path of the file
+    pub path: String,
+    /// Listed IPs, ranges, and CIDR blocks
+    addresses: AddressIndex,
+    /// Listed domains (their subdomains are blocked as well)
+    domains: HashSet<String>,
+    /// Whether the file couldn't be read
+    pub is_error: bool,
+}
+
+impl Blocklist {
+    pub fn from_file(path: &str) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(path, &content),
+            Err(_) => Self {
+                path: path.to_string(),
+                addresses: AddressIndex::default(),
+                domains: HashSet::new(),
+                is_error: true,
+            },
+        }
+    }
+
+    fn parse(path: &str, content: &str) -> Self {
+        let mut addresses = AddressCollection::empty();
+        let mut domains = HashSet::new();
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                [] => {}
+                [entry] => {
+                    if addresses.insert(entry).is_none()
+                        && let Some(domain) = normalize_domain(entry)
+                    {
+                        domains.insert(domain);
+                    }
+                }
+                // hosts file format: the address the names are redirected to isn't an entry itself
+                [address, names @ ..] if IpAddr::from_str(address).is_ok() => {
+                    domains.extend(names.iter().filter_map(|name| normalize_domain(name)));
+                }
+                _ => {}
+            }
+        }
+        Self {
+            path: path.to_string(),
+            addresses: addresses.into(),
+            domains,
+            is_error: false,
+        }
+    }
+
+    /// Number of entries in the list
+    pub fn entries_count(&self) -> usize {
+        self.addresses.len() + self.domains.len()
+    }
+
+    fn contains_domain(&self, name: &str) -> bool {
+        let name = name.trim_end_matches('.').to_lowercase();
+        let mut suffix = name.as_str();
+        loop {
+            if self.domains.contains(suffix) {
+                return true;
+            }
+            match suffix.split_once('.') {
+                Some((_, parent)) => suffix = parent,
+                None => return false,
+            }
+        }
+    }
+}
+
+/// Returns the given name as a blocklist domain, or `None` if it doesn't look like a domain
+fn normalize_domain(name: &str) -> Option<String> {
+    let domain = name
+        .trim_start_matches("*.")
+        .trim_matches('.')
+        .to_lowercase();
+    let is_valid = domain.contains('.')
+        && IpAddr::from_str(&domain).is_err()
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'));
+    is_valid.then_some(domain)
+}
+
+/// Blocklists loaded from the files selected by the user
+#[derive(Debug, Clone, Default)]
+pub struct Blocklists {
+    lists: Vec<Blocklist>,
+}
+
+impl Blocklists {
+    pub fn load(paths: &[String]) -> Self {
+        Self {
+            lists: paths
+                .iter()
+                .map(|path| Blocklist::from_file(path))
+                .collect(),
+        }
+    }
+
+    /// Loads the blocklists on a separate thread, not to block the caller while reading the files
+    pub async fn load_in_background(paths: Vec<String>) -> Self {
+        let (tx, rx) = async_channel::bounded(1);
+        let _ = thread::Builder::new()
+            .name("thread_load_blocklists".to_string())
+            .spawn(move || {
+                let _ = tx.send_blocking(Self::load(&paths));
+            })
+            .log_err(location!());
+        rx.recv().await.unwrap_or_default()
+    }
+
+    /// Whether these are the blocklists loaded from the given files
+    pub fn is_loaded_from(&self, paths: &[String]) -> bool {
+        self.lists.iter().map(|list| &list.path).eq(paths)
+    }
+
+    pub fn lists(&self) -> &[Blocklist] {
+        &self.lists
+    }
+
+    /// Whether the address, or the name it was resolved to, is listed in any of the blocklists
+    pub fn matches(&self, address: &IpAddr, name: &str) -> bool {
+        self.matching_list(address, name).is_some()
+    }
+
+    /// Returns the first blocklist listing the address, or the name it was resolved to
+    pub fn matching_list(&self, address: &IpAddr, name: &str) -> Option<&Blocklist> {
+        self.lists
+            .iter()
+            .find(|list| list.addresses.contains(address))
+            .or_else(|| self.lists.iter().find(|list| list.contains_domain(name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "\
+# malicious addresses
+203.0.113.7
+198.51.100.0/24   # whole network
+192.0.2.10-192.0.2.20
+2001:db8::/64
+
+tracker.example
+*.ads.example.org
+0.0.0.0 malware.example   phishing.example # hosts file format
+127.0.0.1 localhost
+not a valid entry
+";
+
+    fn blocklists() -> Blocklists {
+        Blocklists {
+            lists: vec![Blocklist::parse("list.txt", CONTENT)],
+        }
+    }
+
+    fn ip(str: &str) -> IpAddr {
+        IpAddr::from_str(str).unwrap()
+    }
+
+    #[test]
+    fn test_parse_blocklist() {
+        let list = Blocklist::parse("list.txt", CONTENT);
+        assert_eq!(list.path, "list.txt");
+        assert!(!list.is_error);
+        assert_eq!(list.addresses.len(), 4);
+        assert_eq!(
+            list.domains,
+            HashSet::from([
+                "tracker.example".to_string(),
+                "ads.example.org".to_string(),
+                "malware.example".to_string(),
+                "phishing.example".to_string(),
+            ])
+        );
+        assert_eq!(list.entries_count(), 8);
+    }
+
+    #[test]
+    fn test_blocklist_matches_addresses() {
+        let blocklists = blocklists();
+        assert!(blocklists.matches(&ip("203.0.113.7"), ""));
+        assert!(blocklists.matches(&ip("198.51.100.200"), ""));
+        assert!(blocklists.matches(&ip("192.0.2.15"), ""));
+        assert!(blocklists.matches(&ip("2001:db8::1"), ""));
+        assert!(!blocklists.matches(&ip("192.0.2.21"), ""));
+        assert!(!blocklists.matches(&ip("0.0.0.0"), ""));
+        assert!(!blocklists.matches(&ip("127.0.0.1"), ""));
+        assert!(!Blocklists::default().matches(&ip("203.0.113.7"), ""));
+    }
+
+    #[test]
+    fn test_blocklist_matches_domains() {
+        let blocklists = blocklists();
+        let address = ip("8.8.8.8");
+        assert!(blocklists.matches(&address, "tracker.example"));
+        assert!(blocklists.matches(&address, "cdn.Tracker.Example."));
+        assert!(blocklists.matches(&address, "x.ads.example.org"));
+        assert!(blocklists.matches(&address, "phishing.example"));
+        assert!(!blocklists.matches(&address, "example.org"));
+        assert!(!blocklists.matches(&address, "notracker.example"));
+        assert!(!blocklists.matches(&address, "localhost"));
+        // the address alone is enough
+        assert!(blocklists.matches(&ip("203.0.113.7"), "dns.google"));
+    }
+
+    #[tokio::test]
+    async fn test_load_blocklists_in_background() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_blocklist_background_test_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, CONTENT).unwrap();
+        let paths = vec![
+            path.to_string_lossy().to_string(),
+            "/this/file/does/not/exist.txt".to_string(),
+        ];
+        let blocklists = Blocklists::load_in_background(paths.clone()).await;
+        std::fs::remove_file(&path).unwrap();
+        assert!(blocklists.is_loaded_from(&paths));
+        assert!(!blocklists.is_loaded_from(&paths[..1]));
+        assert_eq!(blocklists.lists()[0].entries_count(), 8);
+        assert!(blocklists.lists()[1].is_error);
+        assert!(blocklists.matches(&ip("198.51.100.200"), ""));
+        assert!(Blocklists::default().is_loaded_from(&[]));
+    }
+
+    #[test]
+    fn test_unreadable_blocklist() {
+        let list = Blocklist::from_file("/this/file/does/not/exist.txt");
+        assert!(list.is_error);
+        assert_eq!(list.entries_count(), 0);
+    }
+}
@@ -161,6 +161,14 @@ pub struct Bogon {
     ]
 });
 
+/// Returns the static description of a bogon given its textual representation
+pub fn get_bogon_description(description: &str) -> Option<&'static str> {
+    BOGONS
+        .iter()
+        .map(|bogon| bogon.description)
+        .find(|d| *d == description)
+}
+
 pub fn is_bogon(address: &IpAddr) -> Option<&'static str> {
     for bogon in BOGONS.iter() {
         if bogon.range.contains(address) {
@@ -1,5 +1,6 @@
 use pcap::{Active, Address, Capture, Error, Packet, Savefile, Stat};
 
+use crate::networking::types::filters::Filters;
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::my_link_type::MyLinkType;
 use crate::translations::translations::network_adapter_translation;
@@ -14,8 +15,14 @@ pub enum CaptureContext {
 }
 
 impl CaptureContext {
-    pub fn new(source: &CaptureSource, pcap_out_path: Option<&String>) -> Self {
-        let cap_type = match CaptureType::from_source(source, pcap_out_path) {
+    pub fn new(
+        source: &CaptureSource,
+        pcap_out_path: Option<&String>,
+        filters: &Filters,
+        monitor_mode: bool,
+    ) -> Self {
+        let cap_type = match CaptureType::from_source(source, pcap_out_path, filters, monitor_mode)
+        {
             Ok(c) => c,
             Err(e) => return Self::Error(e.to_string()),
         };
@@ -111,25 +118,47 @@ pub fn stats(&mut self) -> Result<Stat, Error> {
         }
     }
 
-    fn from_source(source: &CaptureSource, pcap_out_path: Option<&String>) -> Result<Self, Error> {
-        match source {
+    fn from_source(
+        source: &CaptureSource,
+        pcap_out_path: Option<&String>,
+        filters: &Filters,
+        monitor_mode: bool,
+    ) -> Result<Self, Error> {
+        let mut cap_type = match source {
             CaptureSource::Device(device) => {
-                let inactive = Capture::from_device(device.to_pcap_device())?;
+                let mut inactive = Capture::from_device(device.to_pcap_device())?;
+                // frames of the whole wireless network are received, including their radiotap headers
+                if monitor_mode && device.is_wireless() {
+                    inactive = inactive.rfmon(true);
+                }
+                let (snaplen, buffer_size) = if pcap_out_path.is_some() {
+                    (i32::from(u16::MAX), 2_000_000) // 2MB buffer
+                } else {
+                    // limit stored packets slice dimension (to keep more in the buffer),
+                    // while still including the application layer data needed to decode DNS and TLS handshakes;
+                    // in immediate mode each packet takes a slot as big as the snaplen on Linux,
+                    // so the buffer is sized to hold about as many packets as with a 200 bytes snaplen
+                    (2048, 16_000_000) // 16MB buffer
+                };
                 let cap = inactive
                     .promisc(true)
-                    .buffer_size(2_000_000) // 2MB buffer
-                    .snaplen(if pcap_out_path.is_some() {
-                        i32::from(u16::MAX)
-                    } else {
-                        200 // limit stored packets slice dimension (to keep more in the buffer)
-                    })
+                    .buffer_size(buffer_size)
+                    .snaplen(snaplen)
                     .immediate_mode(true) // parse packets ASAP
                     .timeout(150) // ensure UI is updated even if no packets are captured
                     .open()?;
-                Ok(Self::Live(cap))
+                Self::Live(cap)
+            }
+            CaptureSource::File(file) => Self::Offline(Capture::from_file(&file.path)?),
+        };
+        if filters.bpf_active() {
+            // compiled and applied in the kernel: packets not matching are never received
+            match &mut cap_type {
+                Self::Live(on) => on.filter(&filters.bpf, true)?,
+                Self::Offline(off) => off.filter(&filters.bpf, true)?,
             }
-            CaptureSource::File(file) => Ok(Self::Offline(Capture::from_file(&file.path)?)),
         }
+        Ok(cap_type)
     }
 }
 
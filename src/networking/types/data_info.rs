@@ -3,12 +3,13 @@
 use crate::chart::types::chart_type::ChartType;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::report::types::sort_type::SortType;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::time::Instant;
 
 /// Amount of exchanged data (packets and bytes) incoming and outgoing, with the timestamp of the latest occurrence
 // data fields are private to make them only editable via the provided methods: needed to correctly refresh timestamps
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct DataInfo {
     /// Incoming packets
     incoming_packets: u128,
@@ -19,6 +20,7 @@ pub struct DataInfo {
     /// Outgoing bytes
     outgoing_bytes: u128,
     /// Latest instant of occurrence
+    #[serde(with = "elapsed_instant")]
     final_instant: Instant,
 }
 
@@ -135,6 +137,23 @@ pub fn new_for_tests(
     }
 }
 
+/// (De)serializes an instant as the time elapsed since then,
+/// so that restored instants keep their relative order
+mod elapsed_instant {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::{Duration, Instant};
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        instant.elapsed().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let elapsed = Duration::deserialize(deserializer)?;
+        let now = Instant::now();
+        Ok(now.checked_sub(elapsed).unwrap_or(now))
+    }
+}
+
 impl Default for DataInfo {
     fn default() -> Self {
         Self {
@@ -1,10 +1,13 @@
 //! Module defining the `DataInfoHost` struct related to hosts.
 
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::networking::types::bogon::get_bogon_description;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::traffic_type::TrafficType;
 
 /// Host-related information.
-#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, Hash, Serialize)]
 pub struct DataInfoHost {
     /// Incoming and outgoing packets and bytes
     pub data_info: DataInfo,
@@ -16,6 +19,8 @@ pub struct DataInfoHost {
     pub is_local: bool,
     /// Determine if the connection is with a bogon address
     pub is_bogon: Option<&'static str>,
+    /// Determine if this host matches the blocklists
+    pub is_blocklisted: bool,
     /// Determine if the connection with this host is unicast, multicast, or broadcast
     pub traffic_type: TrafficType,
 }
@@ -29,3 +34,34 @@ pub fn refresh(&mut self, other: &Self) {
         self.traffic_type = other.traffic_type;
     }
 }
+
+/// Deserialized fields of a `DataInfoHost`.
+///
+/// Needed since bogon descriptions are `&'static str` and must be looked up instead of borrowed.
+#[derive(Deserialize)]
+struct DataInfoHostFields {
+    data_info: DataInfo,
+    is_favorite: bool,
+    is_loopback: bool,
+    is_local: bool,
+    is_bogon: Option<String>,
+    is_blocklisted: bool,
+    traffic_type: TrafficType,
+}
+
+impl<'de> Deserialize<'de> for DataInfoHost {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = DataInfoHostFields::deserialize(deserializer)?;
+        Ok(Self {
+            data_info: fields.data_info,
+            is_favorite: fields.is_favorite,
+            is_loopback: fields.is_loopback,
+            is_local: fields.is_local,
+            is_bogon: fields
+                .is_bogon
+                .and_then(|description| get_bogon_description(&description)),
+            is_blocklisted: fields.is_blocklisted,
+            traffic_type: fields.traffic_type,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+//! Module defining the `DnsLogEntry` struct, representing a DNS response observed in the sniffed traffic.
+
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::dns_message::{DnsMessage, DnsRecordType, DnsResponseCode};
+use crate::utils::types::timestamp::Timestamp;
+
+/// Struct representing a DNS response observed in the sniffed traffic.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct DnsLogEntry {
+    /// Timestamp of the response
+    pub timestamp: Timestamp,
+    /// Host that performed the query
+    pub client: IpAddr,
+    /// DNS server that answered the query
+    pub server: IpAddr,
+    /// Queried name
+    pub query_name: String,
+    /// Type of record queried
+    pub query_type: DnsRecordType,
+    /// Response code
+    pub response_code: DnsResponseCode,
+    /// IP addresses answered
+    pub answers: Vec<IpAddr>,
+}
+
+impl DnsLogEntry {
+    /// Maximum number of entries kept in the DNS log
+    pub const MAX_ENTRIES: usize = 10_000;
+
+    /// Returns a new entry, given the key of the packet carrying the response
+    pub fn new(timestamp: Timestamp, key: &AddressPortPair, response: DnsMessage) -> Self {
+        Self {
+            timestamp,
+            client: key.address2,
+            server: key.address1,
+            query_name: response.query_name,
+            query_type: response.query_type,
+            response_code: response.response_code,
+            answers: response.answers,
+        }
+    }
+
+    pub fn answers_string(&self) -> String {
+        if self.answers.is_empty() {
+            return "-".to_string();
+        }
+        self.answers
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,309 @@
+//! Module defining the `DnsMessage` struct, representing the relevant content of a DNS query or response.
+
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::{Deserialize, Serialize};
+
+/// Relevant content of a DNS message
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DnsMessage {
+    /// Whether this is a response (or a query)
+    pub is_response: bool,
+    /// Name queried in the question section
+    pub query_name: String,
+    /// Type of record queried in the question section
+    pub query_type: DnsRecordType,
+    /// Response code
+    pub response_code: DnsResponseCode,
+    /// IP addresses contained in the answer section (A and AAAA records)
+    pub answers: Vec<IpAddr>,
+}
+
+impl DnsMessage {
+    const HEADER_LEN: usize = 12;
+
+    /// Parses a DNS message (as carried by UDP, i.e., without the length prefix used over TCP).
+    ///
+    /// Answers are parsed on a best effort basis, since captured packets may be truncated.
+    pub fn parse(payload: &[u8]) -> Option<Self> {
+        let flags = read_u16(payload, 2)?;
+        let questions = read_u16(payload, 4)?;
+        let answers_count = read_u16(payload, 6)?;
+        // standard queries only
+        let opcode = (flags >> 11) & 0xF;
+        if questions == 0 || opcode != 0 {
+            return None;
+        }
+
+        let (query_name, mut pos) = read_name(payload, Self::HEADER_LEN)?;
+        let query_type = DnsRecordType::from_u16(read_u16(payload, pos)?);
+        pos += 4;
+        for _ in 1..questions {
+            let (_, name_end) = read_name(payload, pos)?;
+            pos = name_end + 4;
+        }
+
+        let mut answers = Vec::new();
+        for _ in 0..answers_count {
+            let Some((_, name_end)) = read_name(payload, pos) else {
+                break;
+            };
+            let (Some(record_type), Some(data_len)) =
+                (read_u16(payload, name_end), read_u16(payload, name_end + 8))
+            else {
+                break;
+            };
+            let data_start = name_end + 10;
+            let Some(data) = payload.get(data_start..data_start + usize::from(data_len)) else {
+                break;
+            };
+            match (DnsRecordType::from_u16(record_type), data.len()) {
+                (DnsRecordType::A, 4) => {
+                    answers.push(IpAddr::V4(Ipv4Addr::new(
+                        data[0], data[1], data[2], data[3],
+                    )));
+                }
+                (DnsRecordType::AAAA, 16) => {
+                    let mut octets = [0; 16];
+                    octets.copy_from_slice(data);
+                    answers.push(IpAddr::V6(Ipv6Addr::from(octets)));
+                }
+                _ => {}
+            }
+            pos = data_start + data.len();
+        }
+
+        Some(Self {
+            is_response: flags & 0x8000 != 0,
+            query_name,
+            query_type,
+            response_code: DnsResponseCode::from_u8((flags & 0xF) as u8),
+            answers,
+        })
+    }
+}
+
+/// Type of DNS resource record
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum DnsRecordType {
+    A,
+    NS,
+    CNAME,
+    SOA,
+    PTR,
+    MX,
+    TXT,
+    AAAA,
+    SRV,
+    SVCB,
+    HTTPS,
+    ANY,
+    Other(u16),
+}
+
+impl DnsRecordType {
+    fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::A,
+            2 => Self::NS,
+            5 => Self::CNAME,
+            6 => Self::SOA,
+            12 => Self::PTR,
+            15 => Self::MX,
+            16 => Self::TXT,
+            28 => Self::AAAA,
+            33 => Self::SRV,
+            64 => Self::SVCB,
+            65 => Self::HTTPS,
+            255 => Self::ANY,
+            n => Self::Other(n),
+        }
+    }
+}
+
+impl Display for DnsRecordType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Other(n) => write!(f, "TYPE{n}"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+/// Response code of a DNS message
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DnsResponseCode {
+    NoError,
+    FormErr,
+    ServFail,
+    NxDomain,
+    NotImp,
+    Refused,
+    Other(u8),
+}
+
+impl DnsResponseCode {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::NoError,
+            1 => Self::FormErr,
+            2 => Self::ServFail,
+            3 => Self::NxDomain,
+            4 => Self::NotImp,
+            5 => Self::Refused,
+            n => Self::Other(n),
+        }
+    }
+}
+
+impl Display for DnsResponseCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Other(n) => write!(f, "RCODE{n}"),
+            _ => write!(f, "{}", format!("{self:?}").to_uppercase()),
+        }
+    }
+}
+
+fn read_u16(payload: &[u8], pos: usize) -> Option<u16> {
+    let bytes = payload.get(pos..pos + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a (possibly compressed) domain name starting at the given position.
+///
+/// Returns the name and the position of the first byte after it.
+fn read_name(payload: &[u8], mut pos: usize) -> Option<(String, usize)> {
+    // max number of compression pointers to follow (to avoid infinite loops)
+    const MAX_JUMPS: u8 = 16;
+
+    let mut labels: Vec<String> = Vec::new();
+    let mut end = None;
+    let mut jumps = 0;
+    loop {
+        let len = *payload.get(pos)?;
+        match len & 0xC0 {
+            0x00 if len == 0 => {
+                break;
+            }
+            0x00 => {
+                let label = payload.get(pos + 1..pos + 1 + usize::from(len))?;
+                labels.push(String::from_utf8_lossy(label).to_lowercase());
+                pos += 1 + usize::from(len);
+            }
+            0xC0 => {
+                jumps += 1;
+                if jumps > MAX_JUMPS {
+                    return None;
+                }
+                let pointer = usize::from(read_u16(payload, pos)? & 0x3FFF);
+                end.get_or_insert(pos + 2);
+                pos = pointer;
+            }
+            _ => return None,
+        }
+    }
+    Some((labels.join("."), end.unwrap_or(pos + 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // query for www.example.com (A)
+    const QUERY: [u8; 33] = [
+        0x12, 0x34, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, b'w', b'w',
+        b'w', 0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00, 0x00,
+        0x01, 0x00, 0x01,
+    ];
+
+    // response for www.example.com (A), with a CNAME and an A record using compressed names
+    fn response() -> Vec<u8> {
+        let mut response = QUERY.to_vec();
+        // flags: response, recursion desired and available, NOERROR
+        response[2] = 0x81;
+        response[3] = 0x80;
+        // 2 answers
+        response[7] = 0x02;
+        // www.example.com CNAME cdn.example.com
+        response.extend_from_slice(&[
+            0xC0, 0x0C, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x06, 0x03, b'c',
+            b'd', b'n', 0xC0, 0x10,
+        ]);
+        // cdn.example.com A 93.184.216.34
+        response.extend_from_slice(&[
+            0xC0, 0x2D, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x04, 93, 184, 216,
+            34,
+        ]);
+        response
+    }
+
+    #[test]
+    fn test_parse_dns_query() {
+        assert_eq!(
+            DnsMessage::parse(&QUERY),
+            Some(DnsMessage {
+                is_response: false,
+                query_name: "www.example.com".to_string(),
+                query_type: DnsRecordType::A,
+                response_code: DnsResponseCode::NoError,
+                answers: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_dns_response() {
+        assert_eq!(
+            DnsMessage::parse(&response()),
+            Some(DnsMessage {
+                is_response: true,
+                query_name: "www.example.com".to_string(),
+                query_type: DnsRecordType::A,
+                response_code: DnsResponseCode::NoError,
+                answers: vec![IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34))],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated_dns_response() {
+        let mut response = response();
+        response.truncate(response.len() - 2);
+        let msg = DnsMessage::parse(&response).unwrap();
+        assert_eq!(msg.query_name, "www.example.com");
+        assert!(msg.answers.is_empty());
+
+        assert_eq!(DnsMessage::parse(&QUERY[..20]), None);
+        assert_eq!(DnsMessage::parse(&[]), None);
+    }
+
+    #[test]
+    fn test_parse_nxdomain_aaaa_response() {
+        let mut response = QUERY.to_vec();
+        response[2] = 0x81;
+        response[3] = 0x83;
+        response[30] = 0x1C;
+        let msg = DnsMessage::parse(&response).unwrap();
+        assert!(msg.is_response);
+        assert_eq!(msg.query_type, DnsRecordType::AAAA);
+        assert_eq!(msg.response_code, DnsResponseCode::NxDomain);
+    }
+
+    #[test]
+    fn test_compression_loops_are_rejected() {
+        let mut query = QUERY[..12].to_vec();
+        query.extend_from_slice(&[0xC0, 0x0C, 0x00, 0x01, 0x00, 0x01]);
+        assert_eq!(DnsMessage::parse(&query), None);
+    }
+
+    #[test]
+    fn test_dns_display() {
+        assert_eq!(DnsRecordType::AAAA.to_string(), "AAAA");
+        assert_eq!(DnsRecordType::Other(99).to_string(), "TYPE99");
+        assert_eq!(DnsResponseCode::NxDomain.to_string(), "NXDOMAIN");
+        assert_eq!(DnsResponseCode::Other(9).to_string(), "RCODE9");
+    }
+}
@@ -0,0 +1,73 @@
+//! Module defining the `DnsResolutions` struct, used to name hosts after the DNS responses observed in the sniffed traffic.
+
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
+
+/// Names queried in the observed DNS responses, for each of the addresses answered.
+///
+/// Only the addresses included in the latest answers are remembered.
+#[derive(Default)]
+pub struct DnsResolutions {
+    /// Queried name of each address, with the identifier of the latest answer including it
+    names: HashMap<IpAddr, (String, u64)>,
+    /// Answers in the order they were observed, identified by a sequential number
+    answers: VecDeque<(IpAddr, u64)>,
+    /// Number of answers observed so far
+    answers_count: u64,
+}
+
+impl DnsResolutions {
+    /// Maximum number of answers remembered
+    pub const MAX_ENTRIES: usize = 10_000;
+
+    /// Records that an address was answered for the given name, forgetting about the oldest answer if needed
+    pub fn insert(&mut self, address: IpAddr, name: String) {
+        self.answers_count += 1;
+        self.names.insert(address, (name, self.answers_count));
+        self.answers.push_back((address, self.answers_count));
+        if self.answers.len() > Self::MAX_ENTRIES
+            && let Some((oldest, id)) = self.answers.pop_front()
+            // the address could have been answered again in the meantime
+            && self.names.get(&oldest).is_some_and(|(_, latest_id)| *latest_id == id)
+        {
+            self.names.remove(&oldest);
+        }
+    }
+
+    /// Returns the name the given address was queried for, if any
+    pub fn get(&self, address: &IpAddr) -> Option<&String> {
+        self.names.get(address).map(|(name, _)| name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dns_resolutions_are_bounded() {
+        let mut dns_resolutions = DnsResolutions::default();
+        let first = IpAddr::from([1, 1, 1, 1]);
+        let repeated = IpAddr::from([8, 8, 8, 8]);
+        dns_resolutions.insert(first, "one.one.one.one".to_string());
+        dns_resolutions.insert(repeated, "dns.google".to_string());
+        for i in 0..DnsResolutions::MAX_ENTRIES - 2 {
+            let address = IpAddr::from(u32::try_from(i).unwrap().to_be_bytes());
+            dns_resolutions.insert(address, format!("host{i}.example.com"));
+        }
+        assert_eq!(
+            dns_resolutions.get(&first),
+            Some(&"one.one.one.one".to_string())
+        );
+        // answered again: no longer among the oldest answers
+        dns_resolutions.insert(repeated, "dns.google.com".to_string());
+        assert_eq!(dns_resolutions.get(&first), None);
+        dns_resolutions.insert(IpAddr::from([9, 9, 9, 9]), "dns.quad9.net".to_string());
+        assert_eq!(
+            dns_resolutions.get(&repeated),
+            Some(&"dns.google.com".to_string())
+        );
+        assert_eq!(dns_resolutions.names.len(), DnsResolutions::MAX_ENTRIES);
+        assert_eq!(dns_resolutions.answers.len(), DnsResolutions::MAX_ENTRIES);
+    }
+}
@@ -2,9 +2,13 @@
 
 use std::collections::HashSet;
 
+use pcap::{Capture, Linktype};
+
 use crate::networking::types::ip_collection::AddressCollection;
+use crate::networking::types::my_link_type::MyLinkType;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
 use crate::networking::types::port_collection::PortCollection;
+use crate::networking::types::vlan_collection::VlanCollection;
 use crate::{IpVersion, Protocol};
 
 /// Possible filters applicable to network traffic
@@ -22,6 +26,14 @@ pub struct Filters {
     pub port_str: String,
     /// Port collection to match against traffic
     pub port_collection: PortCollection,
+    /// VLAN IDs string in Initial page text input
+    pub vlan_str: String,
+    /// VLAN ID collection to match against traffic
+    pub vlan_collection: VlanCollection,
+    /// Berkeley Packet Filter expression, applied by the kernel before packets are parsed
+    pub bpf: String,
+    /// Whether the BPF expression compiles for the link type of the selected capture source
+    bpf_compiles: bool,
 }
 
 impl Default for Filters {
@@ -33,6 +45,10 @@ fn default() -> Self {
             address_collection: AddressCollection::default(),
             port_str: String::new(),
             port_collection: PortCollection::default(),
+            vlan_str: String::new(),
+            vlan_collection: VlanCollection::default(),
+            bpf: String::new(),
+            bpf_compiles: true,
         }
     }
 }
@@ -50,6 +66,11 @@ pub fn matches(&self, packet_filters_fields: &PacketFiltersFields) -> bool {
                     .contains(&packet_filters_fields.dest))
             && (self.port_collection.contains(packet_filters_fields.sport)
                 || self.port_collection.contains(packet_filters_fields.dport))
+            && (!self.vlan_active()
+                || packet_filters_fields
+                    .vlan_ids
+                    .iter()
+                    .any(|id| self.vlan_collection.contains(*id)))
     }
 
     pub fn are_valid(&self) -> bool {
@@ -57,6 +78,8 @@ pub fn are_valid(&self) -> bool {
             && self.protocol_valid()
             && self.address_valid()
             && self.port_valid()
+            && self.vlan_valid()
+            && self.bpf_valid()
     }
 
     pub fn ip_version_valid(&self) -> bool {
@@ -75,11 +98,38 @@ pub fn port_valid(&self) -> bool {
         PortCollection::new(&self.port_str).is_some()
     }
 
+    pub fn vlan_valid(&self) -> bool {
+        VlanCollection::new(&self.vlan_str).is_some()
+    }
+
+    /// Whether the BPF expression compiles (an empty expression is always valid)
+    pub fn bpf_valid(&self) -> bool {
+        self.bpf_compiles
+    }
+
+    /// Sets the BPF expression, validating it for the given link type
+    pub fn set_bpf(&mut self, bpf: String, link_type: MyLinkType) {
+        self.bpf = bpf;
+        self.validate_bpf(link_type);
+    }
+
+    /// Compiles the BPF expression for the given link type (Ethernet if it's not known yet),
+    /// since the same expression can be valid for some link types only
+    pub fn validate_bpf(&mut self, link_type: MyLinkType) {
+        let link_type = link_type.pcap_link_type().unwrap_or(Linktype::ETHERNET);
+        self.bpf_compiles = !self.bpf_active()
+            || Capture::dead(link_type)
+                .and_then(|cap| cap.compile(&self.bpf, true))
+                .is_ok();
+    }
+
     pub fn none_active(&self) -> bool {
         !self.ip_version_active()
             && !self.protocol_active()
             && !self.address_active()
             && !self.port_active()
+            && !self.vlan_active()
+            && !self.bpf_active()
     }
 
     pub fn ip_version_active(&self) -> bool {
@@ -98,6 +148,15 @@ pub fn port_active(&self) -> bool {
         self.port_collection != PortCollection::default()
     }
 
+    /// Whether only tagged traffic with the given VLAN IDs is considered
+    pub fn vlan_active(&self) -> bool {
+        self.vlan_collection != VlanCollection::default()
+    }
+
+    pub fn bpf_active(&self) -> bool {
+        !self.bpf.trim().is_empty()
+    }
+
     pub fn pretty_print_ip(&self) -> String {
         format!("{:?}", self.ip_versions)
             .replace('{', "")
@@ -110,3 +169,72 @@ pub fn pretty_print_protocol(&self) -> String {
             .replace('}', "")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bpf_filter_active() {
+        let mut filters = Filters::default();
+        assert!(!filters.bpf_active());
+        assert!(filters.bpf_valid());
+        assert!(filters.none_active());
+
+        let ethernet = MyLinkType::from_pcap_link_type(Linktype::ETHERNET);
+        filters.set_bpf("   ".to_string(), ethernet);
+        assert!(!filters.bpf_active());
+        assert!(filters.bpf_valid());
+        assert!(filters.none_active());
+
+        filters.set_bpf("vlan and tcp".to_string(), ethernet);
+        assert!(filters.bpf_active());
+        assert!(filters.bpf_valid());
+        assert!(!filters.none_active());
+
+        filters.set_bpf("tcp port".to_string(), ethernet);
+        assert!(filters.bpf_active());
+        assert!(!filters.bpf_valid());
+        assert!(!filters.are_valid());
+
+        // Ethernet addresses can't be matched on raw IP captures
+        filters.set_bpf("ether host 00:11:22:33:44:55".to_string(), ethernet);
+        assert!(filters.bpf_valid());
+        filters.validate_bpf(MyLinkType::from_pcap_link_type(Linktype(12)));
+        assert!(!filters.bpf_valid());
+        // link type not known yet
+        filters.validate_bpf(MyLinkType::NotYetAssigned);
+        assert!(filters.bpf_valid());
+    }
+
+    #[test]
+    fn test_vlan_filter() {
+        let mut filters = Filters::default();
+        let mut packet_filters_fields = PacketFiltersFields::default();
+        assert!(!filters.vlan_active());
+        assert!(filters.matches(&packet_filters_fields));
+
+        filters.vlan_str = "10, 100-199".to_string();
+        filters.vlan_collection = VlanCollection::new(&filters.vlan_str).unwrap();
+        assert!(filters.vlan_active());
+        assert!(filters.vlan_valid());
+        assert!(!filters.none_active());
+        // untagged traffic
+        assert!(!filters.matches(&packet_filters_fields));
+        packet_filters_fields.vlan_ids = vec![20];
+        assert!(!filters.matches(&packet_filters_fields));
+        // QinQ: the inner tag matches
+        packet_filters_fields.vlan_ids = vec![20, 150];
+        assert!(filters.matches(&packet_filters_fields));
+        packet_filters_fields.vlan_ids = vec![10];
+        assert!(filters.matches(&packet_filters_fields));
+
+        filters.vlan_str = "4096".to_string();
+        assert!(!filters.vlan_valid());
+        assert!(!filters.are_valid());
+        filters.vlan_str = "100-5000".to_string();
+        assert!(!filters.vlan_valid());
+        filters.vlan_str = " ".to_string();
+        assert!(filters.vlan_valid());
+    }
+}
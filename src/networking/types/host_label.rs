@@ -0,0 +1,176 @@
+//! Module defining the `HostLabel` struct, which allows users to give custom names and groups
+//! to the hosts of their network.
+
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::ip_collection::AddressCollection;
+
+/// Custom name and group attached to the hosts matching a target
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct HostLabel {
+    /// IP address, IP range, CIDR block, or MAC address of the labeled hosts
+    pub target: String,
+    /// Custom name (e.g., "NAS")
+    pub name: String,
+    /// Group tag (e.g., "Kubernetes nodes")
+    pub group: String,
+}
+
+impl HostLabel {
+    /// Whether the label can be added, i.e., it has a valid target and a name or a group
+    pub fn is_valid(&self) -> bool {
+        LabelTarget::new(&self.target).is_some()
+            && !(self.name.trim().is_empty() && self.group.trim().is_empty())
+    }
+
+    /// Text to display in place of or next to the host domain
+    pub fn text(&self) -> String {
+        let name = self.name.trim();
+        let group = self.group.trim();
+        match (name.is_empty(), group.is_empty()) {
+            (false, false) => format!("{name} [{group}]"),
+            (false, true) => name.to_string(),
+            _ => format!("[{group}]"),
+        }
+    }
+}
+
+/// Parsed target of a label
+#[derive(Debug, Clone)]
+enum LabelTarget {
+    Addresses(AddressCollection),
+    /// MAC address, lowercase and with `:` separators
+    Mac(String),
+}
+
+impl LabelTarget {
+    fn new(target: &str) -> Option<Self> {
+        let target = target.trim();
+        if target.is_empty() {
+            return None;
+        }
+        if let Some(mac) = normalize_mac(target) {
+            return Some(Self::Mac(mac));
+        }
+        let mut addresses = AddressCollection::empty();
+        addresses.insert(&target.replace(' ', ""))?;
+        Some(Self::Addresses(addresses))
+    }
+}
+
+/// Returns the given MAC address in lowercase and with `:` separators,
+/// or `None` if it isn't a valid MAC address
+fn normalize_mac(mac: &str) -> Option<String> {
+    let parts: Vec<&str> = mac.split([':', '-']).collect();
+    let is_valid = parts.len() == 6
+        && parts
+            .iter()
+            .all(|p| p.len() == 2 && p.chars().all(|c| c.is_ascii_hexdigit()));
+    is_valid.then(|| parts.join(":").to_lowercase())
+}
+
+/// Labels defined by the user, ready to be matched against the observed hosts
+#[derive(Debug, Clone, Default)]
+pub struct HostLabels {
+    labels: Vec<(LabelTarget, HostLabel)>,
+}
+
+impl HostLabels {
+    pub fn new(labels: &[HostLabel]) -> Self {
+        Self {
+            labels: labels
+                .iter()
+                .filter_map(|label| Some((LabelTarget::new(&label.target)?, label.clone())))
+                .collect(),
+        }
+    }
+
+    /// Whether any of the labels targets a MAC address
+    pub fn has_mac_targets(&self) -> bool {
+        self.labels
+            .iter()
+            .any(|(target, _)| matches!(target, LabelTarget::Mac(_)))
+    }
+
+    /// Returns the first label matching the given address or MAC address
+    pub fn get(&self, address: &IpAddr, mac: Option<&str>) -> Option<&HostLabel> {
+        self.labels
+            .iter()
+            .find(|(target, _)| match target {
+                LabelTarget::Addresses(addresses) => addresses.contains(address),
+                LabelTarget::Mac(target_mac) => mac.is_some_and(|m| m == target_mac),
+            })
+            .map(|(_, label)| label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn label(target: &str, name: &str, group: &str) -> HostLabel {
+        HostLabel {
+            target: target.to_string(),
+            name: name.to_string(),
+            group: group.to_string(),
+        }
+    }
+
+    fn ip(str: &str) -> IpAddr {
+        IpAddr::from_str(str).unwrap()
+    }
+
+    #[test]
+    fn test_host_label_validity_and_text() {
+        assert!(label("192.168.1.10", "NAS", "").is_valid());
+        assert!(label(" 10.0.0.0/24 ", "", "Kubernetes nodes").is_valid());
+        assert!(label("10.0.0.1-10.0.0.9", "x", "y").is_valid());
+        assert!(label("AA-BB-CC-DD-EE-FF", "printer", "").is_valid());
+        assert!(!label("192.168.1.10", " ", "").is_valid());
+        assert!(!label("", "NAS", "").is_valid());
+        assert!(!label("nas.local", "NAS", "").is_valid());
+        assert!(!label("aa:bb:cc:dd:ee", "NAS", "").is_valid());
+
+        assert_eq!(label("", "NAS", "home").text(), "NAS [home]");
+        assert_eq!(label("", "NAS", "").text(), "NAS");
+        assert_eq!(label("", "", "home").text(), "[home]");
+    }
+
+    #[test]
+    fn test_host_labels_matching() {
+        let labels = HostLabels::new(&[
+            label("192.168.1.10", "NAS", "home"),
+            label("10.0.0.0/24", "", "Kubernetes nodes"),
+            label("AA-BB-CC-DD-EE-FF", "printer", ""),
+            label("not a target", "ignored", ""),
+            label("10.0.0.5", "shadowed", ""),
+        ]);
+        assert_eq!(labels.labels.len(), 4);
+        assert!(labels.has_mac_targets());
+
+        assert_eq!(labels.get(&ip("192.168.1.10"), None).unwrap().name, "NAS");
+        // the first matching label wins
+        assert_eq!(
+            labels.get(&ip("10.0.0.5"), None).unwrap().group,
+            "Kubernetes nodes"
+        );
+        assert_eq!(
+            labels
+                .get(&ip("192.168.1.20"), Some("aa:bb:cc:dd:ee:ff"))
+                .unwrap()
+                .name,
+            "printer"
+        );
+        assert!(labels.get(&ip("192.168.1.20"), None).is_none());
+        assert!(
+            labels
+                .get(&ip("192.168.1.20"), Some("aa:bb:cc:dd:ee:00"))
+                .is_none()
+        );
+        assert!(!HostLabels::new(&[label("::1", "me", "")]).has_mac_targets());
+    }
+}
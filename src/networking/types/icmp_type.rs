@@ -1,10 +1,11 @@
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
 use etherparse::{Icmpv4Type, Icmpv6Type};
 use std::fmt::Write;
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum IcmpType {
     V4(IcmpTypeV4),
     V6(IcmpTypeV6),
@@ -47,7 +48,7 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 pub enum IcmpTypeV4 {
     EchoReply,
@@ -163,7 +164,7 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 pub enum IcmpTypeV6 {
     DestinationUnreachable,
@@ -1,18 +1,26 @@
 //! Module defining the `InfoAddressPortPair` struct, useful to format the output report file and
 //! to keep track of statistics about the sniffed traffic.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
 
 use crate::Service;
 use crate::networking::types::arp_type::ArpType;
 use crate::networking::types::icmp_type::IcmpType;
+use crate::networking::types::process::Process;
+use crate::networking::types::tcp_flags::TcpFlagsCount;
+use crate::networking::types::tcp_rtt::TcpRtt;
+use crate::networking::types::tls_client_hello::TlsClientHello;
 use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::networking::types::wifi_frame::WifiInfo;
+use crate::utils::map_as_vec;
 use crate::utils::types::timestamp::Timestamp;
 
 /// Struct useful to format the output report file and to keep track of statistics about the sniffed traffic.
 ///
 /// Each `InfoAddressPortPair` struct is associated to a single address:port pair.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct InfoAddressPortPair {
     /// Source MAC address
     pub mac_address1: Option<String>,
@@ -31,9 +39,27 @@ pub struct InfoAddressPortPair {
     /// Determines if the connection is incoming or outgoing
     pub traffic_direction: TrafficDirection,
     /// Types of the ICMP messages exchanged, with the relative count (this is empty if not ICMP)
+    #[serde(with = "map_as_vec")]
     pub icmp_types: HashMap<IcmpType, usize>,
     /// Types of the ARP operations, with the relative count (this is empty if not ARP)
+    #[serde(with = "map_as_vec")]
     pub arp_types: HashMap<ArpType, usize>,
+    /// Count of the relevant TCP flags observed (this is all zeros if not TCP)
+    pub tcp_flags: TcpFlagsCount,
+    /// Round-trip time estimations of the TCP connection (this is empty if not TCP)
+    pub tcp_rtt: TcpRtt,
+    /// TLS ClientHello sent to open the connection (this is empty if not observed)
+    pub tls_client_hello: Option<TlsClientHello>,
+    /// Local process owning the socket of the connection (this is empty if unknown)
+    pub process: Option<Process>,
+    /// Names of the network adapters the connection was observed on (this is empty for imported files)
+    pub interfaces: BTreeSet<String>,
+    /// Station, signal, and channel of the last frame (this is empty if not captured in monitor mode)
+    pub wifi: Option<WifiInfo>,
+    /// IDs of the VLAN tags observed, including the ones of stacked tags (this is empty if untagged)
+    pub vlan_ids: BTreeSet<u16>,
+    /// Labels of the MPLS stack observed (this is empty if not carried over MPLS)
+    pub mpls_labels: BTreeSet<u32>,
 }
 
 impl InfoAddressPortPair {
@@ -55,5 +81,19 @@ pub fn refresh(&mut self, other: &Self) {
                 .and_modify(|v| *v += count)
                 .or_insert(*count);
         }
+        self.tcp_flags.refresh(&other.tcp_flags);
+        self.tcp_rtt.refresh(&other.tcp_rtt);
+        if other.tls_client_hello.is_some() {
+            self.tls_client_hello.clone_from(&other.tls_client_hello);
+        }
+        if other.process.is_some() {
+            self.process.clone_from(&other.process);
+        }
+        self.interfaces.extend(other.interfaces.iter().cloned());
+        if other.wifi.is_some() {
+            self.wifi.clone_from(&other.wifi);
+        }
+        self.vlan_ids.extend(&other.vlan_ids);
+        self.mpls_labels.extend(&other.mpls_labels);
     }
 }
@@ -3,13 +3,16 @@
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dns_log_entry::DnsLogEntry;
 use crate::networking::types::host::Host;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::utils::map_as_vec;
 use crate::utils::types::timestamp::Timestamp;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
 /// Struct containing overall traffic statistics and data.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct InfoTraffic {
     /// Total amount of exchanged data
     pub tot_data_info: DataInfo,
@@ -22,19 +25,27 @@ pub struct InfoTraffic {
     /// Timestamp of the latest parsed packet
     pub last_packet_timestamp: Timestamp,
     /// Map of the filtered traffic
+    #[serde(with = "map_as_vec")]
     pub map: HashMap<AddressPortPair, InfoAddressPortPair>,
     /// Map of the upper layer services with their data info
+    #[serde(with = "map_as_vec")]
     pub services: HashMap<Service, DataInfo>,
     /// Map of the hosts with their data info
+    #[serde(with = "map_as_vec")]
     pub hosts: HashMap<Host, DataInfoHost>,
+    /// Map of the local applications (identified by their executable name) with their data info
+    pub applications: HashMap<String, DataInfo>,
+    /// Log of the DNS responses observed (most recent last)
+    pub dns_log: VecDeque<DnsLogEntry>,
+    /// Map of the network adapters being captured with their data info
+    pub interfaces: HashMap<String, DataInfo>,
+    /// Map of the VLAN IDs with their data info (packets with stacked tags count for each of their IDs)
+    #[serde(with = "map_as_vec")]
+    pub vlans: HashMap<u16, DataInfo>,
 }
 
 impl InfoTraffic {
     pub fn refresh(&mut self, msg: &mut InfoTraffic) {
-        self.tot_data_info.refresh(msg.tot_data_info);
-
-        self.all_packets += msg.all_packets;
-        self.all_bytes += msg.all_bytes;
         self.dropped_packets = msg.dropped_packets;
 
         // it can happen they're equal due to dis-alignments in the PCAP timestamp
@@ -43,6 +54,16 @@ pub fn refresh(&mut self, msg: &mut InfoTraffic) {
         }
         self.last_packet_timestamp = msg.last_packet_timestamp;
 
+        self.merge(msg);
+    }
+
+    /// Adds the data of `msg` to `self`, leaving the dropped packets and the last packet timestamp as they are
+    pub fn merge(&mut self, msg: &InfoTraffic) {
+        self.tot_data_info.refresh(msg.tot_data_info);
+
+        self.all_packets += msg.all_packets;
+        self.all_bytes += msg.all_bytes;
+
         for (key, value) in &msg.map {
             self.map
                 .entry(*key)
@@ -57,12 +78,37 @@ pub fn refresh(&mut self, msg: &mut InfoTraffic) {
                 .or_insert(*value);
         }
 
+        for (key, value) in &msg.applications {
+            self.applications
+                .entry(key.clone())
+                .and_modify(|x| x.refresh(*value))
+                .or_insert(*value);
+        }
+
+        for (key, value) in &msg.interfaces {
+            self.interfaces
+                .entry(key.clone())
+                .and_modify(|x| x.refresh(*value))
+                .or_insert(*value);
+        }
+
+        for (key, value) in &msg.vlans {
+            self.vlans
+                .entry(*key)
+                .and_modify(|x| x.refresh(*value))
+                .or_insert(*value);
+        }
+
         for (key, value) in &msg.hosts {
             self.hosts
                 .entry(key.clone())
                 .and_modify(|x| x.refresh(value))
                 .or_insert(*value);
         }
+
+        self.dns_log.extend(msg.dns_log.iter().cloned());
+        let excess = self.dns_log.len().saturating_sub(DnsLogEntry::MAX_ENTRIES);
+        self.dns_log.drain(..excess);
     }
 
     pub fn get_thumbnail_data(&self, chart_type: ChartType) -> (u128, u128, u128, u128) {
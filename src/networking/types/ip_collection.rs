@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::ops::RangeInclusive;
 use std::str::FromStr;
@@ -11,6 +12,7 @@ pub(crate) struct AddressCollection {
 impl AddressCollection {
     const SEPARATOR: char = ',';
     const RANGE_SEPARATOR: char = '-';
+    const CIDR_SEPARATOR: char = '/';
 
     pub const PLACEHOLDER_STR: &'static str =
         "0.0.0.0-255.255.255.255, ::-ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff";
@@ -22,34 +24,53 @@ pub(crate) fn new(str: &str) -> Option<Self> {
             return Some(Self::default());
         }
 
-        let mut ips = Vec::new();
-        let mut ranges = Vec::new();
+        let mut collection = Self::empty();
+        for object in str.split(Self::SEPARATOR) {
+            collection.insert(object)?;
+        }
 
-        let objects: Vec<&str> = str.split(Self::SEPARATOR).collect();
-        for object in objects {
-            if object.contains(Self::RANGE_SEPARATOR) {
-                // IP range
-                let mut subparts = object.split(Self::RANGE_SEPARATOR);
-                if subparts.clone().count() != 2 {
-                    return None;
-                }
-                let (lower_str, upper_str) =
-                    (subparts.next().unwrap_or(""), subparts.next().unwrap_or(""));
-                let lower_ip = IpAddr::from_str(lower_str).ok()?;
-                let upper_ip = IpAddr::from_str(upper_str).ok()?;
-                let range = RangeInclusive::new(lower_ip, upper_ip);
-                if range.is_empty() || lower_ip.is_ipv4() != upper_ip.is_ipv4() {
-                    return None;
-                }
-                ranges.push(range);
-            } else {
-                // individual IP
-                let ip = IpAddr::from_str(object).ok()?;
-                ips.push(ip);
-            }
+        Some(collection)
+    }
+
+    /// Returns a collection not containing any address
+    pub(crate) fn empty() -> Self {
+        Self {
+            ips: Vec::new(),
+            ranges: Vec::new(),
         }
+    }
 
-        Some(Self { ips, ranges })
+    /// Adds an individual IP, an IP range (e.g., `10.0.0.1-10.0.0.255`),
+    /// or a CIDR block (e.g., `10.0.0.0/24`) to the collection.
+    ///
+    /// Returns `None` if the object isn't valid.
+    pub(crate) fn insert(&mut self, object: &str) -> Option<()> {
+        if object.contains(Self::RANGE_SEPARATOR) {
+            // IP range
+            let mut subparts = object.split(Self::RANGE_SEPARATOR);
+            if subparts.clone().count() != 2 {
+                return None;
+            }
+            let (lower_str, upper_str) =
+                (subparts.next().unwrap_or(""), subparts.next().unwrap_or(""));
+            let lower_ip = IpAddr::from_str(lower_str).ok()?;
+            let upper_ip = IpAddr::from_str(upper_str).ok()?;
+            let range = RangeInclusive::new(lower_ip, upper_ip);
+            if range.is_empty() || lower_ip.is_ipv4() != upper_ip.is_ipv4() {
+                return None;
+            }
+            self.ranges.push(range);
+        } else if let Some((ip_str, prefix_str)) = object.split_once(Self::CIDR_SEPARATOR) {
+            // CIDR block
+            let ip = IpAddr::from_str(ip_str).ok()?;
+            let prefix_len = prefix_str.parse().ok()?;
+            self.ranges.push(cidr_range(ip, prefix_len)?);
+        } else {
+            // individual IP
+            let ip = IpAddr::from_str(object).ok()?;
+            self.ips.push(ip);
+        }
+        Some(())
     }
 
     pub(crate) fn contains(&self, ip: &IpAddr) -> bool {
@@ -60,6 +81,89 @@ pub(crate) fn contains(&self, ip: &IpAddr) -> bool {
         }
         self.ips.contains(ip)
     }
+
+    /// Number of IPs, ranges, and CIDR blocks in the collection
+    pub(crate) fn len(&self) -> usize {
+        self.ips.len() + self.ranges.len()
+    }
+}
+
+/// Addresses of a collection, indexed for fast lookups in large collections such as blocklists
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AddressIndex {
+    ips: HashSet<IpAddr>,
+    /// Disjoint ranges, sorted by their lower address
+    ranges: Vec<RangeInclusive<IpAddr>>,
+    /// Number of IPs, ranges, and CIDR blocks of the original collection
+    len: usize,
+}
+
+impl AddressIndex {
+    pub(crate) fn contains(&self, ip: &IpAddr) -> bool {
+        if self.ips.contains(ip) {
+            return true;
+        }
+        // the only range that can contain the address is the last one starting before it
+        let index = self.ranges.partition_point(|range| range.start() <= ip);
+        index > 0 && self.ranges[index - 1].contains(ip)
+    }
+
+    /// Number of IPs, ranges, and CIDR blocks of the original collection
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl From<AddressCollection> for AddressIndex {
+    fn from(collection: AddressCollection) -> Self {
+        let len = collection.len();
+        let mut ranges = collection.ranges;
+        ranges.sort_unstable_by_key(|range| *range.start());
+        // merge overlapping ranges (IPv4 and IPv6 ranges never overlap, since every IPv4 address sorts first)
+        let mut merged: Vec<RangeInclusive<IpAddr>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start() <= last.end() => {
+                    if range.end() > last.end() {
+                        *last = RangeInclusive::new(*last.start(), *range.end());
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+        Self {
+            ips: collection.ips.into_iter().collect(),
+            ranges: merged,
+            len,
+        }
+    }
+}
+
+/// Returns the range of addresses of a CIDR block, or `None` if the prefix length is too long
+fn cidr_range(ip: IpAddr, prefix_len: u32) -> Option<RangeInclusive<IpAddr>> {
+    // shifting by the whole width fails: the mask of a /0 block is empty
+    match ip {
+        IpAddr::V4(ip) => {
+            let mask = u32::MAX
+                .checked_shl(32_u32.checked_sub(prefix_len)?)
+                .unwrap_or(0);
+            let lower = u32::from(ip) & mask;
+            Some(RangeInclusive::new(
+                IpAddr::V4(Ipv4Addr::from(lower)),
+                IpAddr::V4(Ipv4Addr::from(lower | !mask)),
+            ))
+        }
+        IpAddr::V6(ip) => {
+            let mask = u128::MAX
+                .checked_shl(128_u32.checked_sub(prefix_len)?)
+                .unwrap_or(0);
+            let lower = u128::from(ip) & mask;
+            Some(RangeInclusive::new(
+                IpAddr::V6(Ipv6Addr::from(lower)),
+                IpAddr::V6(Ipv6Addr::from(lower | !mask)),
+            ))
+        }
+    }
 }
 
 impl Default for AddressCollection {
@@ -89,7 +193,7 @@ mod tests {
     use std::ops::RangeInclusive;
     use std::str::FromStr;
 
-    use crate::networking::types::ip_collection::AddressCollection;
+    use crate::networking::types::ip_collection::{AddressCollection, AddressIndex};
 
     #[test]
     fn test_default_collection_contains_everything() {
@@ -224,6 +328,39 @@ fn test_new_collections_2() {
         );
     }
 
+    #[test]
+    fn test_new_collections_cidr() {
+        assert_eq!(
+            AddressCollection::new("10.1.2.3/8, 192.168.1.7/32, 2001:db8::/32, 0.0.0.0/0").unwrap(),
+            AddressCollection {
+                ips: vec![],
+                ranges: vec![
+                    RangeInclusive::new(
+                        IpAddr::from_str("10.0.0.0").unwrap(),
+                        IpAddr::from_str("10.255.255.255").unwrap()
+                    ),
+                    RangeInclusive::new(
+                        IpAddr::from_str("192.168.1.7").unwrap(),
+                        IpAddr::from_str("192.168.1.7").unwrap()
+                    ),
+                    RangeInclusive::new(
+                        IpAddr::from_str("2001:db8::").unwrap(),
+                        IpAddr::from_str("2001:db8:ffff:ffff:ffff:ffff:ffff:ffff").unwrap()
+                    ),
+                    RangeInclusive::new(
+                        IpAddr::from_str("0.0.0.0").unwrap(),
+                        IpAddr::from_str("255.255.255.255").unwrap()
+                    )
+                ]
+            }
+        );
+
+        assert_eq!(AddressCollection::new("10.0.0.0/33"), None);
+        assert_eq!(AddressCollection::new("2001:db8::/129"), None);
+        assert_eq!(AddressCollection::new("10.0.0.0/"), None);
+        assert_eq!(AddressCollection::new("10.0.0.0/8/8"), None);
+    }
+
     #[test]
     fn test_new_collections_invalid() {
         assert_eq!(
@@ -326,4 +463,33 @@ fn test_ip_collection_contains_ipv6() {
         assert!(!collection_3.contains(&IpAddr::from_str("192.168.1.1").unwrap()));
         assert!(!collection_3.contains(&IpAddr::from_str("0.0.0.0").unwrap()));
     }
+
+    #[test]
+    fn test_address_index_contains() {
+        let collection = AddressCollection::new(
+            "1.1.1.1, 10.0.0.0-10.0.0.255, 10.0.0.128-10.0.1.10, 10.0.0.5-10.0.0.6, 192.168.0.0/16, 2001:db8::/32",
+        )
+        .unwrap();
+        let index = AddressIndex::from(collection.clone());
+        assert_eq!(index.len(), 6);
+        assert_eq!(index.ranges.len(), 3);
+        for ip in [
+            "1.1.1.1",
+            "1.1.1.2",
+            "10.0.0.0",
+            "10.0.0.7",
+            "10.0.0.200",
+            "10.0.1.10",
+            "10.0.1.11",
+            "192.168.255.255",
+            "192.169.0.0",
+            "2001:db8::1",
+            "2001:db9::",
+            "::1",
+        ] {
+            let ip = IpAddr::from_str(ip).unwrap();
+            assert_eq!(index.contains(&ip), collection.contains(&ip), "{ip}");
+        }
+        assert!(!AddressIndex::default().contains(&IpAddr::from_str("1.1.1.1").unwrap()));
+    }
 }
@@ -0,0 +1,185 @@
+//! Module defining the `LinuxSllHeader` struct, representing the pseudo-header prepended by Linux
+//! to the packets captured in "cooked" mode (e.g., on the `any` device or with `tcpdump -i any`).
+
+use crate::networking::manage_packets::mac_from_dec_to_hex;
+use crate::networking::types::my_link_type::MyLinkType;
+use crate::networking::types::traffic_direction::TrafficDirection;
+
+/// Fields of a `LINUX_SLL` or `LINUX_SLL2` header relevant for the analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinuxSllHeader {
+    /// Whether the packet was sent to this host, by this host, broadcasted, etc.
+    pub packet_type: LinuxSllPacketType,
+    /// `ARPHRD_` type of the interface the packet was captured on
+    arp_hardware_type: u16,
+    /// Link-layer address of the sender (only the first `link_address_len` bytes are meaningful)
+    link_address: [u8; 8],
+    link_address_len: usize,
+    /// Protocol of the payload, usually an `EtherType`
+    pub protocol_type: u16,
+    /// Length of the header itself
+    pub len: usize,
+}
+
+/// Value of the packet type field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinuxSllPacketType {
+    /// Sent to this host
+    Host,
+    /// Broadcasted by another host
+    Broadcast,
+    /// Multicasted by another host
+    Multicast,
+    /// Sent by another host to another host (only seen in promiscuous mode)
+    OtherHost,
+    /// Sent by this host
+    Outgoing,
+    /// Any other value
+    Other(u16),
+}
+
+impl LinuxSllPacketType {
+    fn from_u16(value: u16) -> Self {
+        match value {
+            0 => Self::Host,
+            1 => Self::Broadcast,
+            2 => Self::Multicast,
+            3 => Self::OtherHost,
+            4 => Self::Outgoing,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl LinuxSllHeader {
+    pub const SLL_LEN: usize = 16;
+    pub const SLL2_LEN: usize = 20;
+    const ARPHRD_ETHER: u16 = 1;
+
+    /// Parses the cooked header of the given packet,
+    /// or returns `None` if the link type isn't a Linux cooked one or the packet is too short
+    pub fn from_packet(packet: &[u8], my_link_type: MyLinkType) -> Option<Self> {
+        match my_link_type {
+            MyLinkType::LinuxSll(_) => Self::from_sll(packet),
+            MyLinkType::LinuxSll2(_) => Self::from_sll2(packet),
+            _ => None,
+        }
+    }
+
+    /// Reads a `LINUX_SLL` header: <https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL.html>
+    fn from_sll(packet: &[u8]) -> Option<Self> {
+        let h = packet.get(..Self::SLL_LEN)?;
+        Some(Self {
+            packet_type: LinuxSllPacketType::from_u16(u16::from_be_bytes([h[0], h[1]])),
+            arp_hardware_type: u16::from_be_bytes([h[2], h[3]]),
+            link_address_len: usize::from(u16::from_be_bytes([h[4], h[5]])),
+            link_address: h[6..14].try_into().ok()?,
+            protocol_type: u16::from_be_bytes([h[14], h[15]]),
+            len: Self::SLL_LEN,
+        })
+    }
+
+    /// Reads a `LINUX_SLL2` header: <https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL2.html>
+    fn from_sll2(packet: &[u8]) -> Option<Self> {
+        let h = packet.get(..Self::SLL2_LEN)?;
+        Some(Self {
+            protocol_type: u16::from_be_bytes([h[0], h[1]]),
+            // bytes 2..4 are reserved, and 4..8 contain the interface index
+            arp_hardware_type: u16::from_be_bytes([h[8], h[9]]),
+            packet_type: LinuxSllPacketType::from_u16(u16::from(h[10])),
+            link_address_len: usize::from(h[11]),
+            link_address: h[12..20].try_into().ok()?,
+            len: Self::SLL2_LEN,
+        })
+    }
+
+    /// Direction of the packet as recorded by the kernel,
+    /// or `None` if it wasn't sent by nor to this host
+    pub fn traffic_direction(&self) -> Option<TrafficDirection> {
+        match self.packet_type {
+            LinuxSllPacketType::Host
+            | LinuxSllPacketType::Broadcast
+            | LinuxSllPacketType::Multicast => Some(TrafficDirection::Incoming),
+            LinuxSllPacketType::Outgoing => Some(TrafficDirection::Outgoing),
+            LinuxSllPacketType::OtherHost | LinuxSllPacketType::Other(_) => None,
+        }
+    }
+
+    /// MAC address of the sender, if the packet was captured on an Ethernet interface
+    pub fn source_mac(&self) -> Option<String> {
+        if self.arp_hardware_type != Self::ARPHRD_ETHER || self.link_address_len != 6 {
+            return None;
+        }
+        let mac: [u8; 6] = self.link_address[..6].try_into().ok()?;
+        Some(mac_from_dec_to_hex(mac))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pcap::Linktype;
+
+    use super::*;
+
+    const MAC: [u8; 6] = [0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f];
+
+    fn sll(packet_type: u16, arp_hardware_type: u16, protocol_type: u16) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend(packet_type.to_be_bytes());
+        header.extend(arp_hardware_type.to_be_bytes());
+        header.extend(6_u16.to_be_bytes());
+        header.extend(MAC);
+        header.extend([0, 0]);
+        header.extend(protocol_type.to_be_bytes());
+        header
+    }
+
+    fn sll2(packet_type: u8, arp_hardware_type: u16, protocol_type: u16) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend(protocol_type.to_be_bytes());
+        header.extend([0, 0]);
+        header.extend(3_u32.to_be_bytes());
+        header.extend(arp_hardware_type.to_be_bytes());
+        header.extend([packet_type, 6]);
+        header.extend(MAC);
+        header.extend([0, 0]);
+        header
+    }
+
+    #[test]
+    fn test_parse_sll() {
+        let link_type = MyLinkType::from_pcap_link_type(Linktype::LINUX_SLL);
+        let header = LinuxSllHeader::from_packet(&sll(4, 1, 0x0800), link_type).unwrap();
+        assert_eq!(header.packet_type, LinuxSllPacketType::Outgoing);
+        assert_eq!(header.traffic_direction(), Some(TrafficDirection::Outgoing));
+        assert_eq!(header.protocol_type, 0x0800);
+        assert_eq!(header.len, 16);
+        assert_eq!(header.source_mac(), Some("00:1b:2c:3d:4e:5f".to_string()));
+
+        // loopback interface: no MAC address
+        let header = LinuxSllHeader::from_packet(&sll(0, 772, 0x86dd), link_type).unwrap();
+        assert_eq!(header.traffic_direction(), Some(TrafficDirection::Incoming));
+        assert_eq!(header.source_mac(), None);
+
+        assert!(LinuxSllHeader::from_packet(&sll(0, 1, 0x0800)[..15], link_type).is_none());
+    }
+
+    #[test]
+    fn test_parse_sll2() {
+        let link_type = MyLinkType::from_pcap_link_type(Linktype::LINUX_SLL2);
+        let header = LinuxSllHeader::from_packet(&sll2(1, 1, 0x0806), link_type).unwrap();
+        assert_eq!(header.packet_type, LinuxSllPacketType::Broadcast);
+        assert_eq!(header.traffic_direction(), Some(TrafficDirection::Incoming));
+        assert_eq!(header.protocol_type, 0x0806);
+        assert_eq!(header.len, 20);
+        assert_eq!(header.source_mac(), Some("00:1b:2c:3d:4e:5f".to_string()));
+
+        let header = LinuxSllHeader::from_packet(&sll2(3, 1, 0x0800), link_type).unwrap();
+        assert_eq!(header.packet_type, LinuxSllPacketType::OtherHost);
+        assert_eq!(header.traffic_direction(), None);
+
+        // not a cooked capture
+        let ethernet = MyLinkType::from_pcap_link_type(Linktype::ETHERNET);
+        assert!(LinuxSllHeader::from_packet(&sll2(0, 1, 0x0800), ethernet).is_none());
+    }
+}
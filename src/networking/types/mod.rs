@@ -1,25 +1,37 @@
 pub mod address_port_pair;
 pub mod arp_type;
 pub mod asn;
+pub mod blocklist;
 pub mod bogon;
 pub mod byte_multiple;
 pub mod capture_context;
 pub mod data_info;
 pub mod data_info_host;
+pub mod dns_log_entry;
+pub mod dns_message;
+pub mod dns_resolutions;
 pub mod filters;
 pub mod host;
 pub mod host_data_states;
+pub mod host_label;
 pub mod icmp_type;
 pub mod info_address_port_pair;
 pub mod info_traffic;
 pub mod ip_collection;
 pub mod ip_version;
+pub mod linux_sll_header;
 pub mod my_device;
 pub mod my_link_type;
 pub mod packet_filters_fields;
 pub mod port_collection;
+pub mod process;
 pub mod protocol;
 pub mod service;
 pub mod service_query;
+pub mod tcp_flags;
+pub mod tcp_rtt;
+pub mod tls_client_hello;
 pub mod traffic_direction;
 pub mod traffic_type;
+pub mod vlan_collection;
+pub mod wifi_frame;
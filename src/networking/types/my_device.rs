@@ -11,6 +11,8 @@ pub struct MyDevice {
     desc: Option<String>,
     addresses: Vec<Address>,
     link_type: MyLinkType,
+    /// Whether it's a wireless device, which can be captured in monitor mode
+    is_wireless: bool,
 }
 
 impl MyDevice {
@@ -34,6 +36,7 @@ pub fn from_pcap_device(device: Device) -> Self {
             desc: device.desc,
             addresses: device.addresses,
             link_type: MyLinkType::default(),
+            is_wireless: device.flags.is_wireless(),
         }
     }
 
@@ -53,6 +56,10 @@ pub fn set_addresses(&mut self, addresses: Vec<Address>) {
         self.addresses = addresses;
     }
 
+    pub fn is_wireless(&self) -> bool {
+        self.is_wireless
+    }
+
     pub fn get_link_type(&self) -> MyLinkType {
         self.link_type
     }
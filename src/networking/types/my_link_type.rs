@@ -16,6 +16,10 @@ pub enum MyLinkType {
     Loop(Linktype),
     IPv4(Linktype),
     IPv6(Linktype),
+    LinuxSll(Linktype),
+    LinuxSll2(Linktype),
+    Ieee80211(Linktype),
+    Radiotap(Linktype),
     Unsupported(Linktype),
     #[default]
     NotYetAssigned,
@@ -34,10 +38,32 @@ pub fn from_pcap_link_type(link_type: Linktype) -> Self {
             Linktype::LOOP => Self::Loop(link_type),
             Linktype::IPV4 => Self::IPv4(link_type),
             Linktype::IPV6 => Self::IPv6(link_type),
+            Linktype::LINUX_SLL => Self::LinuxSll(link_type),
+            Linktype::LINUX_SLL2 => Self::LinuxSll2(link_type),
+            Linktype::IEEE802_11 => Self::Ieee80211(link_type),
+            Linktype::IEEE802_11_RADIOTAP => Self::Radiotap(link_type),
             _ => Self::Unsupported(link_type),
         }
     }
 
+    /// Returns the pcap link type, or `None` if it's not known yet
+    pub fn pcap_link_type(self) -> Option<Linktype> {
+        match self {
+            Self::Null(l)
+            | Self::Ethernet(l)
+            | Self::RawIp(l)
+            | Self::Loop(l)
+            | Self::IPv4(l)
+            | Self::IPv6(l)
+            | Self::LinuxSll(l)
+            | Self::LinuxSll2(l)
+            | Self::Ieee80211(l)
+            | Self::Radiotap(l)
+            | Self::Unsupported(l) => Some(l),
+            Self::NotYetAssigned => None,
+        }
+    }
+
     pub fn full_print_on_one_line(self, language: Language) -> String {
         match self {
             Self::Null(l)
@@ -46,6 +72,10 @@ pub fn full_print_on_one_line(self, language: Language) -> String {
             | Self::Loop(l)
             | Self::IPv4(l)
             | Self::IPv6(l)
+            | Self::LinuxSll(l)
+            | Self::LinuxSll2(l)
+            | Self::Ieee80211(l)
+            | Self::Radiotap(l)
             | Self::Unsupported(l) => {
                 format!(
                     "{}: {} ({})",
@@ -70,6 +100,10 @@ pub fn link_type_col<'a>(
             | Self::Loop(l)
             | Self::IPv4(l)
             | Self::IPv6(l)
+            | Self::LinuxSll(l)
+            | Self::LinuxSll2(l)
+            | Self::Ieee80211(l)
+            | Self::Radiotap(l)
             | Self::Unsupported(l) => {
                 let link_info = format!(
                     "{} ({})",
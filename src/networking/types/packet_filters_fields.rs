@@ -17,6 +17,8 @@ pub struct PacketFiltersFields {
     pub sport: Option<u16>,
     /// Destination port
     pub dport: Option<u16>,
+    /// VLAN IDs of the tags, outermost first
+    pub vlan_ids: Vec<u16>,
 }
 
 impl Default for PacketFiltersFields {
@@ -28,6 +30,7 @@ fn default() -> Self {
             dest: IpAddr::V6(Ipv6Addr::UNSPECIFIED),
             sport: None,
             dport: None,
+            vlan_ids: Vec::new(),
         }
     }
 }
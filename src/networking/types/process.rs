@@ -0,0 +1,432 @@
+//! Module defining the `Process` struct, representing a local process owning network sockets,
+//! and the `ProcessResolver` struct, used to attribute connections to processes while parsing packets.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+
+use pcap::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::networking::manage_packets::is_my_address;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::utils::error_logger::{ErrorLogger, Location};
+use crate::{Protocol, location};
+
+/// Local process owning the socket of a connection
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub struct Process {
+    /// Process identifier
+    pub pid: u32,
+    /// Name of the executable
+    pub name: String,
+}
+
+impl Display for Process {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (PID {})", self.name, self.pid)
+    }
+}
+
+/// Attributes connections to the local processes owning their sockets.
+///
+/// Sockets are read from `/proc/net/{tcp,udp,tcp6,udp6}` and their owners from `/proc/<pid>/fd`,
+/// so this is only supported on Linux; moreover, sockets of processes belonging to other users
+/// can only be attributed when running with the required privileges.
+///
+/// Walking `/proc` is expensive, so the tables are refreshed by a dedicated thread
+/// and the packet parsing thread only performs lookups on them.
+pub struct ProcessResolver {
+    table: Arc<Mutex<SocketTable>>,
+}
+
+/// Local sockets and processes owning them, as read during the latest refresh
+#[derive(Default)]
+struct SocketTable {
+    /// Inode of the socket bound to each local address:port, for each transport protocol
+    sockets: HashMap<(Protocol, IpAddr, u16), u64>,
+    /// Process owning each socket inode
+    owners: HashMap<u64, Process>,
+}
+
+impl ProcessResolver {
+    /// Time between two refreshes of the tables
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Creates a resolver and spawns the thread in charge of refreshing its tables,
+    /// which terminates as soon as the resolver is dropped
+    pub fn new() -> Self {
+        let table = Arc::new(Mutex::new(SocketTable::default()));
+        if cfg!(target_os = "linux") {
+            let table_weak = Arc::downgrade(&table);
+            let _ = thread::Builder::new()
+                .name("thread_process_resolver".to_string())
+                .spawn(move || refresh_loop(&table_weak))
+                .log_err(location!());
+        }
+        Self { table }
+    }
+
+    /// Returns the local process owning the socket of the given connection, if any
+    pub fn get(
+        &self,
+        key: &AddressPortPair,
+        traffic_direction: TrafficDirection,
+        interface_addresses: &Vec<Address>,
+    ) -> Option<Process> {
+        if !matches!(key.protocol, Protocol::TCP | Protocol::UDP) {
+            return None;
+        }
+        // forwarded traffic can't belong to any local process
+        // (the addresses of some adapters, e.g. the "any" device, are unknown)
+        if !interface_addresses.is_empty()
+            && !is_my_address(&key.address1, interface_addresses)
+            && !is_my_address(&key.address2, interface_addresses)
+        {
+            return None;
+        }
+        let (local_address, local_port) = match traffic_direction {
+            TrafficDirection::Outgoing => (key.address1, key.port1?),
+            TrafficDirection::Incoming => (key.address2, key.port2?),
+        };
+        self.table
+            .lock()
+            .unwrap()
+            .lookup(key.protocol, local_address, local_port)
+    }
+}
+
+impl SocketTable {
+    fn lookup(&self, protocol: Protocol, address: IpAddr, port: u16) -> Option<Process> {
+        // also consider sockets bound to any address
+        [
+            address.to_canonical(),
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        ]
+        .iter()
+        .find_map(|address| self.sockets.get(&(protocol, *address, port)))
+        .and_then(|inode| self.owners.get(inode))
+        .cloned()
+    }
+
+    fn read() -> Self {
+        let mut sockets = HashMap::new();
+        for (protocol, table) in [
+            (Protocol::TCP, "tcp"),
+            (Protocol::TCP, "tcp6"),
+            (Protocol::UDP, "udp"),
+            (Protocol::UDP, "udp6"),
+        ] {
+            let Ok(content) = fs::read_to_string(format!("/proc/net/{table}")) else {
+                continue;
+            };
+            for (address, port, inode) in content.lines().skip(1).filter_map(parse_socket_line) {
+                sockets.insert((protocol, address, port), inode);
+            }
+        }
+        Self {
+            sockets,
+            owners: read_socket_owners(),
+        }
+    }
+}
+
+/// The calling thread enters a loop in which it periodically refreshes the socket table,
+/// until the corresponding resolver is dropped
+fn refresh_loop(table: &Weak<Mutex<SocketTable>>) {
+    loop {
+        // tables are read without holding the lock, not to block the packet parsing thread
+        let new_table = SocketTable::read();
+        let Some(table) = table.upgrade() else {
+            return;
+        };
+        *table.lock().unwrap() = new_table;
+        drop(table);
+        thread::sleep(ProcessResolver::REFRESH_INTERVAL);
+    }
+}
+
+/// Parses a line of `/proc/net/{tcp,udp,tcp6,udp6}`, returning the local address, port, and socket inode
+fn parse_socket_line(line: &str) -> Option<(IpAddr, u16, u64)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let (address, port) = fields.get(1)?.split_once(':')?;
+    let inode = fields.get(9)?.parse().ok()?;
+    if inode == 0 {
+        // e.g., connections in TIME_WAIT state
+        return None;
+    }
+    let port = u16::from_str_radix(port, 16).ok()?;
+    // addresses are printed as 32-bit words in host byte order
+    let mut octets = Vec::with_capacity(16);
+    for i in (0..address.len()).step_by(8) {
+        let word = u32::from_str_radix(address.get(i..i + 8)?, 16).ok()?;
+        octets.extend_from_slice(&word.to_ne_bytes());
+    }
+    let address = match octets.len() {
+        4 => IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3])),
+        16 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(octets).ok()?)).to_canonical(),
+        _ => return None,
+    };
+    Some((address, port, inode))
+}
+
+/// Parses the target of a link in `/proc/<pid>/fd`, returning the inode if it's a socket
+fn parse_socket_link(link: &str) -> Option<u64> {
+    link.strip_prefix("socket:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Returns the file name of the executable a `/proc/<pid>/exe` link points to
+fn parse_exe_link(link: &Path) -> Option<String> {
+    let name = link.file_name()?.to_string_lossy();
+    // the executable may have been replaced or removed after the process started
+    Some(name.strip_suffix(" (deleted)").unwrap_or(&name).to_string())
+}
+
+/// Returns the name of the executable of the process at the given `/proc/<pid>` path
+///
+/// Falls back to `comm` when the `exe` link can't be read (e.g., for kernel threads),
+/// even if it's truncated to 15 characters and can be changed by the process itself
+fn read_process_name(proc_path: &Path) -> String {
+    fs::read_link(proc_path.join("exe"))
+        .ok()
+        .and_then(|link| parse_exe_link(&link))
+        .or_else(|| {
+            fs::read_to_string(proc_path.join("comm"))
+                .ok()
+                .map(|comm| comm.trim().to_string())
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the process owning each socket inode
+fn read_socket_owners() -> HashMap<u64, Process> {
+    let mut owners = HashMap::new();
+    let Ok(proc_entries) = fs::read_dir("/proc") else {
+        return owners;
+    };
+    for proc_entry in proc_entries.flatten() {
+        let Some(pid) = proc_entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        // this fails for processes of other users, if not running with the required privileges
+        let Ok(fd_entries) = fs::read_dir(proc_entry.path().join("fd")) else {
+            continue;
+        };
+        let mut name = None;
+        for fd_entry in fd_entries.flatten() {
+            let Some(inode) = fs::read_link(fd_entry.path())
+                .ok()
+                .and_then(|link| parse_socket_link(&link.to_string_lossy()))
+            else {
+                continue;
+            };
+            let name = name.get_or_insert_with(|| read_process_name(&proc_entry.path()));
+            owners.insert(
+                inode,
+                Process {
+                    pid,
+                    name: name.clone(),
+                },
+            );
+        }
+    }
+    owners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ipv4_socket_line() {
+        let word = u32::from_ne_bytes([127, 0, 0, 1]);
+        let line = format!(
+            "   0: {word:08X}:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000   101        0 23456 1 0000000000000000 100 0 0 10 5"
+        );
+        assert_eq!(
+            parse_socket_line(&line),
+            Some((IpAddr::V4(Ipv4Addr::LOCALHOST), 53, 23456))
+        );
+    }
+
+    #[test]
+    fn test_parse_ipv6_socket_line() {
+        let words: Vec<String> = [
+            [0x20, 0x01, 0x0d, 0xb8],
+            [0, 0, 0, 0],
+            [0, 0, 0, 0],
+            [0, 0, 0, 0x01],
+        ]
+        .iter()
+        .map(|bytes| format!("{:08X}", u32::from_ne_bytes(*bytes)))
+        .collect();
+        let line = format!(
+            "   3: {}:01BB 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 98765 1 0000000000000000 100 0 0 10 0",
+            words.concat()
+        );
+        assert_eq!(
+            parse_socket_line(&line),
+            Some((
+                IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+                443,
+                98765
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_ipv4_mapped_socket_line() {
+        let words: Vec<String> = [
+            [0, 0, 0, 0],
+            [0, 0, 0, 0],
+            [0, 0, 0xff, 0xff],
+            [192, 168, 1, 10],
+        ]
+        .iter()
+        .map(|bytes| format!("{:08X}", u32::from_ne_bytes(*bytes)))
+        .collect();
+        let line = format!(
+            "   0: {}:1F90 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 555 1",
+            words.concat()
+        );
+        assert_eq!(
+            parse_socket_line(&line),
+            Some((IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)), 8080, 555))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_socket_lines() {
+        // header
+        assert_eq!(
+            parse_socket_line(
+                "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode"
+            ),
+            None
+        );
+        // no inode
+        assert_eq!(
+            parse_socket_line(
+                "   1: 0100007F:0035 0100007F:C350 06 00000000:00000000 03:00000F2D 00000000     0        0 0 3"
+            ),
+            None
+        );
+        assert_eq!(parse_socket_line(""), None);
+    }
+
+    #[test]
+    fn test_parse_socket_link() {
+        assert_eq!(parse_socket_link("socket:[12345]"), Some(12345));
+        assert_eq!(parse_socket_link("pipe:[12345]"), None);
+        assert_eq!(parse_socket_link("/dev/null"), None);
+    }
+
+    #[test]
+    fn test_parse_exe_link() {
+        assert_eq!(
+            parse_exe_link(Path::new("/usr/libexec/gnome-shell-calendar-server")),
+            Some("gnome-shell-calendar-server".to_string())
+        );
+        assert_eq!(
+            parse_exe_link(Path::new("/usr/bin/firefox (deleted)")),
+            Some("firefox".to_string())
+        );
+        assert_eq!(parse_exe_link(Path::new("/")), None);
+    }
+
+    #[test]
+    fn test_read_process_name() {
+        let exe = std::env::current_exe().unwrap();
+        assert_eq!(
+            read_process_name(Path::new("/proc/self")),
+            exe.file_name().unwrap().to_string_lossy()
+        );
+        assert_eq!(read_process_name(Path::new("/this/does/not/exist")), "");
+    }
+
+    #[test]
+    fn test_process_resolver_lookups() {
+        let firefox = Process {
+            pid: 4242,
+            name: "firefox".to_string(),
+        };
+        let resolver = ProcessResolver {
+            table: Arc::new(Mutex::new(SocketTable {
+                sockets: HashMap::from([
+                    (
+                        (Protocol::TCP, IpAddr::from([192, 168, 1, 10]), 50_000),
+                        555,
+                    ),
+                    (
+                        (Protocol::UDP, IpAddr::V6(Ipv6Addr::UNSPECIFIED), 5353),
+                        556,
+                    ),
+                ]),
+                owners: HashMap::from([(555, firefox.clone()), (556, firefox.clone())]),
+            })),
+        };
+        let interface_addresses = vec![Address {
+            addr: IpAddr::from([192, 168, 1, 10]),
+            netmask: None,
+            broadcast_addr: None,
+            dst_addr: None,
+        }];
+        let mut key = AddressPortPair {
+            address1: IpAddr::from([192, 168, 1, 10]),
+            port1: Some(50_000),
+            address2: IpAddr::from([1, 1, 1, 1]),
+            port2: Some(443),
+            protocol: Protocol::TCP,
+        };
+        assert_eq!(
+            resolver.get(&key, TrafficDirection::Outgoing, &interface_addresses),
+            Some(firefox.clone())
+        );
+        assert_eq!(
+            resolver.get(
+                &key.reversed(),
+                TrafficDirection::Incoming,
+                &interface_addresses
+            ),
+            Some(firefox.clone())
+        );
+
+        // sockets bound to any address
+        key.protocol = Protocol::UDP;
+        key.port1 = Some(5353);
+        assert_eq!(
+            resolver.get(&key, TrafficDirection::Outgoing, &interface_addresses),
+            Some(firefox)
+        );
+
+        // forwarded traffic isn't looked up
+        key.address1 = IpAddr::from([192, 168, 1, 20]);
+        assert_eq!(
+            resolver.get(&key, TrafficDirection::Outgoing, &interface_addresses),
+            None
+        );
+    }
+
+    #[test]
+    fn test_process_display() {
+        let process = Process {
+            pid: 4242,
+            name: "firefox".to_string(),
+        };
+        assert_eq!(process.to_string(), "firefox (PID 4242)");
+    }
+}
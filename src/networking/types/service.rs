@@ -1,3 +1,8 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::networking::manage_packets::get_service_name;
+
 /// Upper layer services.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Service {
@@ -29,6 +34,25 @@ fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     }
 }
 
+impl Serialize for Service {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Service {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match name.as_str() {
+            "?" => Ok(Service::Unknown),
+            "-" => Ok(Service::NotApplicable),
+            _ => get_service_name(&name)
+                .map(Service::Name)
+                .ok_or_else(|| D::Error::custom(format!("unknown service: {name}"))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +81,19 @@ fn test_service_to_string_with_equal_prefix() {
         // unknown should not have the prefix
         assert_eq!(Service::Unknown.to_string_with_equal_prefix(), "?");
     }
+
+    #[test]
+    fn test_service_serde() {
+        for service in [
+            Service::Name("https"),
+            Service::Name("mdns"),
+            Service::Unknown,
+            Service::NotApplicable,
+        ] {
+            let json = serde_json::to_string(&service).unwrap();
+            assert_eq!(json, format!("\"{service}\""));
+            assert_eq!(serde_json::from_str::<Service>(&json).unwrap(), service);
+        }
+        assert!(serde_json::from_str::<Service>("\"not-a-known-service\"").is_err());
+    }
 }
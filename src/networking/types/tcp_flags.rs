@@ -0,0 +1,383 @@
+//! Module defining the `TcpSegment`, `TcpFlags`, and `TcpFlagsCount` structs and the `TcpState` enum,
+//! used to keep track of the state of TCP connections.
+
+use std::fmt::Write;
+
+use etherparse::TcpHeader;
+use serde::{Deserialize, Serialize};
+
+use crate::translations::translations_4::{
+    tcp_closed_translation, tcp_established_translation, tcp_fin_wait_translation,
+    tcp_half_open_translation, tcp_opening_translation, tcp_reset_translation,
+};
+use crate::translations::types::language::Language;
+use crate::utils::types::timestamp::Timestamp;
+
+/// Relevant fields of a single TCP segment
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct TcpSegment {
+    pub flags: TcpFlags,
+    pub sequence_number: u32,
+    pub acknowledgment_number: u32,
+    /// Length of the TCP payload in bytes (computed from the IP header, since captured packets may be truncated)
+    pub payload_len: u32,
+}
+
+impl TcpSegment {
+    pub fn from_etherparse(tcp_header: &TcpHeader, ip_payload_len: usize) -> Self {
+        Self {
+            flags: TcpFlags::from_etherparse(tcp_header),
+            sequence_number: tcp_header.sequence_number,
+            acknowledgment_number: tcp_header.acknowledgment_number,
+            payload_len: u32::try_from(ip_payload_len.saturating_sub(tcp_header.header_len()))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Relevant flags of a single TCP segment
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct TcpFlags {
+    pub syn: bool,
+    pub ack: bool,
+    pub fin: bool,
+    pub rst: bool,
+}
+
+impl TcpFlags {
+    pub fn from_etherparse(tcp_header: &TcpHeader) -> Self {
+        Self {
+            syn: tcp_header.syn,
+            ack: tcp_header.ack,
+            fin: tcp_header.fin,
+            rst: tcp_header.rst,
+        }
+    }
+}
+
+/// Number of TCP segments observed with the relevant flags
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub struct TcpFlagsCount {
+    /// Segments with SYN set and ACK unset (connection requests)
+    pub syn: usize,
+    /// Segments with both SYN and ACK set (connection request accepted)
+    pub syn_ack: usize,
+    /// Segments with FIN set
+    pub fin: usize,
+    /// Segments with RST set
+    pub rst: usize,
+    /// Segments with neither SYN nor RST set (data or acknowledgements)
+    pub other: usize,
+    /// When the latest segments of each kind were observed
+    #[serde(default)]
+    pub latest: TcpFlagsTimestamps,
+}
+
+/// Timestamps of the latest segments observed with the relevant flags
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub struct TcpFlagsTimestamps {
+    pub syn: Option<Timestamp>,
+    pub syn_ack: Option<Timestamp>,
+    pub fin: Option<Timestamp>,
+    pub rst: Option<Timestamp>,
+    pub other: Option<Timestamp>,
+}
+
+impl TcpFlagsTimestamps {
+    fn refresh(&mut self, other: &Self) {
+        self.syn = self.syn.max(other.syn);
+        self.syn_ack = self.syn_ack.max(other.syn_ack);
+        self.fin = self.fin.max(other.fin);
+        self.rst = self.rst.max(other.rst);
+        self.other = self.other.max(other.other);
+    }
+}
+
+impl TcpFlagsCount {
+    /// Counts a new segment with the given flags
+    pub fn add(&mut self, flags: TcpFlags, timestamp: Timestamp) {
+        let timestamp = Some(timestamp);
+        if flags.syn {
+            if flags.ack {
+                self.syn_ack += 1;
+                self.latest.syn_ack = timestamp;
+            } else {
+                self.syn += 1;
+                self.latest.syn = timestamp;
+            }
+        }
+        if flags.fin {
+            self.fin += 1;
+            self.latest.fin = timestamp;
+        }
+        if flags.rst {
+            self.rst += 1;
+            self.latest.rst = timestamp;
+        }
+        if !flags.syn && !flags.rst {
+            self.other += 1;
+            self.latest.other = timestamp;
+        }
+    }
+
+    pub fn refresh(&mut self, other: &Self) {
+        self.syn += other.syn;
+        self.syn_ack += other.syn_ack;
+        self.fin += other.fin;
+        self.rst += other.rst;
+        self.other += other.other;
+        self.latest.refresh(&other.latest);
+    }
+
+    /// Derives the state of a connection from the segments observed in this direction
+    /// and (if available) in the opposite one.
+    ///
+    /// Only the segments following the latest connection request are considered,
+    /// since the same addresses and ports may be reused by a new connection.
+    pub fn state(&self, reverse: Option<&Self>) -> TcpState {
+        let reverse = reverse.map(|r| r.latest).unwrap_or_default();
+        let mut both = self.latest;
+        both.refresh(&reverse);
+        // segments preceding the latest SYN belong to a previous connection
+        let current = |t: Option<Timestamp>| t.filter(|t| both.syn.is_none_or(|syn| *t >= syn));
+
+        let fins = (current(self.latest.fin), current(reverse.fin));
+        if let Some(rst) = current(both.rst) {
+            // a reset following the closure in both directions doesn't abort anything
+            match fins {
+                (Some(fin), Some(reverse_fin)) if fin.max(reverse_fin) <= rst => TcpState::Closed,
+                _ => TcpState::Reset,
+            }
+        } else if let (Some(_), Some(_)) = fins {
+            TcpState::Closed
+        } else if fins.0.is_some() || fins.1.is_some() {
+            TcpState::FinWait
+        } else if current(both.other).is_some() {
+            // also includes connections already established when the capture was started
+            TcpState::Established
+        } else if current(both.syn_ack).is_some() {
+            TcpState::Opening
+        } else {
+            TcpState::HalfOpen
+        }
+    }
+
+    pub fn pretty_print(&self) -> String {
+        let mut ret_val = String::new();
+        for (flag, n) in [
+            ("SYN", self.syn),
+            ("SYN-ACK", self.syn_ack),
+            ("FIN", self.fin),
+            ("RST", self.rst),
+        ] {
+            if n > 0 {
+                let _ = writeln!(ret_val, "   {flag} ({n})");
+            }
+        }
+        ret_val
+    }
+}
+
+/// State of a TCP connection
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TcpState {
+    /// The connection request has been accepted, but no further segment was exchanged
+    Opening,
+    /// Data or acknowledgements have been exchanged
+    Established,
+    /// One of the endpoints closed its side of the connection, the other one can still send data
+    FinWait,
+    /// Both the endpoints closed the connection
+    Closed,
+    /// One of the endpoints reset the connection (e.g., connection refused)
+    Reset,
+    /// A connection request was sent but never accepted
+    HalfOpen,
+}
+
+impl TcpState {
+    pub fn get_label(self, language: Language) -> &'static str {
+        match self {
+            TcpState::Opening => tcp_opening_translation(language),
+            TcpState::Established => tcp_established_translation(language),
+            TcpState::FinWait => tcp_fin_wait_translation(language),
+            TcpState::Closed => tcp_closed_translation(language),
+            TcpState::Reset => tcp_reset_translation(language),
+            TcpState::HalfOpen => tcp_half_open_translation(language),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYN: TcpFlags = TcpFlags {
+        syn: true,
+        ack: false,
+        fin: false,
+        rst: false,
+    };
+    const SYN_ACK: TcpFlags = TcpFlags {
+        syn: true,
+        ack: true,
+        fin: false,
+        rst: false,
+    };
+    const ACK: TcpFlags = TcpFlags {
+        syn: false,
+        ack: true,
+        fin: false,
+        rst: false,
+    };
+    const FIN_ACK: TcpFlags = TcpFlags {
+        syn: false,
+        ack: true,
+        fin: true,
+        rst: false,
+    };
+    const RST_ACK: TcpFlags = TcpFlags {
+        syn: false,
+        ack: true,
+        fin: false,
+        rst: true,
+    };
+
+    fn ts(secs: i64) -> Option<Timestamp> {
+        Some(Timestamp::new(secs, 0))
+    }
+
+    /// Counts segments observed at the given seconds
+    fn count_at(segments: &[(TcpFlags, i64)]) -> TcpFlagsCount {
+        let mut count = TcpFlagsCount::default();
+        for (f, secs) in segments {
+            count.add(*f, Timestamp::new(*secs, 0));
+        }
+        count
+    }
+
+    /// Counts segments observed one second apart from each other
+    fn count(flags: &[TcpFlags]) -> TcpFlagsCount {
+        let segments: Vec<(TcpFlags, i64)> = flags.iter().copied().zip(0..).collect();
+        count_at(&segments)
+    }
+
+    #[test]
+    fn test_tcp_flags_count_add() {
+        assert_eq!(
+            count(&[SYN, SYN_ACK, ACK, ACK, FIN_ACK, RST_ACK]),
+            TcpFlagsCount {
+                syn: 1,
+                syn_ack: 1,
+                fin: 1,
+                rst: 1,
+                other: 3,
+                latest: TcpFlagsTimestamps {
+                    syn: ts(0),
+                    syn_ack: ts(1),
+                    fin: ts(4),
+                    rst: ts(5),
+                    other: ts(4),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_tcp_flags_count_refresh() {
+        let mut count_1 = count(&[SYN, ACK]);
+        count_1.refresh(&count(&[SYN_ACK, FIN_ACK]));
+        assert_eq!(
+            count_1,
+            TcpFlagsCount {
+                syn: 1,
+                syn_ack: 1,
+                fin: 1,
+                rst: 0,
+                other: 2,
+                latest: TcpFlagsTimestamps {
+                    syn: ts(0),
+                    syn_ack: ts(0),
+                    fin: ts(1),
+                    rst: None,
+                    other: ts(1),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_tcp_state() {
+        // SYN never answered
+        assert_eq!(count(&[SYN, SYN]).state(None), TcpState::HalfOpen);
+        // SYN answered with RST (connection refused)
+        assert_eq!(
+            count(&[SYN]).state(Some(&count(&[RST_ACK]))),
+            TcpState::Reset
+        );
+        // handshake not yet completed
+        assert_eq!(
+            count(&[SYN]).state(Some(&count(&[SYN_ACK]))),
+            TcpState::Opening
+        );
+        // handshake completed
+        assert_eq!(
+            count(&[SYN, ACK]).state(Some(&count(&[SYN_ACK]))),
+            TcpState::Established
+        );
+        // connection started before the capture
+        assert_eq!(count(&[ACK]).state(None), TcpState::Established);
+        // connection closed
+        assert_eq!(
+            count(&[SYN, ACK, FIN_ACK]).state(Some(&count(&[SYN_ACK, FIN_ACK]))),
+            TcpState::Closed
+        );
+    }
+
+    #[test]
+    fn test_tcp_state_half_close() {
+        let client = count_at(&[(SYN, 0), (ACK, 2), (FIN_ACK, 3)]);
+        let server = count_at(&[(SYN_ACK, 1), (ACK, 4)]);
+        // only the client closed its side
+        assert_eq!(client.state(Some(&server)), TcpState::FinWait);
+        assert_eq!(server.state(Some(&client)), TcpState::FinWait);
+        assert_eq!(client.state(None), TcpState::FinWait);
+        // and then the server closed its own
+        let mut server_closing = server;
+        server_closing.add(FIN_ACK, Timestamp::new(5, 0));
+        assert_eq!(client.state(Some(&server_closing)), TcpState::Closed);
+    }
+
+    #[test]
+    fn test_tcp_state_reset_order() {
+        let client = count_at(&[(SYN, 0), (ACK, 2), (FIN_ACK, 3)]);
+        // reset following the closure in both directions
+        let server = count_at(&[(SYN_ACK, 1), (FIN_ACK, 4), (RST_ACK, 5)]);
+        assert_eq!(client.state(Some(&server)), TcpState::Closed);
+        // reset aborting a half-closed connection
+        let server = count_at(&[(SYN_ACK, 1), (RST_ACK, 4)]);
+        assert_eq!(client.state(Some(&server)), TcpState::Reset);
+        // reset of a previous connection, with the same ports reused by a new one
+        let client = count_at(&[(SYN, 0), (SYN, 10), (ACK, 12)]);
+        let server = count_at(&[(RST_ACK, 1), (SYN_ACK, 11)]);
+        assert_eq!(client.state(Some(&server)), TcpState::Established);
+        assert_eq!(server.state(Some(&client)), TcpState::Established);
+    }
+
+    #[test]
+    fn test_tcp_flags_pretty_print() {
+        assert_eq!(count(&[ACK, ACK]).pretty_print(), "");
+        assert_eq!(
+            count(&[SYN, ACK, FIN_ACK, RST_ACK]).pretty_print(),
+            "   SYN (1)\n   FIN (1)\n   RST (1)\n"
+        );
+    }
+
+    #[test]
+    fn test_tcp_state_label() {
+        assert_eq!(TcpState::HalfOpen.get_label(Language::EN), "Half-open");
+        assert_eq!(TcpState::Established.get_label(Language::EN), "Established");
+        assert_eq!(TcpState::FinWait.get_label(Language::EN), "Closing");
+        assert_eq!(TcpState::Reset.get_label(Language::IT), "Reimpostata");
+    }
+}
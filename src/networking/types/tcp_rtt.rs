@@ -0,0 +1,354 @@
+//! Module defining the `TcpRtt` struct, representing the round-trip time estimations of a TCP connection,
+//! and the `TcpRttTracker` struct, used to compute them while parsing packets.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::tcp_flags::TcpSegment;
+use crate::utils::types::timestamp::Timestamp;
+
+/// Round-trip time estimations of a TCP connection
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub struct TcpRtt {
+    /// Time elapsed between the SYN and the ACK completing the three-way handshake
+    pub handshake: Option<Duration>,
+    /// Smoothed RTT computed from the acknowledgements of data segments (as described in RFC 6298)
+    pub rolling: Option<Duration>,
+}
+
+impl TcpRtt {
+    pub fn refresh(&mut self, other: &Self) {
+        if other.handshake.is_some() {
+            self.handshake = other.handshake;
+        }
+        if other.rolling.is_some() {
+            self.rolling = other.rolling;
+        }
+    }
+
+    /// Returns the most up-to-date estimation available
+    pub fn value(&self) -> Option<Duration> {
+        self.rolling.or(self.handshake)
+    }
+
+    /// Compares two estimations, considering connections without an estimation greater than any other
+    pub fn cmp_values(&self, other: &Self) -> Ordering {
+        match (self.value(), other.value()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    pub fn formatted_string(&self) -> String {
+        match self.value() {
+            None => "-".to_string(),
+            Some(rtt) if rtt < Duration::from_secs(1) => {
+                format!("{:.1} ms", rtt.as_secs_f64() * 1000.0)
+            }
+            Some(rtt) => format!("{:.2} s", rtt.as_secs_f64()),
+        }
+    }
+}
+
+/// Keeps track of the TCP segments needed to estimate the round-trip time of each connection.
+///
+/// Each connection is identified by a single `AddressPortPair`, independently of the direction of its segments.
+#[derive(Default)]
+pub struct TcpRttTracker {
+    connections: HashMap<AddressPortPair, ConnectionRttState>,
+    /// Timestamp of the latest removal of idle connections
+    last_cleanup: Timestamp,
+}
+
+#[derive(Default)]
+struct ConnectionRttState {
+    /// Timestamp of the SYN opening the connection
+    syn_timestamp: Option<Timestamp>,
+    /// Direction of the SYN-ACK and acknowledgment number expected in the reply completing the handshake
+    handshake_ack: Option<(usize, u32)>,
+    /// For each direction, the segment being timed: acknowledgment number expected and timestamp
+    pending: [Option<(u32, Timestamp)>; 2],
+    /// For each direction, the highest sequence number sent so far (used to skip retransmissions)
+    highest_seq: [Option<u32>; 2],
+    /// For each direction, whether a FIN was observed
+    fin: [bool; 2],
+    /// Timestamp of the latest segment of the connection
+    last_seen: Timestamp,
+    rtt: TcpRtt,
+}
+
+impl TcpRttTracker {
+    /// Connections without segments for this long are forgotten
+    /// (they timed out, or their closing segments weren't observed)
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+    /// Updates the estimations of the connection a segment belongs to, and returns them
+    pub fn update(
+        &mut self,
+        key: &AddressPortPair,
+        segment: &TcpSegment,
+        timestamp: Timestamp,
+    ) -> TcpRtt {
+        let (conn_key, dir) = if (key.address1, key.port1) <= (key.address2, key.port2) {
+            (*key, 0)
+        } else {
+            (key.reversed(), 1)
+        };
+        let other_dir = 1 - dir;
+        let flags = segment.flags;
+        let seq = segment.sequence_number;
+        let ack = segment.acknowledgment_number;
+
+        let conn = self.connections.entry(conn_key).or_default();
+
+        if flags.syn && !flags.ack {
+            // a new connection is being opened: forget about any previous one on the same pair
+            *conn = ConnectionRttState {
+                syn_timestamp: Some(timestamp),
+                ..ConnectionRttState::default()
+            };
+        } else if flags.syn {
+            conn.handshake_ack = Some((dir, seq.wrapping_add(1)));
+        }
+        conn.last_seen = timestamp;
+
+        if flags.ack {
+            // handshake completed
+            if let (Some(syn_timestamp), Some((synack_dir, expected_ack))) =
+                (conn.syn_timestamp, conn.handshake_ack)
+                && !flags.syn
+                && synack_dir == other_dir
+                && ack == expected_ack
+            {
+                conn.rtt.handshake = elapsed(syn_timestamp, timestamp);
+                conn.syn_timestamp = None;
+                conn.handshake_ack = None;
+            }
+            // timed segment of the opposite direction acknowledged
+            if let Some((expected_ack, sent_timestamp)) = conn.pending[other_dir]
+                && seq_ge(ack, expected_ack)
+            {
+                if let Some(sample) = elapsed(sent_timestamp, timestamp) {
+                    conn.rtt.rolling = Some(match conn.rtt.rolling {
+                        None => sample,
+                        Some(srtt) => srtt * 7 / 8 + sample / 8,
+                    });
+                }
+                conn.pending[other_dir] = None;
+            }
+        }
+
+        if segment.payload_len > 0 {
+            let end = seq.wrapping_add(segment.payload_len);
+            let is_new_data = conn.highest_seq[dir].is_none_or(|highest| seq_gt(end, highest));
+            if is_new_data {
+                conn.highest_seq[dir] = Some(end);
+                if conn.pending[dir].is_none() {
+                    conn.pending[dir] = Some((end, timestamp));
+                }
+            } else {
+                // retransmission: the acknowledgement would be ambiguous (Karn's algorithm)
+                conn.pending[dir] = None;
+            }
+        }
+
+        if flags.fin {
+            conn.fin[dir] = true;
+        }
+
+        let rtt = conn.rtt;
+        if flags.rst || conn.fin == [true, true] {
+            self.connections.remove(&conn_key);
+        }
+        self.remove_idle(timestamp);
+        rtt
+    }
+
+    /// Forgets about idle connections (checked at most once per timeout interval)
+    fn remove_idle(&mut self, now: Timestamp) {
+        if elapsed(self.last_cleanup, now).is_none_or(|e| e < Self::IDLE_TIMEOUT) {
+            return;
+        }
+        self.last_cleanup = now;
+        self.connections.retain(|_, conn| {
+            elapsed(conn.last_seen, now).is_none_or(|e| e < Self::IDLE_TIMEOUT)
+        });
+    }
+}
+
+/// Time elapsed between two timestamps (`None` if negative)
+fn elapsed(from: Timestamp, to: Timestamp) -> Option<Duration> {
+    let usecs = to.to_usecs()?.checked_sub(from.to_usecs()?)?;
+    Some(Duration::from_micros(u64::try_from(usecs).ok()?))
+}
+
+/// Compares sequence numbers taking into account wrap-around
+#[allow(clippy::cast_possible_wrap)]
+fn seq_ge(a: u32, b: u32) -> bool {
+    a.wrapping_sub(b) as i32 >= 0
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn seq_gt(a: u32, b: u32) -> bool {
+    a.wrapping_sub(b) as i32 > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::*;
+    use crate::Protocol;
+    use crate::networking::types::tcp_flags::TcpFlags;
+
+    fn client_to_server() -> AddressPortPair {
+        AddressPortPair::new(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+            Some(50000),
+            IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+            Some(443),
+            Protocol::TCP,
+        )
+    }
+
+    fn segment(flags: &str, seq: u32, ack: u32, payload_len: u32) -> TcpSegment {
+        TcpSegment {
+            flags: TcpFlags {
+                syn: flags.contains('S'),
+                ack: flags.contains('A'),
+                fin: flags.contains('F'),
+                rst: flags.contains('R'),
+            },
+            sequence_number: seq,
+            acknowledgment_number: ack,
+            payload_len,
+        }
+    }
+
+    fn ms(millis: i64) -> Timestamp {
+        Timestamp::new(100 + millis / 1000, (millis % 1000) * 1000)
+    }
+
+    #[test]
+    fn test_handshake_rtt() {
+        let c2s = client_to_server();
+        let s2c = c2s.reversed();
+        let mut tracker = TcpRttTracker::default();
+
+        let rtt = tracker.update(&c2s, &segment("S", 1000, 0, 0), ms(0));
+        assert_eq!(rtt, TcpRtt::default());
+        let rtt = tracker.update(&s2c, &segment("SA", 5000, 1001, 0), ms(20));
+        assert_eq!(rtt, TcpRtt::default());
+        let rtt = tracker.update(&c2s, &segment("A", 1001, 5001, 0), ms(30));
+        assert_eq!(rtt.handshake, Some(Duration::from_millis(30)));
+        assert_eq!(rtt.rolling, None);
+        assert_eq!(rtt.value(), Some(Duration::from_millis(30)));
+    }
+
+    #[test]
+    fn test_rolling_rtt() {
+        let c2s = client_to_server();
+        let s2c = c2s.reversed();
+        let mut tracker = TcpRttTracker::default();
+
+        // connection already established when the capture started
+        tracker.update(&c2s, &segment("A", 1000, 5000, 100), ms(0));
+        tracker.update(&c2s, &segment("A", 1100, 5000, 100), ms(1));
+        // partial ACK: the timed segment is the first one
+        let rtt = tracker.update(&s2c, &segment("A", 5000, 1100, 0), ms(40));
+        assert_eq!(rtt.rolling, Some(Duration::from_millis(40)));
+        // segment sent after the previous sample completed
+        tracker.update(&c2s, &segment("A", 1200, 5000, 100), ms(50));
+        let rtt = tracker.update(&s2c, &segment("A", 5000, 1300, 0), ms(130));
+        // 40 * 7/8 + 80 / 8
+        assert_eq!(rtt.rolling, Some(Duration::from_millis(45)));
+        assert_eq!(rtt.handshake, None);
+        assert_eq!(rtt.value(), Some(Duration::from_millis(45)));
+    }
+
+    #[test]
+    fn test_retransmissions_are_not_sampled() {
+        let c2s = client_to_server();
+        let s2c = c2s.reversed();
+        let mut tracker = TcpRttTracker::default();
+
+        tracker.update(&c2s, &segment("A", 1000, 5000, 100), ms(0));
+        // retransmission of the timed segment
+        tracker.update(&c2s, &segment("A", 1000, 5000, 100), ms(200));
+        let rtt = tracker.update(&s2c, &segment("A", 5000, 1100, 0), ms(210));
+        assert_eq!(rtt.rolling, None);
+    }
+
+    #[test]
+    fn test_sequence_numbers_wrap_around() {
+        let c2s = client_to_server();
+        let s2c = c2s.reversed();
+        let mut tracker = TcpRttTracker::default();
+
+        tracker.update(&c2s, &segment("A", u32::MAX - 49, 5000, 100), ms(0));
+        let rtt = tracker.update(&s2c, &segment("A", 5000, 50, 0), ms(25));
+        assert_eq!(rtt.rolling, Some(Duration::from_millis(25)));
+    }
+
+    #[test]
+    fn test_closed_connections_are_forgotten() {
+        let c2s = client_to_server();
+        let s2c = c2s.reversed();
+        let mut tracker = TcpRttTracker::default();
+
+        tracker.update(&c2s, &segment("A", 1000, 5000, 0), ms(0));
+        assert_eq!(tracker.connections.len(), 1);
+        tracker.update(&c2s, &segment("FA", 1000, 5000, 0), ms(1));
+        assert_eq!(tracker.connections.len(), 1);
+        tracker.update(&s2c, &segment("FA", 5000, 1001, 0), ms(2));
+        assert!(tracker.connections.is_empty());
+
+        tracker.update(&s2c, &segment("A", 5000, 1001, 0), ms(3));
+        assert_eq!(tracker.connections.len(), 1);
+        tracker.update(&s2c, &segment("R", 5000, 1001, 0), ms(4));
+        assert!(tracker.connections.is_empty());
+    }
+
+    #[test]
+    fn test_idle_connections_are_forgotten() {
+        let c2s = client_to_server();
+        let mut other = c2s;
+        other.port1 = Some(50001);
+        let mut tracker = TcpRttTracker::default();
+
+        tracker.update(&c2s, &segment("S", 1000, 0, 0), ms(0));
+        tracker.update(&other, &segment("S", 2000, 0, 0), ms(0));
+        tracker.update(&other, &segment("A", 2001, 0, 10), ms(200_000));
+        assert_eq!(tracker.connections.len(), 2);
+        tracker.update(&other, &segment("A", 2011, 0, 10), ms(500_000));
+        assert_eq!(tracker.connections.len(), 1);
+        assert!(tracker.connections.contains_key(&other.reversed()));
+    }
+
+    #[test]
+    fn test_tcp_rtt_formatted_string_and_cmp() {
+        let none = TcpRtt::default();
+        let fast = TcpRtt {
+            handshake: Some(Duration::from_micros(12_345)),
+            rolling: None,
+        };
+        let slow = TcpRtt {
+            handshake: Some(Duration::from_millis(1)),
+            rolling: Some(Duration::from_millis(1500)),
+        };
+        assert_eq!(none.formatted_string(), "-");
+        assert_eq!(fast.formatted_string(), "12.3 ms");
+        assert_eq!(slow.formatted_string(), "1.50 s");
+
+        assert_eq!(fast.cmp_values(&slow), Ordering::Less);
+        assert_eq!(slow.cmp_values(&none), Ordering::Less);
+        assert_eq!(none.cmp_values(&fast), Ordering::Greater);
+        assert_eq!(none.cmp_values(&none), Ordering::Equal);
+    }
+}
@@ -0,0 +1,432 @@
+//! Module defining the `TlsClientHello` struct, representing the relevant content of a TLS ClientHello message,
+//! and the `TlsClientHelloTracker` struct, used to extract it while parsing packets.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::tcp_flags::TcpSegment;
+use crate::utils::types::timestamp::Timestamp;
+
+/// Relevant content of a TLS ClientHello message
+#[derive(Clone, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub struct TlsClientHello {
+    /// Server Name Indication
+    pub server_name: Option<String>,
+    /// Application-Layer Protocol Negotiation list (e.g., "h2", "http/1.1")
+    pub alpn: Vec<String>,
+}
+
+/// Outcome of the parsing of a TLS ClientHello
+#[derive(PartialEq, Eq, Debug)]
+enum ParseOutcome {
+    Complete(TlsClientHello),
+    /// The data seen so far is the beginning of a ClientHello
+    Incomplete,
+    /// The data isn't a ClientHello
+    Invalid,
+}
+
+impl TlsClientHello {
+    const RECORD_HEADER_LEN: usize = 5;
+    const HANDSHAKE_HEADER_LEN: usize = 4;
+    const MAX_RECORD_LEN: usize = 16384;
+
+    const EXTENSION_SERVER_NAME: u16 = 0;
+    const EXTENSION_ALPN: u16 = 16;
+
+    fn parse(data: &[u8]) -> ParseOutcome {
+        // TLS record header: content type (handshake), version, length
+        if data.len() < Self::RECORD_HEADER_LEN {
+            return if data.is_empty() || data[0] == 0x16 {
+                ParseOutcome::Incomplete
+            } else {
+                ParseOutcome::Invalid
+            };
+        }
+        let record_len = usize::from(u16::from_be_bytes([data[3], data[4]]));
+        if data[0] != 0x16 || data[1] != 0x03 || record_len > Self::MAX_RECORD_LEN {
+            return ParseOutcome::Invalid;
+        }
+        // handshake type (ClientHello)
+        if data
+            .get(Self::RECORD_HEADER_LEN)
+            .is_some_and(|t| *t != 0x01)
+        {
+            return ParseOutcome::Invalid;
+        }
+        let Some(record) = data.get(Self::RECORD_HEADER_LEN..Self::RECORD_HEADER_LEN + record_len)
+        else {
+            return ParseOutcome::Incomplete;
+        };
+        match Self::parse_client_hello(record.get(Self::HANDSHAKE_HEADER_LEN..).unwrap_or(&[])) {
+            Some(client_hello) => ParseOutcome::Complete(client_hello),
+            None => ParseOutcome::Invalid,
+        }
+    }
+
+    fn parse_client_hello(body: &[u8]) -> Option<Self> {
+        let mut reader = Reader::new(body);
+        // client version and random
+        reader.skip(2 + 32)?;
+        // session ID
+        let len = usize::from(reader.u8()?);
+        reader.skip(len)?;
+        // cipher suites
+        let len = usize::from(reader.u16()?);
+        reader.skip(len)?;
+        // compression methods
+        let len = usize::from(reader.u8()?);
+        reader.skip(len)?;
+
+        let mut client_hello = Self::default();
+        if reader.is_empty() {
+            // no extensions
+            return Some(client_hello);
+        }
+        let len = usize::from(reader.u16()?);
+        let mut extensions = Reader::new(reader.bytes(len)?);
+        while !extensions.is_empty() {
+            let extension_type = extensions.u16()?;
+            let len = usize::from(extensions.u16()?);
+            let mut extension = Reader::new(extensions.bytes(len)?);
+            match extension_type {
+                Self::EXTENSION_SERVER_NAME => {
+                    let len = usize::from(extension.u16()?);
+                    let mut names = Reader::new(extension.bytes(len)?);
+                    while !names.is_empty() {
+                        let name_type = names.u8()?;
+                        let len = usize::from(names.u16()?);
+                        let name = names.bytes(len)?;
+                        // host_name (always ASCII, as internationalized names are punycode-encoded)
+                        if name_type == 0 && name.is_ascii() {
+                            client_hello.server_name =
+                                Some(String::from_utf8_lossy(name).to_lowercase());
+                        }
+                    }
+                }
+                Self::EXTENSION_ALPN => {
+                    let len = usize::from(extension.u16()?);
+                    let mut protocols = Reader::new(extension.bytes(len)?);
+                    while !protocols.is_empty() {
+                        let len = usize::from(protocols.u8()?);
+                        let protocol = protocols.bytes(len)?;
+                        client_hello
+                            .alpn
+                            .push(String::from_utf8_lossy(protocol).to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(client_hello)
+    }
+
+    pub fn alpn_string(&self) -> String {
+        if self.alpn.is_empty() {
+            "-".to_string()
+        } else {
+            self.alpn.join(", ")
+        }
+    }
+}
+
+/// Minimal big-endian reader over a slice
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.data.len() {
+            return None;
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.bytes(2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+    }
+}
+
+/// Keeps track of the ClientHello messages observed for each TCP connection.
+#[derive(Default)]
+pub struct TlsClientHelloTracker {
+    /// Initial bytes of the ClientHello messages spanning multiple segments,
+    /// with the timestamp of the latest segment
+    partial: HashMap<AddressPortPair, (Vec<u8>, Timestamp)>,
+    /// ClientHello of each connection, identified by its client-to-server address:port pair,
+    /// with the timestamp of the latest segment of the connection
+    client_hellos: HashMap<AddressPortPair, (TlsClientHello, Timestamp)>,
+    /// Timestamp of the latest removal of idle connections
+    last_cleanup: Timestamp,
+}
+
+impl TlsClientHelloTracker {
+    /// Max number of ClientHello messages waiting for further segments
+    const MAX_PARTIAL: usize = 256;
+    /// Connections without segments for this long are forgotten
+    /// (they timed out, or their closing segments weren't observed)
+    const IDLE_TIMEOUT_SECS: i64 = 300;
+
+    /// Looks for a ClientHello in the payload of a TCP segment
+    pub fn update(
+        &mut self,
+        key: &AddressPortPair,
+        segment: &TcpSegment,
+        payload: &[u8],
+        timestamp: Timestamp,
+    ) {
+        if !payload.is_empty() {
+            let outcome = if let Some((mut data, _)) = self.partial.remove(key) {
+                data.extend_from_slice(payload);
+                let outcome = TlsClientHello::parse(&data);
+                if outcome == ParseOutcome::Incomplete {
+                    self.partial.insert(*key, (data, timestamp));
+                }
+                outcome
+            } else if payload[0] == 0x16 && !self.client_hellos.contains_key(key) {
+                let outcome = TlsClientHello::parse(payload);
+                if outcome == ParseOutcome::Incomplete && self.partial.len() < Self::MAX_PARTIAL {
+                    self.partial.insert(*key, (payload.to_vec(), timestamp));
+                }
+                outcome
+            } else {
+                ParseOutcome::Invalid
+            };
+            if let ParseOutcome::Complete(client_hello) = outcome {
+                self.client_hellos.insert(*key, (client_hello, timestamp));
+            }
+        }
+
+        if segment.flags.fin || segment.flags.rst {
+            self.partial.remove(key);
+            self.client_hellos.remove(key);
+            self.client_hellos.remove(&key.reversed());
+        } else if let Some((_, last_seen)) = self.client_hellos.get_mut(key) {
+            *last_seen = timestamp;
+        } else if let Some((_, last_seen)) = self.client_hellos.get_mut(&key.reversed()) {
+            *last_seen = timestamp;
+        }
+
+        self.remove_idle(timestamp);
+    }
+
+    /// Returns the ClientHello of the connection a pair belongs to (in either direction)
+    pub fn get(&self, key: &AddressPortPair) -> Option<&TlsClientHello> {
+        self.client_hellos
+            .get(key)
+            .or_else(|| self.client_hellos.get(&key.reversed()))
+            .map(|(client_hello, _)| client_hello)
+    }
+
+    /// Forgets about idle connections (checked at most once per timeout interval)
+    fn remove_idle(&mut self, now: Timestamp) {
+        let is_active =
+            |last_seen: &Timestamp| now.secs() - last_seen.secs() < Self::IDLE_TIMEOUT_SECS;
+        if is_active(&self.last_cleanup) {
+            return;
+        }
+        self.last_cleanup = now;
+        self.partial
+            .retain(|_, (_, last_seen)| is_active(last_seen));
+        self.client_hellos
+            .retain(|_, (_, last_seen)| is_active(last_seen));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::*;
+    use crate::Protocol;
+    use crate::networking::types::tcp_flags::TcpFlags;
+
+    /// Builds a ClientHello for the given server name and ALPN list
+    fn client_hello(server_name: &str, alpn: &[&str]) -> Vec<u8> {
+        let mut sni = Vec::new();
+        let name_len = u16::try_from(server_name.len()).unwrap();
+        sni.extend_from_slice(&(name_len + 3).to_be_bytes());
+        sni.push(0);
+        sni.extend_from_slice(&name_len.to_be_bytes());
+        sni.extend_from_slice(server_name.as_bytes());
+
+        let mut alpn_list = Vec::new();
+        for protocol in alpn {
+            alpn_list.push(u8::try_from(protocol.len()).unwrap());
+            alpn_list.extend_from_slice(protocol.as_bytes());
+        }
+        let mut alpn_ext = u16::try_from(alpn_list.len())
+            .unwrap()
+            .to_be_bytes()
+            .to_vec();
+        alpn_ext.extend(alpn_list);
+
+        let mut extensions = Vec::new();
+        // an extension to be ignored (supported versions)
+        extensions.extend_from_slice(&[0x00, 0x2B, 0x00, 0x03, 0x02, 0x03, 0x04]);
+        for (ext_type, ext) in [(0_u16, sni), (16, alpn_ext)] {
+            extensions.extend_from_slice(&ext_type.to_be_bytes());
+            extensions.extend_from_slice(&u16::try_from(ext.len()).unwrap().to_be_bytes());
+            extensions.extend(ext);
+        }
+
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0xAB; 32]);
+        // session ID
+        body.push(32);
+        body.extend_from_slice(&[0xCD; 32]);
+        // cipher suites
+        body.extend_from_slice(&[0x00, 0x04, 0x13, 0x01, 0x13, 0x02]);
+        // compression methods
+        body.extend_from_slice(&[0x01, 0x00]);
+        body.extend_from_slice(&u16::try_from(extensions.len()).unwrap().to_be_bytes());
+        body.extend(extensions);
+
+        let mut handshake = vec![0x01];
+        handshake.extend_from_slice(&u32::try_from(body.len()).unwrap().to_be_bytes()[1..]);
+        handshake.extend(body);
+
+        let mut record = vec![0x16, 0x03, 0x01];
+        record.extend_from_slice(&u16::try_from(handshake.len()).unwrap().to_be_bytes());
+        record.extend(handshake);
+        record
+    }
+
+    fn key() -> AddressPortPair {
+        AddressPortPair::new(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+            Some(50000),
+            IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+            Some(443),
+            Protocol::TCP,
+        )
+    }
+
+    fn segment(fin: bool) -> TcpSegment {
+        TcpSegment {
+            flags: TcpFlags {
+                ack: true,
+                fin,
+                ..TcpFlags::default()
+            },
+            ..TcpSegment::default()
+        }
+    }
+
+    fn ts(secs: i64) -> Timestamp {
+        Timestamp::new(1_000 + secs, 0)
+    }
+
+    #[test]
+    fn test_parse_client_hello() {
+        assert_eq!(
+            TlsClientHello::parse(&client_hello("www.Example.com", &["h2", "http/1.1"])),
+            ParseOutcome::Complete(TlsClientHello {
+                server_name: Some("www.example.com".to_string()),
+                alpn: vec!["h2".to_string(), "http/1.1".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_incomplete_or_invalid_client_hello() {
+        let data = client_hello("example.com", &[]);
+        assert_eq!(
+            TlsClientHello::parse(&data[..data.len() - 1]),
+            ParseOutcome::Incomplete
+        );
+        assert_eq!(TlsClientHello::parse(&data[..3]), ParseOutcome::Incomplete);
+        // not a handshake
+        assert_eq!(
+            TlsClientHello::parse(&[0x17, 0x03, 0x03, 0x00, 0x10]),
+            ParseOutcome::Invalid
+        );
+        // HTTP
+        assert_eq!(
+            TlsClientHello::parse(b"GET / HTTP/1.1\r\n"),
+            ParseOutcome::Invalid
+        );
+        // ServerHello
+        let mut server_hello = data.clone();
+        server_hello[5] = 0x02;
+        assert_eq!(TlsClientHello::parse(&server_hello), ParseOutcome::Invalid);
+        // inconsistent lengths
+        let mut broken = data;
+        broken[9 + 2 + 32] = 200;
+        assert_eq!(TlsClientHello::parse(&broken), ParseOutcome::Invalid);
+    }
+
+    #[test]
+    fn test_tracker_reassembles_client_hello() {
+        let data = client_hello("example.com", &["h2"]);
+        let mut tracker = TlsClientHelloTracker::default();
+
+        tracker.update(&key(), &segment(false), &data[..60], ts(0));
+        assert_eq!(tracker.get(&key()), None);
+        tracker.update(&key(), &segment(false), &data[60..], ts(0));
+
+        let expected = TlsClientHello {
+            server_name: Some("example.com".to_string()),
+            alpn: vec!["h2".to_string()],
+        };
+        assert_eq!(tracker.get(&key()), Some(&expected));
+        assert_eq!(tracker.get(&key().reversed()), Some(&expected));
+        assert_eq!(expected.alpn_string(), "h2");
+
+        // application data doesn't overwrite it
+        tracker.update(
+            &key(),
+            &segment(false),
+            &[0x17, 0x03, 0x03, 0x00, 0x01, 0x00],
+            ts(1),
+        );
+        assert_eq!(tracker.get(&key()), Some(&expected));
+
+        // forgotten when the connection is closed
+        tracker.update(&key().reversed(), &segment(true), &[], ts(2));
+        assert_eq!(tracker.get(&key()), None);
+    }
+
+    #[test]
+    fn test_tracker_forgets_idle_connections() {
+        let data = client_hello("example.com", &[]);
+        let mut other = key();
+        other.port1 = Some(50001);
+        let mut tracker = TlsClientHelloTracker::default();
+
+        tracker.update(&key(), &segment(false), &data, ts(0));
+        tracker.update(&other, &segment(false), &data[..60], ts(0));
+        assert_eq!(tracker.client_hellos.len(), 1);
+        assert_eq!(tracker.partial.len(), 1);
+
+        // the connection is still active, but the ClientHello spanning multiple segments was never completed
+        tracker.update(&key().reversed(), &segment(false), &[], ts(200));
+        tracker.update(&key(), &segment(false), &[], ts(400));
+        assert!(tracker.get(&key()).is_some());
+        assert!(tracker.partial.is_empty());
+
+        tracker.update(&other, &segment(false), &[], ts(800));
+        assert_eq!(tracker.get(&key()), None);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::networking::types::port_collection::PortCollection;
+
+/// VLAN IDs to filter, expressed with the same syntax used for ports (e.g., `10, 100-199`)
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub(crate) struct VlanCollection(PortCollection);
+
+impl VlanCollection {
+    pub const PLACEHOLDER_STR: &'static str = "0-4095";
+    /// VLAN IDs are 12 bits long
+    const MAX_ID: u16 = 4095;
+
+    pub(crate) fn new(str: &str) -> Option<Self> {
+        let collection = PortCollection::new(str)?;
+        if collection == PortCollection::default() {
+            return Some(Self::default());
+        }
+        let is_valid = collection.ports.iter().all(|id| *id <= Self::MAX_ID)
+            && collection
+                .ranges
+                .iter()
+                .all(|range| *range.end() <= Self::MAX_ID);
+        is_valid.then_some(Self(collection))
+    }
+
+    pub(crate) fn contains(&self, id: u16) -> bool {
+        self.0.contains(Some(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::vlan_collection::VlanCollection;
+
+    #[test]
+    fn test_new_vlan_collections() {
+        assert_eq!(VlanCollection::new("").unwrap(), VlanCollection::default());
+        assert_eq!(VlanCollection::new(" ").unwrap(), VlanCollection::default());
+        assert!(VlanCollection::new("0, 10, 100-199, 4095").is_some());
+        assert!(VlanCollection::new("0-4095").is_some());
+
+        assert_eq!(VlanCollection::new("4096"), None);
+        assert_eq!(VlanCollection::new("100-5000"), None);
+        assert_eq!(VlanCollection::new("0-65535"), None);
+        assert_eq!(VlanCollection::new("199-100"), None);
+    }
+
+    #[test]
+    fn test_vlan_collection_contains() {
+        let collection = VlanCollection::new("10, 100-199").unwrap();
+        assert!(collection.contains(10));
+        assert!(collection.contains(100));
+        assert!(collection.contains(150));
+        assert!(collection.contains(199));
+        assert!(!collection.contains(0));
+        assert!(!collection.contains(11));
+        assert!(!collection.contains(200));
+
+        let default = VlanCollection::default();
+        assert!(default.contains(0));
+        assert!(default.contains(4095));
+    }
+}
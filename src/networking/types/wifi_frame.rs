@@ -0,0 +1,327 @@
+//! Module defining the `WifiFrame` struct, representing the IEEE 802.11 data frames captured
+//! on wireless adapters in monitor mode (optionally preceded by a radiotap header).
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::manage_packets::mac_from_dec_to_hex;
+use crate::networking::types::my_link_type::MyLinkType;
+use crate::translations::translations_4::{
+    channel_translation, signal_translation, station_translation,
+};
+use crate::translations::types::language::Language;
+
+/// Wireless details of a connection observed in monitor mode
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WifiInfo {
+    /// MAC address of the station transmitting the frames
+    pub station: String,
+    /// Signal strength of the last frame, in dBm
+    pub signal_dbm: Option<i8>,
+    /// Channel the last frame was received on
+    pub channel: Option<u16>,
+}
+
+impl WifiInfo {
+    /// Lines describing the station, signal, and channel
+    pub fn to_lines(&self, language: Language) -> Vec<String> {
+        let mut lines = vec![format!(
+            "{}: {}",
+            station_translation(language),
+            self.station
+        )];
+        if let Some(signal_dbm) = self.signal_dbm {
+            lines.push(format!(
+                "{}: {signal_dbm} dBm",
+                signal_translation(language)
+            ));
+        }
+        if let Some(channel) = self.channel {
+            lines.push(format!("{}: {channel}", channel_translation(language)));
+        }
+        lines
+    }
+}
+
+/// Data frame carrying an `EtherType` payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WifiFrame<'a> {
+    /// Source and destination MAC addresses
+    pub mac_addresses: ([u8; 6], [u8; 6]),
+    /// Wireless details of the frame
+    pub wifi_info: WifiInfo,
+    /// Protocol of the payload
+    pub ether_type: u16,
+    pub payload: &'a [u8],
+}
+
+impl<'a> WifiFrame<'a> {
+    const MAC_HEADER_LEN: usize = 24;
+    const LLC_SNAP_HEADER: [u8; 3] = [0xaa, 0xaa, 0x03];
+
+    /// Parses the given packet, returning `None` if the link type isn't IEEE 802.11
+    /// or if the packet isn't an unencrypted data frame
+    pub fn from_packet(packet: &'a [u8], my_link_type: MyLinkType) -> Option<Self> {
+        match my_link_type {
+            MyLinkType::Ieee80211(_) => Self::from_mac_frame(packet, RadiotapFields::default()),
+            MyLinkType::Radiotap(_) => {
+                let (radiotap_fields, len) = RadiotapFields::parse(packet)?;
+                let mut frame = packet.get(len..)?;
+                if radiotap_fields.has_fcs {
+                    frame = frame.get(..frame.len().checked_sub(4)?)?;
+                }
+                Self::from_mac_frame(frame, radiotap_fields)
+            }
+            _ => None,
+        }
+    }
+
+    /// Reads an 802.11 MAC frame: <https://www.tcpdump.org/linktypes/LINKTYPE_IEEE802_11.html>
+    fn from_mac_frame(frame: &'a [u8], radiotap_fields: RadiotapFields) -> Option<Self> {
+        let (frame_control, flags) = (*frame.first()?, *frame.get(1)?);
+        let frame_type = (frame_control >> 2) & 0b11;
+        let subtype = frame_control >> 4;
+        // only data frames with payload (i.e., no management, control, or null frames)
+        if frame_type != 2 || subtype & 0b0100 != 0 {
+            return None;
+        }
+        // encrypted payloads can't be analysed
+        if flags & 0x40 != 0 {
+            return None;
+        }
+        let to_ds = flags & 0x01 != 0;
+        let from_ds = flags & 0x02 != 0;
+        let is_qos = subtype & 0b1000 != 0;
+
+        let address =
+            |offset: usize| -> Option<[u8; 6]> { frame.get(offset..offset + 6)?.try_into().ok() };
+        let (address1, address2, address3) = (address(4)?, address(10)?, address(16)?);
+        let mut header_len = Self::MAC_HEADER_LEN;
+        let mac_addresses = match (to_ds, from_ds) {
+            (false, false) => (address2, address1),
+            (true, false) => (address2, address3),
+            (false, true) => (address3, address1),
+            (true, true) => {
+                header_len += 6;
+                (address(24)?, address3)
+            }
+        };
+        if is_qos {
+            // aggregated MSDUs aren't supported
+            if frame.get(header_len)? & 0x80 != 0 {
+                return None;
+            }
+            header_len += 2;
+            // HT control field
+            if flags & 0x80 != 0 {
+                header_len += 4;
+            }
+        }
+
+        let llc = frame.get(header_len..header_len + 8)?;
+        if llc[..3] != Self::LLC_SNAP_HEADER {
+            return None;
+        }
+        Some(Self {
+            mac_addresses,
+            wifi_info: WifiInfo {
+                station: mac_from_dec_to_hex(address2),
+                signal_dbm: radiotap_fields.signal_dbm,
+                channel: radiotap_fields.frequency.and_then(channel_from_frequency),
+            },
+            ether_type: u16::from_be_bytes([llc[6], llc[7]]),
+            payload: &frame[header_len + 8..],
+        })
+    }
+}
+
+/// Radiotap fields relevant for the analysis: <https://www.radiotap.org>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct RadiotapFields {
+    /// Whether the frame includes the frame check sequence at its end
+    has_fcs: bool,
+    /// Frequency in MHz
+    frequency: Option<u16>,
+    signal_dbm: Option<i8>,
+}
+
+impl RadiotapFields {
+    const TSFT: u32 = 1 << 0;
+    const FLAGS: u32 = 1 << 1;
+    const RATE: u32 = 1 << 2;
+    const CHANNEL: u32 = 1 << 3;
+    const FHSS: u32 = 1 << 4;
+    const DBM_ANTENNA_SIGNAL: u32 = 1 << 5;
+    const EXT: u32 = 1 << 31;
+    const FLAG_FCS: u8 = 0x10;
+
+    /// Returns the fields of the radiotap header and the length of the header itself
+    fn parse(packet: &[u8]) -> Option<(Self, usize)> {
+        let h = packet.get(..8)?;
+        if h[0] != 0 {
+            return None;
+        }
+        let len = usize::from(u16::from_le_bytes([h[2], h[3]]));
+        let header = packet.get(..len)?;
+        let word = |offset: usize| -> Option<u32> {
+            Some(u32::from_le_bytes(
+                header.get(offset..offset + 4)?.try_into().ok()?,
+            ))
+        };
+
+        // the fields follow all the present bitmaps, but only the ones of the first bitmap are needed
+        let present = word(4)?;
+        let mut offset = 8;
+        let mut bitmap = present;
+        while bitmap & Self::EXT != 0 {
+            bitmap = word(offset)?;
+            offset += 4;
+        }
+
+        let mut fields = Self::default();
+        // fields are aligned to their natural size, starting from the beginning of the header
+        let mut field = |bit: u32, align: usize, size: usize| -> Option<&[u8]> {
+            if present & bit == 0 {
+                return None;
+            }
+            offset = offset.next_multiple_of(align);
+            let value = header.get(offset..offset + size);
+            offset += size;
+            value
+        };
+        field(Self::TSFT, 8, 8);
+        if let Some(flags) = field(Self::FLAGS, 1, 1) {
+            fields.has_fcs = flags[0] & Self::FLAG_FCS != 0;
+        }
+        field(Self::RATE, 1, 1);
+        if let Some(channel) = field(Self::CHANNEL, 2, 4) {
+            fields.frequency = Some(u16::from_le_bytes([channel[0], channel[1]]));
+        }
+        field(Self::FHSS, 1, 2);
+        if let Some(signal) = field(Self::DBM_ANTENNA_SIGNAL, 1, 1) {
+            fields.signal_dbm = Some(i8::from_le_bytes([signal[0]]));
+        }
+        Some((fields, len))
+    }
+}
+
+/// Returns the Wi-Fi channel number corresponding to the given frequency in MHz
+fn channel_from_frequency(frequency: u16) -> Option<u16> {
+    match frequency {
+        2484 => Some(14),
+        2412..=2472 => Some((frequency - 2407) / 5),
+        5160..=5885 => Some((frequency - 5000) / 5),
+        5955..=7115 => Some((frequency - 5950) / 5),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pcap::Linktype;
+
+    use super::*;
+
+    const STATION: [u8; 6] = [0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f];
+    const ACCESS_POINT: [u8; 6] = [0xa0, 0xb1, 0xc2, 0xd3, 0xe4, 0xf5];
+    const ROUTER: [u8; 6] = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+    const PAYLOAD: [u8; 4] = [0x45, 0x00, 0x00, 0x14];
+
+    /// QoS data frame sent by a station to the access point, carrying an IPv4 payload
+    fn data_frame(flags: u8) -> Vec<u8> {
+        let mut frame = vec![0x88, flags, 0, 0];
+        frame.extend(ACCESS_POINT);
+        frame.extend(STATION);
+        frame.extend(ROUTER);
+        frame.extend([0, 0]); // sequence control
+        frame.extend([0, 0]); // QoS control
+        frame.extend([0xaa, 0xaa, 0x03, 0, 0, 0, 0x08, 0x00]);
+        frame.extend(PAYLOAD);
+        frame
+    }
+
+    /// Radiotap header with TSFT, flags, rate, channel, and antenna signal
+    fn radiotap(flags: u8) -> Vec<u8> {
+        let mut header = vec![0, 0, 0, 0];
+        header.extend(0b10_1111_u32.to_le_bytes());
+        header.extend(1_234_u64.to_le_bytes());
+        header.extend([flags, 0x0c]);
+        header.extend(5_180_u16.to_le_bytes());
+        header.extend(0x0140_u16.to_le_bytes());
+        header.push(0xc4_u8); // -60 dBm
+        let len = u16::try_from(header.len()).unwrap();
+        header[2..4].copy_from_slice(&len.to_le_bytes());
+        header
+    }
+
+    #[test]
+    fn test_data_frame_to_access_point() {
+        let link_type = MyLinkType::from_pcap_link_type(Linktype::IEEE802_11);
+        let frame = data_frame(0x01);
+        let wifi_frame = WifiFrame::from_packet(&frame, link_type).unwrap();
+        assert_eq!(wifi_frame.mac_addresses, (STATION, ROUTER));
+        assert_eq!(wifi_frame.ether_type, 0x0800);
+        assert_eq!(wifi_frame.payload, PAYLOAD);
+        assert_eq!(
+            wifi_frame.wifi_info,
+            WifiInfo {
+                station: "00:1b:2c:3d:4e:5f".to_string(),
+                signal_dbm: None,
+                channel: None,
+            }
+        );
+
+        // protected frame
+        assert!(WifiFrame::from_packet(&data_frame(0x41), link_type).is_none());
+        // beacon
+        let mut beacon = data_frame(0x00);
+        beacon[0] = 0x80;
+        assert!(WifiFrame::from_packet(&beacon, link_type).is_none());
+        // truncated
+        assert!(WifiFrame::from_packet(&frame[..30], link_type).is_none());
+    }
+
+    #[test]
+    fn test_radiotap_data_frame() {
+        let link_type = MyLinkType::from_pcap_link_type(Linktype::IEEE802_11_RADIOTAP);
+        let mut packet = radiotap(0x00);
+        packet.extend(data_frame(0x02));
+        let wifi_frame = WifiFrame::from_packet(&packet, link_type).unwrap();
+        // frame coming from the distribution system: the source is the third address
+        assert_eq!(wifi_frame.mac_addresses, (ROUTER, ACCESS_POINT));
+        assert_eq!(wifi_frame.wifi_info.station, "00:1b:2c:3d:4e:5f");
+        assert_eq!(wifi_frame.wifi_info.signal_dbm, Some(-60));
+        assert_eq!(wifi_frame.wifi_info.channel, Some(36));
+        assert_eq!(
+            wifi_frame.wifi_info.to_lines(Language::EN),
+            vec![
+                "Station: 00:1b:2c:3d:4e:5f",
+                "Signal: -60 dBm",
+                "Channel: 36"
+            ]
+        );
+        assert_eq!(wifi_frame.payload, PAYLOAD);
+
+        // frame check sequence at the end
+        let mut packet = radiotap(0x10);
+        packet.extend(data_frame(0x02));
+        packet.extend([1, 2, 3, 4]);
+        let wifi_frame = WifiFrame::from_packet(&packet, link_type).unwrap();
+        assert_eq!(wifi_frame.payload, PAYLOAD);
+
+        // unknown radiotap version
+        let mut packet = radiotap(0x00);
+        packet[0] = 1;
+        packet.extend(data_frame(0x02));
+        assert!(WifiFrame::from_packet(&packet, link_type).is_none());
+    }
+
+    #[test]
+    fn test_channel_from_frequency() {
+        assert_eq!(channel_from_frequency(2412), Some(1));
+        assert_eq!(channel_from_frequency(2437), Some(6));
+        assert_eq!(channel_from_frequency(2484), Some(14));
+        assert_eq!(channel_from_frequency(5180), Some(36));
+        assert_eq!(channel_from_frequency(5955), Some(1));
+        assert_eq!(channel_from_frequency(900), None);
+    }
+}
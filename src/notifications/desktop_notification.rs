@@ -0,0 +1,201 @@
+//! Module to show notifications on the desktop, through the `org.freedesktop.Notifications` D-Bus interface.
+
+#[cfg(target_os = "linux")]
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::thread::{self, JoinHandle};
+
+#[cfg(target_os = "linux")]
+use zbus::blocking::Connection;
+#[cfg(target_os = "linux")]
+use zbus::zvariant::Value;
+
+#[cfg(target_os = "linux")]
+use crate::SNIFFNET_TITLECASE;
+
+#[cfg(target_os = "linux")]
+const NOTIFICATIONS_NAME: &str = "org.freedesktop.Notifications";
+#[cfg(target_os = "linux")]
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+
+/// Shows a notification on the desktop, using the session bus.
+///
+/// It's a no-op on platforms different from Linux.
+#[cfg(target_os = "linux")]
+pub fn send_desktop_notification(summary: String, body: String) {
+    let _ = notify_in_background(Connection::session, summary, body);
+}
+
+/// Shows a notification on the desktop, using the session bus.
+///
+/// It's a no-op on platforms different from Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn send_desktop_notification(_summary: String, _body: String) {}
+
+/// Spawns a thread calling the `Notify` method on the connection returned by `connect`.
+#[cfg(target_os = "linux")]
+fn notify_in_background(
+    connect: impl FnOnce() -> zbus::Result<Connection> + Send + 'static,
+    summary: String,
+    body: String,
+) -> std::io::Result<JoinHandle<()>> {
+    thread::Builder::new()
+        .name("thread_desktop_notification".to_string())
+        .spawn(move || {
+            // errors are ignored: the notification daemon may simply not be running
+            if let Ok(connection) = connect() {
+                let _ = notify(&connection, &summary, &body);
+            }
+        })
+}
+
+/// Calls the `Notify` method of the notification server, returning the ID of the notification.
+#[cfg(target_os = "linux")]
+fn notify(connection: &Connection, summary: &str, body: &str) -> zbus::Result<u32> {
+    let reply = connection.call_method(
+        Some(NOTIFICATIONS_NAME),
+        NOTIFICATIONS_PATH,
+        Some(NOTIFICATIONS_NAME),
+        "Notify",
+        &(
+            SNIFFNET_TITLECASE,
+            0_u32,
+            "",
+            summary,
+            body,
+            Vec::<&str>::new(),
+            HashMap::<&str, Value>::new(),
+            -1_i32,
+        ),
+    )?;
+    reply.body().deserialize::<u32>()
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::os::unix::net::UnixStream;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use zbus::Guid;
+    use zbus::blocking::Connection;
+    use zbus::blocking::connection::Builder;
+    use zbus::zvariant::OwnedValue;
+
+    use crate::SNIFFNET_TITLECASE;
+    use crate::notifications::desktop_notification::{
+        NOTIFICATIONS_PATH, notify, notify_in_background,
+    };
+
+    /// Minimal notification server, recording the received notifications
+    struct FakeNotificationServer {
+        received: Arc<Mutex<Vec<(String, String, String)>>>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.Notifications")]
+    impl FakeNotificationServer {
+        #[allow(clippy::too_many_arguments)]
+        fn notify(
+            &self,
+            app_name: String,
+            _replaces_id: u32,
+            _app_icon: String,
+            summary: String,
+            body: String,
+            _actions: Vec<String>,
+            _hints: HashMap<String, OwnedValue>,
+            _expire_timeout: i32,
+        ) -> u32 {
+            let mut received = self.received.lock().unwrap();
+            received.push((app_name, summary, body));
+            u32::try_from(received.len()).unwrap()
+        }
+    }
+
+    /// Connects to a new fake notification server, returning the client side of the connection,
+    /// the server side (to be kept alive), and the notifications received by the server
+    #[allow(clippy::type_complexity)]
+    fn connect_to_fake_server() -> (
+        Connection,
+        Connection,
+        Arc<Mutex<Vec<(String, String, String)>>>,
+    ) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let server_received = received.clone();
+        let (server_stream, client_stream) = UnixStream::pair().unwrap();
+
+        let server = thread::spawn(move || {
+            Builder::unix_stream(server_stream)
+                .server(Guid::generate())
+                .unwrap()
+                .p2p()
+                .serve_at(
+                    NOTIFICATIONS_PATH,
+                    FakeNotificationServer {
+                        received: server_received,
+                    },
+                )
+                .unwrap()
+                .build()
+                .unwrap()
+        });
+        let client: Connection = Builder::unix_stream(client_stream).p2p().build().unwrap();
+        (client, server.join().unwrap(), received)
+    }
+
+    #[test]
+    fn test_notify_reaches_notification_server() {
+        let (client, _server, received) = connect_to_fake_server();
+
+        assert_eq!(notify(&client, "New host seen", "example.com").unwrap(), 1);
+        assert_eq!(notify(&client, "Favorite host", "").unwrap(), 2);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                (
+                    SNIFFNET_TITLECASE.to_string(),
+                    "New host seen".to_string(),
+                    "example.com".to_string()
+                ),
+                (
+                    SNIFFNET_TITLECASE.to_string(),
+                    "Favorite host".to_string(),
+                    String::new()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_notify_in_background() {
+        let (client, _server, received) = connect_to_fake_server();
+
+        notify_in_background(
+            move || Ok(client),
+            "Scan detected".to_string(),
+            String::new(),
+        )
+        .unwrap()
+        .join()
+        .unwrap();
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![(
+                SNIFFNET_TITLECASE.to_string(),
+                "Scan detected".to_string(),
+                String::new()
+            )]
+        );
+
+        // a missing notification server isn't a problem
+        notify_in_background(
+            || Err(zbus::Error::Failure("no session bus".to_string())),
+            "Scan detected".to_string(),
+            String::new(),
+        )
+        .unwrap()
+        .join()
+        .unwrap();
+    }
+}
@@ -1,2 +1,3 @@
+pub mod desktop_notification;
 pub mod notify_and_log;
 pub mod types;
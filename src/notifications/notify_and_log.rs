@@ -1,61 +1,112 @@
 use crate::InfoTraffic;
 use crate::chart::types::chart_type::ChartType;
+use crate::countries::types::country::Country;
+use crate::networking::types::asn::Asn;
+use crate::networking::types::blocklist::Blocklists;
 use crate::networking::types::capture_context::CaptureSource;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
-use crate::networking::types::host::Host;
+use crate::networking::types::host::{Host, HostMessage};
 use crate::networking::types::service::Service;
+use crate::notifications::desktop_notification::send_desktop_notification;
 use crate::notifications::types::logged_notification::{
-    DataThresholdExceeded, FavoriteTransmitted, LoggedNotification,
+    AnomalyDetected, BlocklistMatch, DataThresholdExceeded, FavoriteTransmitted, FirstSeen,
+    LoggedNotification, ScanDetected,
 };
-use crate::notifications::types::notifications::Notifications;
+use crate::notifications::types::notification_action::run_actions;
+use crate::notifications::types::notifications::{
+    BlocklistNotification, FirstSeenNotification, HostIdentity, Notifications, NotificationsState,
+    ThresholdScope, ThresholdState,
+};
+use crate::notifications::types::scan_detector::ScanDetector;
 use crate::notifications::types::sound::{Sound, play};
 use crate::report::types::sort_type::SortType;
+use crate::translations::types::language::Language;
 use crate::utils::formatted_strings::get_formatted_timestamp;
 use std::cmp::min;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Checks if one or more notifications have to be emitted and logs them.
 ///
-/// It returns the number of new notifications emitted
+/// It returns the new notifications emitted, from the oldest to the most recent
+#[allow(clippy::too_many_arguments)]
 pub fn notify_and_log(
     logged_notifications: &mut (VecDeque<LoggedNotification>, usize),
-    notifications: Notifications,
+    notifications: &Notifications,
+    notifications_state: &mut NotificationsState,
     info_traffic_msg: &InfoTraffic,
+    first_seen: Vec<(FirstSeenKind, HostMessage)>,
+    blocklisted: Vec<(HostMessage, String)>,
     favorites: &HashSet<Host>,
     cs: &CaptureSource,
-) -> usize {
+    language: Language,
+) -> Vec<LoggedNotification> {
     let mut sound_to_play = Sound::None;
-    let emitted_notifications_prev = logged_notifications.1;
+    let mut emitted = Vec::new();
     let timestamp = info_traffic_msg.last_packet_timestamp;
     let data_info = info_traffic_msg.tot_data_info;
     // data threshold
     if let Some(threshold) = notifications.data_notification.threshold {
         let chart_type = notifications.data_notification.chart_type;
-        if data_info.tot_data(chart_type) > u128::from(threshold) {
-            //log this notification
-            logged_notifications.1 += 1;
-            if logged_notifications.0.len() >= 30 {
-                logged_notifications.0.pop_back();
-            }
-            logged_notifications
-                .0
-                .push_front(LoggedNotification::DataThresholdExceeded(
-                    DataThresholdExceeded {
-                        id: logged_notifications.1,
-                        chart_type,
-                        threshold: notifications.data_notification.previous_threshold,
-                        data_info,
-                        timestamp: get_formatted_timestamp(timestamp),
-                        is_expanded: false,
-                        hosts: hosts_list(info_traffic_msg, chart_type),
-                        services: services_list(info_traffic_msg, chart_type),
-                    },
-                ));
+        if notifications_state.thresholds.global.update(
+            threshold,
+            notifications.data_notification.alert_conditions,
+            data_info.tot_data(chart_type),
+        ) {
+            log_notification(logged_notifications, &mut emitted, |id| {
+                LoggedNotification::DataThresholdExceeded(DataThresholdExceeded {
+                    id,
+                    chart_type,
+                    threshold: notifications.data_notification.previous_threshold,
+                    data_info,
+                    timestamp: get_formatted_timestamp(timestamp),
+                    is_expanded: false,
+                    hosts: hosts_list(info_traffic_msg, chart_type, None),
+                    services: services_list(info_traffic_msg, chart_type, None),
+                    scope: None,
+                })
+            });
             if sound_to_play.eq(&Sound::None) {
                 sound_to_play = notifications.data_notification.sound;
             }
         }
+    } else {
+        notifications_state.thresholds.global = ThresholdState::default();
+    }
+    // scoped data thresholds
+    for (rule, state) in notifications_state
+        .thresholds
+        .scoped(&notifications.scoped_data_notifications)
+    {
+        let Some(threshold) = rule.data_notification.threshold else {
+            *state = ThresholdState::default();
+            continue;
+        };
+        let chart_type = rule.data_notification.chart_type;
+        let scope = &rule.scope;
+        let data_info = scoped_data_info(info_traffic_msg, scope);
+        if state.update(
+            threshold,
+            rule.data_notification.alert_conditions,
+            data_info.tot_data(chart_type),
+        ) {
+            log_notification(logged_notifications, &mut emitted, |id| {
+                LoggedNotification::DataThresholdExceeded(DataThresholdExceeded {
+                    id,
+                    chart_type,
+                    threshold,
+                    data_info,
+                    timestamp: get_formatted_timestamp(timestamp),
+                    is_expanded: false,
+                    hosts: hosts_list(info_traffic_msg, chart_type, Some(scope)),
+                    services: services_list(info_traffic_msg, chart_type, Some(scope)),
+                    scope: Some(scope.clone()),
+                })
+            });
+            if sound_to_play.eq(&Sound::None) {
+                sound_to_play = rule.data_notification.sound;
+            }
+        }
     }
     // from favorites
     if notifications.favorite_notification.notify_on_favorite {
@@ -67,41 +118,282 @@ pub fn notify_and_log(
             .collect();
         if !favorites_last_interval.is_empty() {
             for (host, data_info_host) in favorites_last_interval {
-                //log this notification
-                logged_notifications.1 += 1;
-                if logged_notifications.0.len() >= 30 {
-                    logged_notifications.0.pop_back();
+                log_notification(logged_notifications, &mut emitted, |id| {
+                    LoggedNotification::FavoriteTransmitted(FavoriteTransmitted {
+                        id,
+                        host,
+                        data_info_host,
+                        timestamp: get_formatted_timestamp(timestamp),
+                    })
+                });
+            }
+            if sound_to_play.eq(&Sound::None) {
+                sound_to_play = notifications.favorite_notification.sound;
+            }
+        }
+    }
+
+    // first seen hosts, ASNs, and countries
+    if !first_seen.is_empty() {
+        for (kind, host_msg) in first_seen {
+            log_notification(logged_notifications, &mut emitted, |id| {
+                let first_seen = FirstSeen {
+                    id,
+                    host: host_msg.host,
+                    address: host_msg.address_to_lookup,
+                    data_info_host: host_msg.data_info_host,
+                    timestamp: get_formatted_timestamp(timestamp),
+                };
+                match kind {
+                    FirstSeenKind::Host => LoggedNotification::NewHost(first_seen),
+                    FirstSeenKind::Asn => LoggedNotification::NewAsn(first_seen),
+                    FirstSeenKind::Country => LoggedNotification::NewCountry(first_seen),
                 }
+            });
+        }
+        if sound_to_play.eq(&Sound::None) {
+            sound_to_play = notifications.first_seen_notification.sound;
+        }
+    }
+
+    // hosts matching the blocklists
+    if !blocklisted.is_empty() {
+        for (host_msg, blocklist) in blocklisted {
+            log_notification(logged_notifications, &mut emitted, |id| {
+                LoggedNotification::Blocklisted(BlocklistMatch {
+                    id,
+                    host: host_msg.host,
+                    address: host_msg.address_to_lookup,
+                    data_info_host: host_msg.data_info_host,
+                    blocklist,
+                    timestamp: get_formatted_timestamp(timestamp),
+                })
+            });
+        }
+        if sound_to_play.eq(&Sound::None) {
+            sound_to_play = notifications.blocklist_notification.sound;
+        }
+    }
 
-                logged_notifications
-                    .0
-                    .push_front(LoggedNotification::FavoriteTransmitted(
-                        FavoriteTransmitted {
-                            id: logged_notifications.1,
-                            host,
-                            data_info_host,
-                            timestamp: get_formatted_timestamp(timestamp),
-                        },
-                    ));
+    // traffic anomalies (the baseline is always updated, so that it's ready once enabled)
+    let anomaly_notification = notifications.anomaly_notification;
+    let anomalies = notifications_state.traffic_baseline.update(
+        info_traffic_msg,
+        anomaly_notification.sensitivity.min_z_score(),
+    );
+    if anomaly_notification.notify_on_anomaly && !anomalies.is_empty() {
+        for anomaly in anomalies {
+            log_notification(logged_notifications, &mut emitted, |id| {
+                LoggedNotification::AnomalyDetected(AnomalyDetected {
+                    id,
+                    service: anomaly.service,
+                    data_info: anomaly.data_info,
+                    baseline_bytes: anomaly.baseline_bytes,
+                    z_score: anomaly.z_score,
+                    timestamp: get_formatted_timestamp(timestamp),
+                })
+            });
+        }
+        if sound_to_play.eq(&Sound::None) {
+            sound_to_play = anomaly_notification.sound;
+        }
+    }
+
+    // remote hosts contacting many ports or local hosts
+    let scan_notification = notifications.scan_notification;
+    if scan_notification.notify_on_scan {
+        let scans = notifications_state.scan_detector.update(
+            info_traffic_msg,
+            scan_notification.ports_threshold,
+            scan_notification.hosts_threshold,
+        );
+        if !scans.is_empty() {
+            for scan in scans {
+                log_notification(logged_notifications, &mut emitted, |id| {
+                    LoggedNotification::ScanDetected(ScanDetected {
+                        id,
+                        address: scan.address,
+                        ports: scan.ports,
+                        hosts: scan.hosts,
+                        timestamp: get_formatted_timestamp(timestamp),
+                    })
+                });
             }
             if sound_to_play.eq(&Sound::None) {
-                sound_to_play = notifications.favorite_notification.sound;
+                sound_to_play = scan_notification.sound;
             }
         }
+    } else {
+        notifications_state.scan_detector = ScanDetector::default();
     }
 
-    // don't play sound when importing data from pcap file
+    // don't play sound, show desktop notifications, or run actions when importing data from pcap file
     if matches!(cs, CaptureSource::Device(_)) {
         play(sound_to_play, notifications.volume);
+        if notifications.desktop_notifications
+            && let Some((summary, body)) =
+                desktop_notification_text(&logged_notifications.0, emitted.len(), language)
+        {
+            send_desktop_notification(summary, body);
+        }
+        run_actions(
+            notifications
+                .actions
+                .for_notifications(emitted.iter().rev()),
+        );
+    }
+
+    emitted
+}
+
+/// Logs a new notification, built given its ID, keeping only the 30 most recent ones
+fn log_notification(
+    logged_notifications: &mut (VecDeque<LoggedNotification>, usize),
+    emitted: &mut Vec<LoggedNotification>,
+    notification: impl FnOnce(usize) -> LoggedNotification,
+) {
+    logged_notifications.1 += 1;
+    if logged_notifications.0.len() >= 30 {
+        logged_notifications.0.pop_back();
     }
+    let notification = notification(logged_notifications.1);
+    emitted.push(notification.clone());
+    logged_notifications.0.push_front(notification);
+}
+
+/// Returns summary and body of a single desktop notification reporting the latest `emitted` notifications.
+///
+/// At most 5 notifications are detailed in the body.
+fn desktop_notification_text(
+    logged_notifications: &VecDeque<LoggedNotification>,
+    emitted: usize,
+    language: Language,
+) -> Option<(String, String)> {
+    let latest = logged_notifications.front()?;
+    if emitted == 0 {
+        return None;
+    }
+    if emitted == 1 {
+        return Some((
+            latest.title(language).to_string(),
+            latest.description(language),
+        ));
+    }
+    let summary = format!("{} (+{})", latest.title(language), emitted - 1);
+    let body = logged_notifications
+        .iter()
+        .take(min(emitted, 5))
+        .map(|n| {
+            format!(
+                "{}: {}",
+                n.title(language),
+                n.description(language).replace('\n', ", ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    Some((summary, body))
+}
+
+/// Kind of entity seen for the first time in a capture
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FirstSeenKind {
+    Host,
+    Asn,
+    Country,
+}
+
+/// Returns the hosts, ASNs, and countries seen for the first time among the newly resolved addresses,
+/// according to the enabled notifications.
+///
+/// `known_hosts` are the hosts observed before the new addresses were resolved.
+pub fn get_first_seen(
+    new_hosts: &[HostMessage],
+    known_hosts: &HashMap<Host, DataInfoHost>,
+    first_seen_notification: FirstSeenNotification,
+) -> Vec<(FirstSeenKind, HostMessage)> {
+    let mut first_seen = Vec::new();
+    if !first_seen_notification.is_active() || new_hosts.is_empty() {
+        return first_seen;
+    }
+
+    let mut hosts: HashSet<&Host> = known_hosts.keys().collect();
+    let mut asns: HashSet<&Asn> = hosts.iter().map(|h| &h.asn).collect();
+    let mut countries: HashSet<Country> = hosts.iter().map(|h| h.country).collect();
+
+    for host_msg in new_hosts {
+        let host = &host_msg.host;
+        // every resolved address is a new one
+        let is_new_host =
+            hosts.insert(host) || first_seen_notification.host_identity == HostIdentity::Address;
+        if first_seen_notification.notify_on_new_host && is_new_host {
+            first_seen.push((FirstSeenKind::Host, host_msg.clone()));
+        }
+        if asns.insert(&host.asn)
+            && first_seen_notification.notify_on_new_asn
+            && !host.asn.code.is_empty()
+        {
+            first_seen.push((FirstSeenKind::Asn, host_msg.clone()));
+        }
+        if countries.insert(host.country)
+            && first_seen_notification.notify_on_new_country
+            && host.country != Country::ZZ
+        {
+            first_seen.push((FirstSeenKind::Country, host_msg.clone()));
+        }
+    }
+    first_seen
+}
+
+/// Returns the newly resolved addresses matching the blocklists, together with the path of the matching list,
+/// if the related notification is enabled
+pub fn get_blocklisted(
+    new_hosts: &[HostMessage],
+    blocklists: &Blocklists,
+    blocklist_notification: BlocklistNotification,
+) -> Vec<(HostMessage, String)> {
+    if !blocklist_notification.notify_on_blocklisted {
+        return Vec::new();
+    }
+    new_hosts
+        .iter()
+        .filter_map(|host_msg| {
+            blocklists
+                .matching_list(&host_msg.address_to_lookup, &host_msg.rdns)
+                .map(|list| (host_msg.clone(), list.path.clone()))
+        })
+        .collect()
+}
 
-    logged_notifications.1 - emitted_notifications_prev
+/// Returns the data exchanged in the last interval by the traffic part of the given scope
+fn scoped_data_info(info_traffic_msg: &InfoTraffic, scope: &ThresholdScope) -> DataInfo {
+    let mut data_info = DataInfo::default();
+    for (_, data_info_host) in info_traffic_msg
+        .hosts
+        .iter()
+        .filter(|(h, _)| scope.includes_host(h))
+    {
+        data_info.refresh(data_info_host.data_info);
+    }
+    for (_, service_data_info) in info_traffic_msg
+        .services
+        .iter()
+        .filter(|(s, _)| scope.includes_service(s))
+    {
+        data_info.refresh(*service_data_info);
+    }
+    data_info
 }
 
-fn hosts_list(info_traffic_msg: &InfoTraffic, chart_type: ChartType) -> Vec<(Host, DataInfoHost)> {
+fn hosts_list(
+    info_traffic_msg: &InfoTraffic,
+    chart_type: ChartType,
+    scope: Option<&ThresholdScope>,
+) -> Vec<(Host, DataInfoHost)> {
     let mut hosts: Vec<(Host, DataInfoHost)> = info_traffic_msg
         .hosts
         .iter()
+        .filter(|(h, _)| scope.is_none_or(|scope| scope.includes_host(h)))
         .map(|(h, data)| (h.clone(), *data))
         .collect();
     hosts.sort_by(|(_, a), (_, b)| {
@@ -120,11 +412,13 @@ fn hosts_list(info_traffic_msg: &InfoTraffic, chart_type: ChartType) -> Vec<(Hos
 fn services_list(
     info_traffic_msg: &InfoTraffic,
     chart_type: ChartType,
+    scope: Option<&ThresholdScope>,
 ) -> Vec<(Service, DataInfo)> {
     let mut services: Vec<(Service, DataInfo)> = info_traffic_msg
         .services
         .iter()
         .filter(|(service, _)| service != &&Service::NotApplicable)
+        .filter(|(service, _)| scope.is_none_or(|scope| scope.includes_service(service)))
         .map(|(s, data)| (*s, *data))
         .collect();
     services.sort_by(|(_, a), (_, b)| a.compare(b, SortType::Descending, chart_type));
@@ -136,3 +430,560 @@ fn services_list(
         .into_iter()
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::*;
+    use crate::Protocol;
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::capture_context::MyPcapImport;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::networking::types::tcp_flags::TcpFlagsCount;
+    use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::notifications::types::notifications::{
+        AlertConditions, AnomalyNotification, DataNotification, ScanNotification,
+        ScopedDataNotification,
+    };
+    use crate::notifications::types::traffic_baseline::MIN_BASELINE_SAMPLES;
+
+    fn host_msg(address: [u8; 4], domain: &str, asn_code: &str, country: Country) -> HostMessage {
+        HostMessage {
+            host: Host {
+                domain: domain.to_string(),
+                asn: Asn {
+                    code: asn_code.to_string(),
+                    name: format!("AS{asn_code}"),
+                },
+                country,
+            },
+            data_info_host: DataInfoHost::default(),
+            address_to_lookup: IpAddr::from(address),
+            rdns: domain.to_string(),
+        }
+    }
+
+    fn kinds(first_seen: &[(FirstSeenKind, HostMessage)]) -> Vec<(FirstSeenKind, IpAddr)> {
+        first_seen
+            .iter()
+            .map(|(kind, host_msg)| (*kind, host_msg.address_to_lookup))
+            .collect()
+    }
+
+    #[test]
+    fn test_first_seen_disabled() {
+        let new_hosts = [host_msg([1, 1, 1, 1], "one.one", "13335", Country::US)];
+        let first_seen = get_first_seen(
+            &new_hosts,
+            &HashMap::new(),
+            FirstSeenNotification::default(),
+        );
+        assert!(first_seen.is_empty());
+    }
+
+    #[test]
+    fn test_first_seen_hosts_asns_and_countries() {
+        let known = host_msg([8, 8, 8, 8], "dns.google", "15169", Country::US);
+        let known_hosts = HashMap::from([(known.host, DataInfoHost::default())]);
+        let new_hosts = [
+            // same host as a known one, from another address
+            host_msg([8, 8, 4, 4], "dns.google", "15169", Country::US),
+            // new host, known ASN and country
+            host_msg([8, 8, 8, 9], "other.google", "15169", Country::US),
+            // new host, ASN, and country
+            host_msg([1, 1, 1, 1], "one.one", "13335", Country::AU),
+            // new host in the same batch, unknown ASN and country
+            host_msg([10, 0, 0, 1], "10.0.0.1", "", Country::ZZ),
+        ];
+        let notification = FirstSeenNotification {
+            notify_on_new_host: true,
+            notify_on_new_asn: true,
+            notify_on_new_country: true,
+            ..FirstSeenNotification::default()
+        };
+
+        let first_seen = get_first_seen(&new_hosts, &known_hosts, notification);
+        assert_eq!(
+            kinds(&first_seen),
+            vec![
+                (FirstSeenKind::Host, IpAddr::from([8, 8, 8, 9])),
+                (FirstSeenKind::Host, IpAddr::from([1, 1, 1, 1])),
+                (FirstSeenKind::Asn, IpAddr::from([1, 1, 1, 1])),
+                (FirstSeenKind::Country, IpAddr::from([1, 1, 1, 1])),
+                (FirstSeenKind::Host, IpAddr::from([10, 0, 0, 1])),
+            ]
+        );
+
+        let by_address = FirstSeenNotification {
+            notify_on_new_host: true,
+            host_identity: HostIdentity::Address,
+            ..FirstSeenNotification::default()
+        };
+        let first_seen = get_first_seen(&new_hosts, &known_hosts, by_address);
+        assert_eq!(first_seen.len(), new_hosts.len());
+        assert!(
+            first_seen
+                .iter()
+                .all(|(kind, _)| *kind == FirstSeenKind::Host)
+        );
+    }
+
+    #[test]
+    fn test_blocklisted_hosts() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_blocklist_test_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "1.1.1.0/24\ntracker.example\n").unwrap();
+        let blocklists = Blocklists::load(&[path.to_string_lossy().to_string()]);
+        std::fs::remove_file(&path).unwrap();
+
+        let new_hosts = [
+            host_msg([1, 1, 1, 1], "one.one", "13335", Country::AU),
+            host_msg([8, 8, 8, 8], "dns.google", "15169", Country::US),
+            host_msg([9, 9, 9, 9], "cdn.tracker.example", "19281", Country::CH),
+        ];
+        assert!(
+            get_blocklisted(&new_hosts, &blocklists, BlocklistNotification::default()).is_empty()
+        );
+        let notification = BlocklistNotification {
+            notify_on_blocklisted: true,
+            ..BlocklistNotification::default()
+        };
+        let blocklist = path.to_string_lossy().to_string();
+        assert_eq!(
+            get_blocklisted(&new_hosts, &blocklists, notification)
+                .into_iter()
+                .map(|(host_msg, list)| (host_msg.address_to_lookup, list))
+                .collect::<Vec<_>>(),
+            vec![
+                (IpAddr::from([1, 1, 1, 1]), blocklist.clone()),
+                (IpAddr::from([9, 9, 9, 9]), blocklist),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_notify_and_log_blocklisted() {
+        let mut logged_notifications = (VecDeque::new(), 0);
+        let blocklisted = vec![(
+            host_msg([9, 9, 9, 9], "cdn.tracker.example", "19281", Country::CH),
+            "list.txt".to_string(),
+        )];
+        let emitted = notify_and_log(
+            &mut logged_notifications,
+            &Notifications::default(),
+            &mut NotificationsState::default(),
+            &InfoTraffic::default(),
+            Vec::new(),
+            blocklisted,
+            &HashSet::new(),
+            &CaptureSource::File(MyPcapImport::new(String::new())),
+            Language::EN,
+        );
+        assert_eq!(emitted.len(), 1);
+        assert!(matches!(
+            logged_notifications.0.front(),
+            Some(LoggedNotification::Blocklisted(b)) if b.id == 1 && b.blocklist == "list.txt"
+        ));
+        assert_eq!(
+            logged_notifications.0[0].description(Language::EN),
+            "cdn.tracker.example (9.9.9.9)\nlist.txt"
+        );
+    }
+
+    #[test]
+    fn test_notify_and_log_first_seen() {
+        let mut logged_notifications = (VecDeque::new(), 0);
+        let first_seen = vec![
+            (
+                FirstSeenKind::Host,
+                host_msg([1, 1, 1, 1], "one.one", "13335", Country::AU),
+            ),
+            (
+                FirstSeenKind::Country,
+                host_msg([1, 1, 1, 1], "one.one", "13335", Country::AU),
+            ),
+        ];
+        let emitted = notify_and_log(
+            &mut logged_notifications,
+            &Notifications::default(),
+            &mut NotificationsState::default(),
+            &InfoTraffic::default(),
+            first_seen,
+            Vec::new(),
+            &HashSet::new(),
+            &CaptureSource::File(MyPcapImport::new(String::new())),
+            Language::EN,
+        );
+        assert_eq!(emitted.len(), 2);
+        assert_eq!(logged_notifications.1, 2);
+        assert!(matches!(
+            logged_notifications.0.front(),
+            Some(LoggedNotification::NewCountry(n)) if n.id == 2
+        ));
+        assert!(matches!(
+            logged_notifications.0.back(),
+            Some(LoggedNotification::NewHost(n)) if n.id == 1
+        ));
+    }
+
+    #[test]
+    fn test_notify_and_log_scoped_thresholds() {
+        let ssh = Service::Name("ssh");
+        let mut info_traffic_msg = InfoTraffic::default();
+        info_traffic_msg
+            .services
+            .insert(ssh, DataInfo::new_for_tests(5, 5, 1_000, 1_500));
+        info_traffic_msg.services.insert(
+            Service::Name("https"),
+            DataInfo::new_for_tests(5, 5, 9_000, 9_000),
+        );
+        let rule = |scope, threshold| ScopedDataNotification {
+            scope,
+            data_notification: DataNotification {
+                threshold,
+                ..DataNotification::default()
+            },
+        };
+        let notifications = Notifications {
+            scoped_data_notifications: vec![
+                rule(ThresholdScope::Service("ssh".to_string()), Some(2_000)),
+                rule(ThresholdScope::Service("ssh".to_string()), Some(3_000)),
+                rule(ThresholdScope::Service("ssh".to_string()), None),
+                rule(ThresholdScope::Country("IT".to_string()), Some(0)),
+            ],
+            ..Notifications::default()
+        };
+
+        let mut logged_notifications = (VecDeque::new(), 0);
+        let emitted = notify_and_log(
+            &mut logged_notifications,
+            &notifications,
+            &mut NotificationsState::default(),
+            &info_traffic_msg,
+            Vec::new(),
+            Vec::new(),
+            &HashSet::new(),
+            &CaptureSource::File(MyPcapImport::new(String::new())),
+            Language::EN,
+        );
+        assert_eq!(emitted.len(), 1);
+        let Some(LoggedNotification::DataThresholdExceeded(exceeded)) =
+            logged_notifications.0.front()
+        else {
+            panic!("expected a data threshold notification");
+        };
+        assert_eq!(exceeded.threshold, 2_000);
+        assert_eq!(exceeded.data_info.tot_bytes(), 2_500);
+        assert_eq!(
+            exceeded.scope,
+            Some(ThresholdScope::Service("ssh".to_string()))
+        );
+        assert_eq!(exceeded.services.len(), 1);
+        assert!(exceeded.hosts.is_empty());
+    }
+
+    #[test]
+    fn test_notify_and_log_sustained_thresholds() {
+        let mut info_traffic_msg = InfoTraffic {
+            tot_data_info: DataInfo::new_for_tests(5, 5, 1_000, 1_500),
+            ..InfoTraffic::default()
+        };
+        info_traffic_msg.services.insert(
+            Service::Name("ssh"),
+            DataInfo::new_for_tests(5, 5, 1_000, 1_500),
+        );
+        let data_notification = DataNotification {
+            threshold: Some(2_000),
+            alert_conditions: AlertConditions {
+                sustained_seconds: 2,
+                ..AlertConditions::default()
+            },
+            ..DataNotification::default()
+        };
+        let notifications = Notifications {
+            data_notification,
+            scoped_data_notifications: vec![ScopedDataNotification {
+                scope: ThresholdScope::Service("ssh".to_string()),
+                data_notification,
+            }],
+            ..Notifications::default()
+        };
+
+        let mut logged_notifications = (VecDeque::new(), 0);
+        let mut notifications_state = NotificationsState::default();
+        let mut tick = || {
+            notify_and_log(
+                &mut logged_notifications,
+                &notifications,
+                &mut notifications_state,
+                &info_traffic_msg,
+                Vec::new(),
+                Vec::new(),
+                &HashSet::new(),
+                &CaptureSource::File(MyPcapImport::new(String::new())),
+                Language::EN,
+            )
+            .len()
+        };
+        // both the global and the scoped threshold need to be exceeded for 2 seconds
+        assert_eq!(tick(), 0);
+        assert_eq!(tick(), 2);
+        assert_eq!(tick(), 0);
+        assert_eq!(tick(), 2);
+    }
+
+    #[test]
+    fn test_notify_and_log_anomalies() {
+        let steady = InfoTraffic {
+            tot_data_info: DataInfo::new_for_tests(1, 1, 500, 500),
+            ..InfoTraffic::default()
+        };
+        let spike = InfoTraffic {
+            tot_data_info: DataInfo::new_for_tests(100, 100, 50_000, 50_000),
+            ..InfoTraffic::default()
+        };
+        let mut notifications_state = NotificationsState::default();
+        let mut tick =
+            |notifications: &Notifications,
+             info_traffic_msg: &InfoTraffic,
+             logged_notifications: &mut (VecDeque<LoggedNotification>, usize)| {
+                notify_and_log(
+                    logged_notifications,
+                    notifications,
+                    &mut notifications_state,
+                    info_traffic_msg,
+                    Vec::new(),
+                    Vec::new(),
+                    &HashSet::new(),
+                    &CaptureSource::File(MyPcapImport::new(String::new())),
+                    Language::EN,
+                )
+                .len()
+            };
+
+        // the baseline is learnt even if the notification is disabled
+        let mut logged_notifications = (VecDeque::new(), 0);
+        for _ in 0..MIN_BASELINE_SAMPLES {
+            assert_eq!(
+                tick(
+                    &Notifications::default(),
+                    &steady,
+                    &mut logged_notifications
+                ),
+                0
+            );
+        }
+
+        let notifications = Notifications {
+            anomaly_notification: AnomalyNotification {
+                notify_on_anomaly: true,
+                ..AnomalyNotification::default()
+            },
+            ..Notifications::default()
+        };
+        assert_eq!(tick(&notifications, &spike, &mut logged_notifications), 1);
+        let Some(LoggedNotification::AnomalyDetected(anomaly)) = logged_notifications.0.front()
+        else {
+            panic!("expected an anomaly notification");
+        };
+        assert_eq!(anomaly.service, None);
+        assert_eq!(anomaly.baseline_bytes, 1_000);
+        assert_eq!(anomaly.data_info.tot_bytes(), 100_000);
+    }
+
+    #[test]
+    fn test_notify_and_log_scans() {
+        let scanner = IpAddr::from([203, 0, 113, 7]);
+        let mut info_traffic_msg = InfoTraffic::default();
+        for port in 1..=30 {
+            info_traffic_msg.map.insert(
+                AddressPortPair {
+                    address1: scanner,
+                    port1: Some(40_000),
+                    address2: IpAddr::from([192, 168, 1, 2]),
+                    port2: Some(port),
+                    protocol: Protocol::TCP,
+                },
+                InfoAddressPortPair {
+                    traffic_direction: TrafficDirection::Incoming,
+                    tcp_flags: TcpFlagsCount {
+                        syn: 1,
+                        ..TcpFlagsCount::default()
+                    },
+                    ..InfoAddressPortPair::default()
+                },
+            );
+        }
+        let mut notifications_state = NotificationsState::default();
+        let mut tick =
+            |notifications: &Notifications,
+             logged_notifications: &mut (VecDeque<LoggedNotification>, usize)| {
+                notify_and_log(
+                    logged_notifications,
+                    notifications,
+                    &mut notifications_state,
+                    &info_traffic_msg,
+                    Vec::new(),
+                    Vec::new(),
+                    &HashSet::new(),
+                    &CaptureSource::File(MyPcapImport::new(String::new())),
+                    Language::EN,
+                )
+                .len()
+            };
+
+        let mut logged_notifications = (VecDeque::new(), 0);
+        assert_eq!(
+            tick(&Notifications::default(), &mut logged_notifications),
+            0
+        );
+
+        let notifications = Notifications {
+            scan_notification: ScanNotification {
+                notify_on_scan: true,
+                ..ScanNotification::default()
+            },
+            ..Notifications::default()
+        };
+        assert_eq!(tick(&notifications, &mut logged_notifications), 1);
+        let Some(LoggedNotification::ScanDetected(scan)) = logged_notifications.0.front() else {
+            panic!("expected a scan notification");
+        };
+        assert_eq!(scan.address, scanner);
+        assert_eq!(scan.ports, (1..=30).collect::<Vec<u16>>());
+        assert_eq!(
+            logged_notifications.0[0].description(Language::EN),
+            format!(
+                "203.0.113.7\nDistinct ports (30): {}, ...\nDistinct hosts (1): 192.168.1.2",
+                (1..=20)
+                    .map(|p: u16| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        );
+    }
+
+    #[test]
+    fn test_log_notification_keeps_latest() {
+        let mut logged_notifications = (VecDeque::new(), 0);
+        let mut emitted = Vec::new();
+        for _ in 0..35 {
+            log_notification(&mut logged_notifications, &mut emitted, |id| {
+                LoggedNotification::NewHost(FirstSeen {
+                    id,
+                    host: Host::default(),
+                    address: IpAddr::from([1, 1, 1, 1]),
+                    data_info_host: DataInfoHost::default(),
+                    timestamp: String::new(),
+                })
+            });
+        }
+        assert_eq!(logged_notifications.1, 35);
+        assert_eq!(logged_notifications.0.len(), 30);
+        assert_eq!(logged_notifications.0.front().unwrap().id(), 35);
+        assert_eq!(logged_notifications.0.back().unwrap().id(), 6);
+        // all the emitted notifications are returned, even those not kept in the log
+        assert_eq!(
+            emitted
+                .iter()
+                .map(LoggedNotification::id)
+                .collect::<Vec<_>>(),
+            (1..=35).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_notify_and_log_returns_all_emitted() {
+        let mut logged_notifications = (VecDeque::new(), 0);
+        let first_seen = (1..=35)
+            .map(|i| {
+                (
+                    FirstSeenKind::Host,
+                    host_msg([10, 0, 0, i], &format!("host{i}"), "1", Country::US),
+                )
+            })
+            .collect();
+        let emitted = notify_and_log(
+            &mut logged_notifications,
+            &Notifications::default(),
+            &mut NotificationsState::default(),
+            &InfoTraffic::default(),
+            first_seen,
+            Vec::new(),
+            &HashSet::new(),
+            &CaptureSource::File(MyPcapImport::new(String::new())),
+            Language::EN,
+        );
+        assert_eq!(emitted.len(), 35);
+        assert_eq!(logged_notifications.0.len(), 30);
+        // from the oldest to the most recent
+        assert_eq!(emitted[0].description(Language::EN), "host1 (10.0.0.1)");
+        assert_eq!(emitted[34].description(Language::EN), "host35 (10.0.0.35)");
+    }
+
+    #[test]
+    fn test_desktop_notification_text() {
+        let mut logged_notifications = (VecDeque::new(), 0);
+        assert_eq!(
+            desktop_notification_text(&logged_notifications.0, 0, Language::EN),
+            None
+        );
+
+        let new_host = (
+            FirstSeenKind::Host,
+            host_msg([1, 1, 1, 1], "one.one", "13335", Country::AU),
+        );
+        notify_and_log(
+            &mut logged_notifications,
+            &Notifications::default(),
+            &mut NotificationsState::default(),
+            &InfoTraffic::default(),
+            vec![new_host],
+            Vec::new(),
+            &HashSet::new(),
+            &CaptureSource::File(MyPcapImport::new(String::new())),
+            Language::EN,
+        );
+        assert_eq!(
+            desktop_notification_text(&logged_notifications.0, 1, Language::EN),
+            Some(("New host seen".to_string(), "one.one (1.1.1.1)".to_string()))
+        );
+        // no notifications were emitted in the last interval
+        assert_eq!(
+            desktop_notification_text(&logged_notifications.0, 0, Language::EN),
+            None
+        );
+
+        notify_and_log(
+            &mut logged_notifications,
+            &Notifications::default(),
+            &mut NotificationsState::default(),
+            &InfoTraffic::default(),
+            vec![
+                (
+                    FirstSeenKind::Asn,
+                    host_msg([8, 8, 8, 8], "dns.google", "15169", Country::US),
+                ),
+                (
+                    FirstSeenKind::Country,
+                    host_msg([8, 8, 8, 8], "dns.google", "15169", Country::US),
+                ),
+            ],
+            Vec::new(),
+            &HashSet::new(),
+            &CaptureSource::File(MyPcapImport::new(String::new())),
+            Language::EN,
+        );
+        assert_eq!(
+            desktop_notification_text(&logged_notifications.0, 2, Language::EN),
+            Some((
+                "New country seen (+1)".to_string(),
+                "New country seen: US (dns.google)\nNew autonomous system seen: AS15169"
+                    .to_string()
+            ))
+        );
+    }
+}
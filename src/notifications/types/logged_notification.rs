@@ -1,15 +1,45 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ByteMultiple;
 use crate::chart::types::chart_type::ChartType;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
 use crate::networking::types::host::Host;
 use crate::networking::types::service::Service;
+use crate::notifications::types::notifications::ThresholdScope;
+use crate::translations::translations::{
+    bytes_exceeded_translation, favorite_transmitted_translation, packets_exceeded_translation,
+    per_second_translation, threshold_translation,
+};
+use crate::translations::translations_4::{
+    anomaly_detected_translation, baseline_translation, blocklisted_host_translation,
+    data_exceeded_translation, distinct_hosts_translation, distinct_ports_translation,
+    new_asn_translation, new_country_translation, new_host_translation, scan_detected_translation,
+    whole_traffic_translation,
+};
+use crate::translations::types::language::Language;
 
 /// Enum representing the possible notification events.
+#[derive(Clone, Serialize, Deserialize)]
 pub enum LoggedNotification {
     /// Data threshold exceeded
     DataThresholdExceeded(DataThresholdExceeded),
     /// Favorite connection exchanged data
     FavoriteTransmitted(FavoriteTransmitted),
+    /// Host seen for the first time
+    NewHost(FirstSeen),
+    /// Autonomous System seen for the first time
+    NewAsn(FirstSeen),
+    /// Country seen for the first time
+    NewCountry(FirstSeen),
+    /// Traffic deviating from the baseline
+    AnomalyDetected(AnomalyDetected),
+    /// Remote host contacting many ports or local hosts
+    ScanDetected(ScanDetected),
+    /// Host matching the blocklists
+    Blocklisted(BlocklistMatch),
 }
 
 impl LoggedNotification {
@@ -17,6 +47,12 @@ pub fn id(&self) -> usize {
         match self {
             LoggedNotification::DataThresholdExceeded(d) => d.id,
             LoggedNotification::FavoriteTransmitted(f) => f.id,
+            LoggedNotification::NewHost(n)
+            | LoggedNotification::NewAsn(n)
+            | LoggedNotification::NewCountry(n) => n.id,
+            LoggedNotification::AnomalyDetected(a) => a.id,
+            LoggedNotification::ScanDetected(s) => s.id,
+            LoggedNotification::Blocklisted(b) => b.id,
         }
     }
 
@@ -24,18 +60,183 @@ pub fn data_info(&self) -> DataInfo {
         match self {
             LoggedNotification::DataThresholdExceeded(d) => d.data_info,
             LoggedNotification::FavoriteTransmitted(f) => f.data_info_host.data_info,
+            LoggedNotification::NewHost(n)
+            | LoggedNotification::NewAsn(n)
+            | LoggedNotification::NewCountry(n) => n.data_info_host.data_info,
+            LoggedNotification::AnomalyDetected(a) => a.data_info,
+            LoggedNotification::ScanDetected(_) => DataInfo::default(),
+            LoggedNotification::Blocklisted(b) => b.data_info_host.data_info,
+        }
+    }
+
+    pub fn timestamp(&self) -> &str {
+        match self {
+            LoggedNotification::DataThresholdExceeded(d) => &d.timestamp,
+            LoggedNotification::FavoriteTransmitted(f) => &f.timestamp,
+            LoggedNotification::NewHost(n)
+            | LoggedNotification::NewAsn(n)
+            | LoggedNotification::NewCountry(n) => &n.timestamp,
+            LoggedNotification::AnomalyDetected(a) => &a.timestamp,
+            LoggedNotification::ScanDetected(s) => &s.timestamp,
+            LoggedNotification::Blocklisted(b) => &b.timestamp,
+        }
+    }
+
+    pub fn kind(&self) -> NotificationKind {
+        match self {
+            LoggedNotification::DataThresholdExceeded(_) => NotificationKind::DataThreshold,
+            LoggedNotification::FavoriteTransmitted(_) => NotificationKind::Favorite,
+            LoggedNotification::NewHost(_) => NotificationKind::NewHost,
+            LoggedNotification::NewAsn(_) => NotificationKind::NewAsn,
+            LoggedNotification::NewCountry(_) => NotificationKind::NewCountry,
+            LoggedNotification::AnomalyDetected(_) => NotificationKind::Anomaly,
+            LoggedNotification::ScanDetected(_) => NotificationKind::Scan,
+            LoggedNotification::Blocklisted(_) => NotificationKind::Blocklisted,
         }
     }
 
     pub fn expand(&mut self, expand: bool) {
         match self {
             LoggedNotification::DataThresholdExceeded(d) => d.is_expanded = expand,
-            LoggedNotification::FavoriteTransmitted(_) => {}
+            LoggedNotification::FavoriteTransmitted(_)
+            | LoggedNotification::NewHost(_)
+            | LoggedNotification::NewAsn(_)
+            | LoggedNotification::NewCountry(_)
+            | LoggedNotification::AnomalyDetected(_)
+            | LoggedNotification::ScanDetected(_)
+            | LoggedNotification::Blocklisted(_) => {}
+        }
+    }
+
+    /// Short description of the event, used as title of the notification
+    pub fn title(&self, language: Language) -> &'static str {
+        match self {
+            LoggedNotification::DataThresholdExceeded(d) => {
+                if d.chart_type == ChartType::Bytes {
+                    bytes_exceeded_translation(language)
+                } else {
+                    packets_exceeded_translation(language)
+                }
+            }
+            LoggedNotification::FavoriteTransmitted(_) => {
+                favorite_transmitted_translation(language)
+            }
+            LoggedNotification::NewHost(_) => new_host_translation(language),
+            LoggedNotification::NewAsn(_) => new_asn_translation(language),
+            LoggedNotification::NewCountry(_) => new_country_translation(language),
+            LoggedNotification::AnomalyDetected(_) => anomaly_detected_translation(language),
+            LoggedNotification::ScanDetected(_) => scan_detected_translation(language),
+            LoggedNotification::Blocklisted(_) => blocklisted_host_translation(language),
+        }
+    }
+
+    /// Details of the event, as plain text
+    pub fn description(&self, language: Language) -> String {
+        match self {
+            LoggedNotification::DataThresholdExceeded(d) => {
+                let data_string = if d.chart_type == ChartType::Bytes {
+                    ByteMultiple::formatted_string(d.threshold.into())
+                } else {
+                    d.threshold.to_string()
+                };
+                let mut description = format!(
+                    "{}: {data_string} {}",
+                    threshold_translation(language),
+                    per_second_translation(language)
+                );
+                if let Some(scope) = &d.scope {
+                    description.push_str(&format!(
+                        "\n{}: {}",
+                        scope.get_label(language),
+                        scope.value()
+                    ));
+                }
+                description
+            }
+            LoggedNotification::FavoriteTransmitted(f) => f.host.domain.clone(),
+            LoggedNotification::NewHost(n) => format!("{} ({})", n.host.domain, n.address),
+            LoggedNotification::NewAsn(n) => n.host.asn.name.clone(),
+            LoggedNotification::NewCountry(n) => format!("{} ({})", n.host.country, n.host.domain),
+            LoggedNotification::AnomalyDetected(a) => {
+                let traffic = match &a.service {
+                    Some(service) => service.to_string(),
+                    None => whole_traffic_translation(language).to_string(),
+                };
+                format!(
+                    "{traffic}: {} {}\n{}: {} {} (z = {:.1})",
+                    ByteMultiple::formatted_string(a.data_info.tot_data(ChartType::Bytes)),
+                    per_second_translation(language),
+                    baseline_translation(language),
+                    ByteMultiple::formatted_string(a.baseline_bytes),
+                    per_second_translation(language),
+                    a.z_score
+                )
+            }
+            LoggedNotification::ScanDetected(s) => {
+                let mut description = s.address.to_string();
+                if !s.ports.is_empty() {
+                    description.push_str(&format!(
+                        "\n{} ({}): {}",
+                        distinct_ports_translation(language),
+                        s.ports.len(),
+                        s.ports_list()
+                    ));
+                }
+                description.push_str(&format!(
+                    "\n{} ({}): {}",
+                    distinct_hosts_translation(language),
+                    s.hosts.len(),
+                    s.hosts_list()
+                ));
+                description
+            }
+            LoggedNotification::Blocklisted(b) => {
+                format!("{} ({})\n{}", b.host.domain, b.address, b.blocklist)
+            }
+        }
+    }
+}
+
+/// Kinds of notification events, used to filter them
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotificationKind {
+    DataThreshold,
+    Favorite,
+    NewHost,
+    NewAsn,
+    NewCountry,
+    Anomaly,
+    Scan,
+    Blocklisted,
+}
+
+impl NotificationKind {
+    pub(crate) const ALL: [NotificationKind; 8] = [
+        NotificationKind::DataThreshold,
+        NotificationKind::Favorite,
+        NotificationKind::NewHost,
+        NotificationKind::NewAsn,
+        NotificationKind::NewCountry,
+        NotificationKind::Anomaly,
+        NotificationKind::Scan,
+        NotificationKind::Blocklisted,
+    ];
+
+    pub fn get_label(self, language: Language) -> &'static str {
+        match self {
+            NotificationKind::DataThreshold => data_exceeded_translation(language),
+            NotificationKind::Favorite => favorite_transmitted_translation(language),
+            NotificationKind::NewHost => new_host_translation(language),
+            NotificationKind::NewAsn => new_asn_translation(language),
+            NotificationKind::NewCountry => new_country_translation(language),
+            NotificationKind::Anomaly => anomaly_detected_translation(language),
+            NotificationKind::Scan => scan_detected_translation(language),
+            NotificationKind::Blocklisted => blocklisted_host_translation(language),
         }
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct DataThresholdExceeded {
     pub(crate) id: usize,
     pub(crate) chart_type: ChartType,
@@ -45,12 +246,84 @@ pub struct DataThresholdExceeded {
     pub(crate) is_expanded: bool,
     pub(crate) hosts: Vec<(Host, DataInfoHost)>,
     pub(crate) services: Vec<(Service, DataInfo)>,
+    /// Traffic considered by the exceeded threshold (`None` for the whole traffic)
+    pub(crate) scope: Option<ThresholdScope>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FavoriteTransmitted {
     pub(crate) id: usize,
     pub(crate) host: Host,
     pub(crate) data_info_host: DataInfoHost,
     pub(crate) timestamp: String,
 }
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FirstSeen {
+    pub(crate) id: usize,
+    pub(crate) host: Host,
+    pub(crate) address: IpAddr,
+    pub(crate) data_info_host: DataInfoHost,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BlocklistMatch {
+    pub(crate) id: usize,
+    pub(crate) host: Host,
+    pub(crate) address: IpAddr,
+    pub(crate) data_info_host: DataInfoHost,
+    /// Path of the blocklist listing the host
+    pub(crate) blocklist: String,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AnomalyDetected {
+    pub(crate) id: usize,
+    /// Service whose traffic is anomalous (`None` for the whole traffic)
+    pub(crate) service: Option<Service>,
+    pub(crate) data_info: DataInfo,
+    /// Average bytes per second before the anomaly
+    pub(crate) baseline_bytes: u128,
+    pub(crate) z_score: f64,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ScanDetected {
+    pub(crate) id: usize,
+    /// Address of the remote host
+    pub(crate) address: IpAddr,
+    /// Distinct ports contacted, sorted
+    pub(crate) ports: Vec<u16>,
+    /// Distinct local hosts contacted, sorted
+    pub(crate) hosts: Vec<IpAddr>,
+    pub(crate) timestamp: String,
+}
+
+impl ScanDetected {
+    /// Maximum number of ports or hosts listed in the description
+    const MAX_LISTED: usize = 20;
+
+    pub fn ports_list(&self) -> String {
+        Self::list(self.ports.iter())
+    }
+
+    pub fn hosts_list(&self) -> String {
+        Self::list(self.hosts.iter())
+    }
+
+    fn list<T: ToString>(items: impl ExactSizeIterator<Item = T>) -> String {
+        let more = items.len() > Self::MAX_LISTED;
+        let mut list = items
+            .take(Self::MAX_LISTED)
+            .map(|item| item.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        if more {
+            list.push_str(", ...");
+        }
+        list
+    }
+}
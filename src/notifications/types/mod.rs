@@ -1,3 +1,7 @@
 pub mod logged_notification;
+pub mod notification_action;
 pub mod notifications;
+pub mod notifications_history;
+pub mod scan_detector;
 pub mod sound;
+pub mod traffic_baseline;
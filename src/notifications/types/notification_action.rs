@@ -0,0 +1,394 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use iced::Font;
+use iced::widget::Text;
+use serde::{Deserialize, Serialize};
+
+use crate::gui::styles::style_constants::FONT_SIZE_FOOTER;
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::translations::translations_4::command_translation;
+use crate::translations::types::language::Language;
+use crate::utils::formatted_strings::APP_VERSION;
+use crate::utils::types::icon::Icon;
+use crate::{SNIFFNET_LOWERCASE, StyleType};
+
+/// Action executed when a notification is emitted.
+///
+/// The executed action receives a JSON description of the notification:
+/// on its standard input in case of a command, or as the request body in case of a webhook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum NotificationAction {
+    /// No action
+    #[default]
+    None,
+    /// Shell command to run
+    Command(String),
+    /// URL to send an HTTP POST request to
+    Webhook(String),
+}
+
+impl NotificationAction {
+    pub(crate) const ALL: [NotificationAction; 3] = [
+        NotificationAction::None,
+        NotificationAction::Command(String::new()),
+        NotificationAction::Webhook(String::new()),
+    ];
+
+    pub fn get_text<'a>(&self, font: Font, language: Language) -> Text<'a, StyleType> {
+        match self {
+            NotificationAction::None => Icon::Forbidden.to_text(),
+            NotificationAction::Command(_) => Text::new(command_translation(language)).font(font),
+            NotificationAction::Webhook(_) => Text::new("HTTP POST").font(font),
+        }
+        .size(FONT_SIZE_FOOTER)
+    }
+
+    /// The command or URL of the action
+    pub fn value(&self) -> &str {
+        match self {
+            NotificationAction::None => "",
+            NotificationAction::Command(value) | NotificationAction::Webhook(value) => value,
+        }
+    }
+
+    /// Returns an action of the same kind of `self`, with the given command or URL
+    #[must_use]
+    pub fn with_value(&self, value: String) -> Self {
+        match self {
+            NotificationAction::None => NotificationAction::None,
+            NotificationAction::Command(_) => NotificationAction::Command(value),
+            NotificationAction::Webhook(_) => NotificationAction::Webhook(value),
+        }
+    }
+
+    pub fn same_kind(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Executes the action, waiting for its completion
+    fn execute(&self, payload: &str) -> Result<(), String> {
+        if self.value().trim().is_empty() {
+            return Ok(());
+        }
+        match self {
+            NotificationAction::None => Ok(()),
+            NotificationAction::Command(command) => run_command(command, payload),
+            NotificationAction::Webhook(url) => post_webhook(url, payload),
+        }
+    }
+}
+
+/// Notification types that can trigger an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionTrigger {
+    /// Data thresholds, both the global and the scoped ones
+    Data,
+    /// Favorite hosts exchanging data
+    Favorite,
+    /// Hosts, ASNs, or countries seen for the first time
+    FirstSeen,
+    /// Traffic deviating from the baseline
+    Anomaly,
+    /// Remote hosts contacting many ports or local hosts
+    Scan,
+    /// Hosts matching the blocklists
+    Blocklist,
+}
+
+impl ActionTrigger {
+    pub fn of(logged_notification: &LoggedNotification) -> Self {
+        match logged_notification {
+            LoggedNotification::DataThresholdExceeded(_) => ActionTrigger::Data,
+            LoggedNotification::FavoriteTransmitted(_) => ActionTrigger::Favorite,
+            LoggedNotification::NewHost(_)
+            | LoggedNotification::NewAsn(_)
+            | LoggedNotification::NewCountry(_) => ActionTrigger::FirstSeen,
+            LoggedNotification::AnomalyDetected(_) => ActionTrigger::Anomaly,
+            LoggedNotification::ScanDetected(_) => ActionTrigger::Scan,
+            LoggedNotification::Blocklisted(_) => ActionTrigger::Blocklist,
+        }
+    }
+}
+
+/// Actions configured for each notification type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct NotificationActions {
+    pub data: NotificationAction,
+    pub favorite: NotificationAction,
+    pub first_seen: NotificationAction,
+    pub anomaly: NotificationAction,
+    pub scan: NotificationAction,
+    pub blocklist: NotificationAction,
+}
+
+impl NotificationActions {
+    pub fn get(&self, trigger: ActionTrigger) -> &NotificationAction {
+        match trigger {
+            ActionTrigger::Data => &self.data,
+            ActionTrigger::Favorite => &self.favorite,
+            ActionTrigger::FirstSeen => &self.first_seen,
+            ActionTrigger::Anomaly => &self.anomaly,
+            ActionTrigger::Scan => &self.scan,
+            ActionTrigger::Blocklist => &self.blocklist,
+        }
+    }
+
+    pub fn set(&mut self, trigger: ActionTrigger, action: NotificationAction) {
+        match trigger {
+            ActionTrigger::Data => self.data = action,
+            ActionTrigger::Favorite => self.favorite = action,
+            ActionTrigger::FirstSeen => self.first_seen = action,
+            ActionTrigger::Anomaly => self.anomaly = action,
+            ActionTrigger::Scan => self.scan = action,
+            ActionTrigger::Blocklist => self.blocklist = action,
+        }
+    }
+
+    /// Returns the actions to execute for the given notifications, each one paired with its JSON payload
+    pub fn for_notifications<'a>(
+        &self,
+        logged_notifications: impl Iterator<Item = &'a LoggedNotification>,
+    ) -> Vec<(NotificationAction, String)> {
+        logged_notifications
+            .filter_map(|logged_notification| {
+                let action = self.get(ActionTrigger::of(logged_notification));
+                if action.value().trim().is_empty() {
+                    return None;
+                }
+                let payload = serde_json::to_string(logged_notification).ok()?;
+                Some((action.clone(), payload))
+            })
+            .collect()
+    }
+}
+
+/// Executes the given actions one after the other, in a separate thread
+pub fn run_actions(actions: Vec<(NotificationAction, String)>) {
+    let _ = spawn_actions(actions, NotificationAction::execute);
+}
+
+/// Spawns a thread passing the given actions to `execute` one after the other (if there's any action)
+fn spawn_actions(
+    actions: Vec<(NotificationAction, String)>,
+    execute: impl Fn(&NotificationAction, &str) -> Result<(), String> + Send + 'static,
+) -> Option<JoinHandle<()>> {
+    if actions.is_empty() {
+        return None;
+    }
+    thread::Builder::new()
+        .name("thread_notification_actions".to_string())
+        .spawn(move || {
+            for (action, payload) in actions {
+                // a failing action must not prevent the following ones from running
+                let _ = execute(&action, &payload);
+            }
+        })
+        .ok()
+}
+
+fn run_command(command: &str, payload: &str) -> Result<(), String> {
+    #[cfg(not(target_os = "windows"))]
+    let mut child = Command::new("sh");
+    #[cfg(not(target_os = "windows"))]
+    child.arg("-c");
+
+    #[cfg(target_os = "windows")]
+    let mut child = Command::new("cmd");
+    #[cfg(target_os = "windows")]
+    child.arg("/C");
+
+    let mut child = child
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // the command is free to ignore its input
+        let _ = stdin.write_all(payload.as_bytes());
+    }
+    let status = child.wait().map_err(|e| e.to_string())?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("command exited with {status}"))
+    }
+}
+
+fn post_webhook(url: &str, payload: &str) -> Result<(), String> {
+    reqwest::blocking::Client::new()
+        .post(url)
+        .header("User-agent", format!("{SNIFFNET_LOWERCASE}-{APP_VERSION}"))
+        .header("Content-Type", "application/json")
+        .body(payload.to_string())
+        .timeout(Duration::from_secs(10))
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use super::*;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::host::Host;
+    use crate::notifications::types::logged_notification::FavoriteTransmitted;
+
+    /// Local stand-in for a webhook endpoint: it accepts a single request,
+    /// answering with the given status, and returns the received request line and body
+    fn serve_once(status: &'static str) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n").as_bytes())
+                .unwrap();
+            (
+                request_line.trim().to_string(),
+                String::from_utf8(body).unwrap(),
+            )
+        });
+        (url, handle)
+    }
+
+    fn favorite_notification() -> LoggedNotification {
+        LoggedNotification::FavoriteTransmitted(FavoriteTransmitted {
+            id: 7,
+            host: Host {
+                domain: "example.com".to_string(),
+                ..Host::default()
+            },
+            data_info_host: DataInfoHost::default(),
+            timestamp: "08/07/2025 10:20:30".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_actions_for_notifications() {
+        let notifications = [favorite_notification()];
+        assert!(
+            NotificationActions::default()
+                .for_notifications(notifications.iter())
+                .is_empty()
+        );
+
+        let actions = NotificationActions {
+            data: NotificationAction::Command("true".to_string()),
+            favorite: NotificationAction::Webhook("http://localhost:1234".to_string()),
+            first_seen: NotificationAction::Command(" ".to_string()),
+            anomaly: NotificationAction::None,
+            scan: NotificationAction::None,
+            blocklist: NotificationAction::None,
+        };
+        let to_execute = actions.for_notifications(notifications.iter());
+        assert_eq!(to_execute.len(), 1);
+        assert_eq!(to_execute[0].0, actions.favorite);
+        let payload: serde_json::Value = serde_json::from_str(&to_execute[0].1).unwrap();
+        assert_eq!(payload["FavoriteTransmitted"]["id"], 7);
+        assert_eq!(
+            payload["FavoriteTransmitted"]["host"]["domain"],
+            "example.com"
+        );
+        assert_eq!(
+            payload["FavoriteTransmitted"]["timestamp"],
+            "08/07/2025 10:20:30"
+        );
+    }
+
+    #[test]
+    fn test_spawn_actions() {
+        assert!(spawn_actions(Vec::new(), |_, _| panic!("nothing to execute")).is_none());
+
+        let executed = Arc::new(Mutex::new(Vec::new()));
+        let executed_2 = executed.clone();
+        let actions = vec![
+            (
+                NotificationAction::Command("exit 1".to_string()),
+                "first".to_string(),
+            ),
+            (
+                NotificationAction::Webhook("http://localhost:1234".to_string()),
+                "second".to_string(),
+            ),
+        ];
+        spawn_actions(actions.clone(), move |action, payload| {
+            executed_2
+                .lock()
+                .unwrap()
+                .push((action.clone(), payload.to_string()));
+            Err("failed".to_string())
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+        // all the actions are executed in order, even if they fail
+        assert_eq!(*executed.lock().unwrap(), actions);
+    }
+
+    #[test]
+    fn test_webhook_posts_payload() {
+        let (url, server) = serve_once("200 OK");
+        let payload = serde_json::to_string(&favorite_notification()).unwrap();
+        assert_eq!(NotificationAction::Webhook(url).execute(&payload), Ok(()));
+        let (request_line, body) = server.join().unwrap();
+        assert_eq!(request_line, "POST /hook HTTP/1.1");
+        assert_eq!(body, payload);
+    }
+
+    #[test]
+    fn test_webhook_reports_server_errors() {
+        let (url, server) = serve_once("500 Internal Server Error");
+        assert!(NotificationAction::Webhook(url).execute("{}").is_err());
+        server.join().unwrap();
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_command_receives_payload() {
+        let path = std::env::temp_dir().join(format!(
+            "{SNIFFNET_LOWERCASE}_action_test_{}.json",
+            std::process::id()
+        ));
+        let payload = serde_json::to_string(&favorite_notification()).unwrap();
+        let action = NotificationAction::Command(format!("cat > '{}'", path.display()));
+        assert_eq!(action.execute(&payload), Ok(()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), payload);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(
+            NotificationAction::Command("exit 3".to_string())
+                .execute(&payload)
+                .is_err()
+        );
+    }
+}
@@ -2,14 +2,33 @@
 
 use crate::ByteMultiple;
 use crate::chart::types::chart_type::ChartType;
+use crate::countries::types::country::Country;
+use crate::networking::types::host::Host;
+use crate::networking::types::service::Service;
+use crate::notifications::types::notification_action::NotificationActions;
+use crate::notifications::types::scan_detector::ScanDetector;
 use crate::notifications::types::sound::Sound;
+use crate::notifications::types::traffic_baseline::TrafficBaseline;
+use crate::translations::translations_2::{country_translation, host_translation};
+use crate::translations::translations_3::service_translation;
+use crate::translations::translations_4::{high_translation, low_translation, medium_translation};
+use crate::translations::types::language::Language;
 
 /// Used to contain the notifications configuration set by the user
-#[derive(Clone, Serialize, Deserialize, Copy, PartialEq, Debug)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
 pub struct Notifications {
     pub volume: u8,
     pub data_notification: DataNotification,
+    pub scoped_data_notifications: Vec<ScopedDataNotification>,
     pub favorite_notification: FavoriteNotification,
+    pub first_seen_notification: FirstSeenNotification,
+    pub anomaly_notification: AnomalyNotification,
+    pub scan_notification: ScanNotification,
+    pub blocklist_notification: BlocklistNotification,
+    /// Whether notifications are also shown as desktop notifications
+    pub desktop_notifications: bool,
+    /// Commands or webhooks executed when notifications are emitted
+    pub actions: NotificationActions,
 }
 
 impl Default for Notifications {
@@ -17,7 +36,14 @@ fn default() -> Self {
         Notifications {
             volume: 60,
             data_notification: DataNotification::default(),
+            scoped_data_notifications: Vec::new(),
             favorite_notification: FavoriteNotification::default(),
+            first_seen_notification: FirstSeenNotification::default(),
+            anomaly_notification: AnomalyNotification::default(),
+            scan_notification: ScanNotification::default(),
+            blocklist_notification: BlocklistNotification::default(),
+            desktop_notifications: false,
+            actions: NotificationActions::default(),
         }
     }
 }
@@ -29,6 +55,14 @@ pub enum Notification {
     Data(DataNotification),
     /// Favorites notification
     Favorite(FavoriteNotification),
+    /// Hosts, ASNs, or countries seen for the first time
+    FirstSeen(FirstSeenNotification),
+    /// Traffic deviating from the baseline
+    Anomaly(AnomalyNotification),
+    /// Remote hosts contacting many ports or local hosts
+    Scan(ScanNotification),
+    /// Hosts matching the blocklists
+    Blocklist(BlocklistNotification),
 }
 
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
@@ -43,6 +77,8 @@ pub struct DataNotification {
     pub sound: Sound,
     /// The last used Some value for the threshold field
     pub previous_threshold: u64,
+    /// Conditions to satisfy, besides exceeding the threshold, for a notification to be emitted
+    pub alert_conditions: AlertConditions,
 }
 
 impl Default for DataNotification {
@@ -53,6 +89,7 @@ fn default() -> Self {
             byte_multiple: ByteMultiple::KB,
             sound: Sound::Pop,
             previous_threshold: 800_000,
+            alert_conditions: AlertConditions::default(),
         }
     }
 }
@@ -100,6 +137,214 @@ pub fn from(value: &str, existing: Option<Self>) -> Self {
     }
 }
 
+/// Conditions making a data threshold behave like a monitoring alert, instead of notifying every second
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct AlertConditions {
+    /// Number of consecutive seconds the threshold must be exceeded for
+    pub sustained_seconds: u32,
+    /// Percentage of the threshold the traffic must drop to before notifying again
+    /// (`None` to notify again as soon as the other conditions are satisfied)
+    pub rearm_percent: Option<u8>,
+    /// Minimum number of seconds between two notifications
+    pub cooldown_seconds: u32,
+}
+
+impl Default for AlertConditions {
+    fn default() -> Self {
+        AlertConditions {
+            sustained_seconds: 1,
+            rearm_percent: None,
+            cooldown_seconds: 0,
+        }
+    }
+}
+
+/// Runtime state of a data threshold, needed to evaluate its alert conditions across seconds
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ThresholdState {
+    /// Consecutive seconds the threshold has been exceeded for
+    seconds_above: u32,
+    /// Whether the traffic has still to drop below the re-arm level after the last notification
+    disarmed: bool,
+    /// Seconds to wait before notifying again
+    cooldown_left: u32,
+}
+
+impl ThresholdState {
+    /// Updates the state with the data exchanged in the last second,
+    /// returning true if a notification has to be emitted
+    pub fn update(&mut self, threshold: u64, conditions: AlertConditions, data: u128) -> bool {
+        let threshold = u128::from(threshold);
+        self.cooldown_left = self.cooldown_left.saturating_sub(1);
+        if data > threshold {
+            self.seconds_above = self.seconds_above.saturating_add(1);
+        } else {
+            self.seconds_above = 0;
+        }
+        if self.disarmed
+            && conditions
+                .rearm_percent
+                .is_none_or(|percent| data <= threshold * u128::from(percent) / 100)
+        {
+            self.disarmed = false;
+        }
+        if !self.disarmed
+            && self.cooldown_left == 0
+            && self.seconds_above >= conditions.sustained_seconds.max(1)
+        {
+            self.seconds_above = 0;
+            self.disarmed = conditions.rearm_percent.is_some();
+            self.cooldown_left = conditions.cooldown_seconds;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// States of the global and scoped data thresholds
+#[derive(Clone, Default, Debug)]
+pub struct ThresholdStates {
+    pub global: ThresholdState,
+    /// States of the scoped thresholds, each one with the rule it refers to
+    scoped: Vec<(ScopedDataNotification, ThresholdState)>,
+}
+
+impl ThresholdStates {
+    /// Returns the states of the given scoped rules, in the same order.
+    ///
+    /// States are matched to the rules by their content,
+    /// so that added or edited rules start from a fresh state while the others keep their own.
+    pub fn scoped(
+        &mut self,
+        rules: &[ScopedDataNotification],
+    ) -> &mut [(ScopedDataNotification, ThresholdState)] {
+        if !self.scoped.iter().map(|(rule, _)| rule).eq(rules) {
+            let mut previous = std::mem::take(&mut self.scoped);
+            self.scoped = rules
+                .iter()
+                .map(|rule| {
+                    let state = previous
+                        .iter()
+                        .position(|(r, _)| r == rule)
+                        .map(|i| previous.swap_remove(i).1)
+                        .unwrap_or_default();
+                    (rule.clone(), state)
+                })
+                .collect();
+        }
+        &mut self.scoped
+    }
+}
+
+/// Runtime state needed to evaluate notifications across seconds
+#[derive(Clone, Default, Debug)]
+pub struct NotificationsState {
+    pub thresholds: ThresholdStates,
+    pub traffic_baseline: TrafficBaseline,
+    pub scan_detector: ScanDetector,
+}
+
+/// Data threshold restricted to the traffic of a specific host, service, ASN, or country
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Default)]
+pub struct ScopedDataNotification {
+    /// Traffic considered by this rule
+    pub scope: ThresholdScope,
+    /// Threshold of this rule (the rule is disabled if the threshold is `None`)
+    pub data_notification: DataNotification,
+}
+
+/// Traffic considered by a scoped data threshold
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+pub enum ThresholdScope {
+    /// Traffic exchanged with hosts having the given domain
+    Host(String),
+    /// Traffic of the given service (e.g., "ssh")
+    Service(String),
+    /// Traffic exchanged with hosts of the given Autonomous System (name or number)
+    Asn(String),
+    /// Traffic exchanged with hosts of the given country (ISO code)
+    Country(String),
+}
+
+impl Default for ThresholdScope {
+    fn default() -> Self {
+        ThresholdScope::Host(String::new())
+    }
+}
+
+impl ThresholdScope {
+    pub const ALL: [ThresholdScope; 4] = [
+        ThresholdScope::Host(String::new()),
+        ThresholdScope::Service(String::new()),
+        ThresholdScope::Asn(String::new()),
+        ThresholdScope::Country(String::new()),
+    ];
+
+    pub fn get_label(&self, language: Language) -> &'static str {
+        match self {
+            ThresholdScope::Host(_) => host_translation(language),
+            ThresholdScope::Service(_) => service_translation(language),
+            ThresholdScope::Asn(_) => "ASN",
+            ThresholdScope::Country(_) => country_translation(language),
+        }
+    }
+
+    /// The host, service, ASN, or country this scope refers to
+    pub fn value(&self) -> &str {
+        match self {
+            ThresholdScope::Host(value)
+            | ThresholdScope::Service(value)
+            | ThresholdScope::Asn(value)
+            | ThresholdScope::Country(value) => value,
+        }
+    }
+
+    /// Returns a scope of the same kind, referring to the given value
+    #[must_use]
+    pub fn with_value(&self, value: String) -> Self {
+        match self {
+            ThresholdScope::Host(_) => ThresholdScope::Host(value),
+            ThresholdScope::Service(_) => ThresholdScope::Service(value),
+            ThresholdScope::Asn(_) => ThresholdScope::Asn(value),
+            ThresholdScope::Country(_) => ThresholdScope::Country(value),
+        }
+    }
+
+    /// Whether this scope is of the same kind of another one, independently of the value
+    pub fn same_kind(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Whether the traffic exchanged with the given host is part of this scope
+    pub fn includes_host(&self, host: &Host) -> bool {
+        let value = self.value().trim();
+        match self {
+            ThresholdScope::Host(_) => host.domain.eq_ignore_ascii_case(value),
+            ThresholdScope::Service(_) => false,
+            ThresholdScope::Asn(_) => {
+                !host.asn.code.is_empty()
+                    && (host.asn.name.eq_ignore_ascii_case(value)
+                        || host.asn.code == value.trim_start_matches(['A', 'S', 'a', 's']))
+            }
+            ThresholdScope::Country(_) => {
+                host.country != Country::ZZ && host.country.to_string().eq_ignore_ascii_case(value)
+            }
+        }
+    }
+
+    /// Whether the traffic of the given service is part of this scope
+    pub fn includes_service(&self, service: &Service) -> bool {
+        match self {
+            ThresholdScope::Service(value) => {
+                !matches!(service, Service::Unknown | Service::NotApplicable)
+                    && service.to_string().eq_ignore_ascii_case(value.trim())
+            }
+            _ => false,
+        }
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
 pub struct FavoriteNotification {
     /// Flag to determine if this notification is enabled
@@ -135,11 +380,151 @@ pub fn off(sound: Sound) -> Self {
     }
 }
 
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct FirstSeenNotification {
+    /// Flag to determine if a notification is emitted when a host is seen for the first time
+    pub notify_on_new_host: bool,
+    /// How hosts are identified to determine if they are new
+    pub host_identity: HostIdentity,
+    /// Flag to determine if a notification is emitted when an ASN is seen for the first time
+    pub notify_on_new_asn: bool,
+    /// Flag to determine if a notification is emitted when a country is seen for the first time
+    pub notify_on_new_country: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for FirstSeenNotification {
+    fn default() -> Self {
+        FirstSeenNotification {
+            notify_on_new_host: false,
+            host_identity: HostIdentity::Host,
+            notify_on_new_asn: false,
+            notify_on_new_country: false,
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+impl FirstSeenNotification {
+    /// Whether at least one of the first seen notifications is in use
+    pub fn is_active(&self) -> bool {
+        self.notify_on_new_host || self.notify_on_new_asn || self.notify_on_new_country
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct AnomalyNotification {
+    /// Flag to determine if a notification is emitted when the traffic deviates from its baseline
+    pub notify_on_anomaly: bool,
+    /// How much the traffic has to deviate from its baseline to be anomalous
+    pub sensitivity: AnomalySensitivity,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for AnomalyNotification {
+    fn default() -> Self {
+        AnomalyNotification {
+            notify_on_anomaly: false,
+            sensitivity: AnomalySensitivity::Medium,
+            sound: Sound::Swhoosh,
+        }
+    }
+}
+
+/// Sensitivity of the anomaly detection
+#[derive(Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Debug)]
+pub enum AnomalySensitivity {
+    Low,
+    Medium,
+    High,
+}
+
+impl AnomalySensitivity {
+    pub(crate) const ALL: [AnomalySensitivity; 3] = [
+        AnomalySensitivity::Low,
+        AnomalySensitivity::Medium,
+        AnomalySensitivity::High,
+    ];
+
+    /// Minimum number of standard deviations from the average for the traffic to be anomalous
+    pub fn min_z_score(self) -> f64 {
+        match self {
+            AnomalySensitivity::Low => 5.0,
+            AnomalySensitivity::Medium => 4.0,
+            AnomalySensitivity::High => 3.0,
+        }
+    }
+
+    pub fn get_label(self, language: Language) -> &'static str {
+        match self {
+            AnomalySensitivity::Low => low_translation(language),
+            AnomalySensitivity::Medium => medium_translation(language),
+            AnomalySensitivity::High => high_translation(language),
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct ScanNotification {
+    /// Flag to determine if a notification is emitted when a remote host looks like it's scanning the network
+    pub notify_on_scan: bool,
+    /// Distinct ports a remote host has to contact in a short time to be reported (0 to ignore ports)
+    pub ports_threshold: u16,
+    /// Distinct local hosts a remote host has to contact in a short time to be reported (0 to ignore hosts)
+    pub hosts_threshold: u16,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for ScanNotification {
+    fn default() -> Self {
+        ScanNotification {
+            notify_on_scan: false,
+            ports_threshold: 25,
+            hosts_threshold: 10,
+            sound: Sound::Pop,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct BlocklistNotification {
+    /// Flag to determine if a notification is emitted when a host matching the blocklists is resolved
+    pub notify_on_blocklisted: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for BlocklistNotification {
+    fn default() -> Self {
+        BlocklistNotification {
+            notify_on_blocklisted: false,
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+/// Criterion used to determine if a host is seen for the first time
+#[derive(Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Debug)]
+pub enum HostIdentity {
+    /// Each new IP address is a new host
+    Address,
+    /// Addresses resolving to an already known domain, ASN, and country are not new hosts
+    Host,
+}
+
+impl HostIdentity {
+    pub const ALL: [HostIdentity; 2] = [HostIdentity::Address, HostIdentity::Host];
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::networking::types::asn::Asn;
 
     #[rstest]
     #[case("123",
@@ -221,4 +606,143 @@ fn test_can_instantiate_favourite_notification() {
             }
         );
     }
+
+    #[test]
+    fn test_threshold_scope_includes_host_and_service() {
+        let host = Host {
+            domain: "github.com".to_string(),
+            asn: Asn {
+                code: "36459".to_string(),
+                name: "GITHUB".to_string(),
+            },
+            country: Country::US,
+        };
+        assert!(ThresholdScope::Host("GitHub.com".to_string()).includes_host(&host));
+        assert!(!ThresholdScope::Host("api.github.com".to_string()).includes_host(&host));
+        assert!(ThresholdScope::Asn("github".to_string()).includes_host(&host));
+        assert!(ThresholdScope::Asn("AS36459".to_string()).includes_host(&host));
+        assert!(ThresholdScope::Asn("36459".to_string()).includes_host(&host));
+        assert!(!ThresholdScope::Asn(String::new()).includes_host(&Host::default()));
+        assert!(ThresholdScope::Country("us".to_string()).includes_host(&host));
+        assert!(!ThresholdScope::Country("IT".to_string()).includes_host(&host));
+        assert!(!ThresholdScope::Service("https".to_string()).includes_host(&host));
+
+        let ssh = Service::Name("ssh");
+        assert!(ThresholdScope::Service(" SSH ".to_string()).includes_service(&ssh));
+        assert!(!ThresholdScope::Service("https".to_string()).includes_service(&ssh));
+        assert!(!ThresholdScope::Service("?".to_string()).includes_service(&Service::Unknown));
+        assert!(!ThresholdScope::Host("ssh".to_string()).includes_service(&ssh));
+    }
+
+    #[test]
+    fn test_threshold_scope_with_value() {
+        let scope = ThresholdScope::Country("IT".to_string());
+        assert_eq!(
+            scope.with_value("FR".to_string()),
+            ThresholdScope::Country("FR".to_string())
+        );
+        assert!(scope.same_kind(&ThresholdScope::Country(String::new())));
+        assert!(!scope.same_kind(&ThresholdScope::Asn("IT".to_string())));
+        assert_eq!(scope.value(), "IT");
+    }
+
+    /// Feeds the given data to a new threshold state, returning the seconds in which a notification is emitted
+    fn fired_seconds(conditions: AlertConditions, data: &[u128]) -> Vec<usize> {
+        let mut state = ThresholdState::default();
+        data.iter()
+            .enumerate()
+            .filter(|(_, d)| state.update(100, conditions, **d))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn test_threshold_state_default_conditions() {
+        // every second above the threshold is notified
+        assert_eq!(
+            fired_seconds(AlertConditions::default(), &[50, 150, 150, 100, 101]),
+            vec![1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn test_threshold_state_sustained_rate() {
+        let conditions = AlertConditions {
+            sustained_seconds: 3,
+            ..AlertConditions::default()
+        };
+        assert_eq!(
+            fired_seconds(conditions, &[150, 150, 50, 150, 150, 150, 150, 150, 150]),
+            vec![5, 8]
+        );
+    }
+
+    #[test]
+    fn test_threshold_state_rearm_level() {
+        let conditions = AlertConditions {
+            rearm_percent: Some(50),
+            ..AlertConditions::default()
+        };
+        // dropping slightly below the threshold isn't enough to notify again
+        assert_eq!(
+            fired_seconds(conditions, &[150, 150, 80, 150, 50, 120, 150]),
+            vec![0, 5]
+        );
+    }
+
+    #[test]
+    fn test_threshold_state_cooldown() {
+        let conditions = AlertConditions {
+            cooldown_seconds: 3,
+            ..AlertConditions::default()
+        };
+        assert_eq!(
+            fired_seconds(conditions, &[150, 150, 150, 150, 150, 0, 0, 150]),
+            vec![0, 3, 7]
+        );
+    }
+
+    #[test]
+    fn test_scoped_threshold_states_follow_rules() {
+        let rule = |scope| ScopedDataNotification {
+            scope,
+            data_notification: DataNotification {
+                threshold: Some(100),
+                alert_conditions: AlertConditions {
+                    sustained_seconds: 2,
+                    ..AlertConditions::default()
+                },
+                ..DataNotification::default()
+            },
+        };
+        let host = rule(ThresholdScope::Host("example.com".to_string()));
+        let ssh = rule(ThresholdScope::Service("ssh".to_string()));
+        let mut states = ThresholdStates::default();
+        let tick = |states: &mut ThresholdStates, rules: &[ScopedDataNotification]| {
+            states
+                .scoped(rules)
+                .iter_mut()
+                .map(|(rule, state)| {
+                    let conditions = rule.data_notification.alert_conditions;
+                    state.update(100, conditions, 150)
+                })
+                .collect::<Vec<bool>>()
+        };
+
+        assert_eq!(
+            tick(&mut states, &[host.clone(), ssh.clone()]),
+            [false, false]
+        );
+        // removing a rule doesn't affect the state of the following ones
+        assert_eq!(tick(&mut states, std::slice::from_ref(&ssh)), [true]);
+        // added rules start from a fresh state
+        assert_eq!(
+            tick(&mut states, &[host.clone(), ssh.clone()]),
+            [false, false]
+        );
+        // and so do edited ones
+        let mut edited = ssh.clone();
+        edited.scope = ThresholdScope::Service("https".to_string());
+        assert_eq!(tick(&mut states, &[host, edited]), [true, false]);
+    }
 }
@@ -0,0 +1,392 @@
+//! Module defining the `NotificationsHistory` struct, an append-only log of the emitted notifications
+//! which persists across sessions.
+
+use std::fs::{File, OpenOptions, create_dir_all, rename};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::notifications::types::logged_notification::{LoggedNotification, NotificationKind};
+use crate::translations::types::language::Language;
+
+/// Number of history entries displayed per page
+pub const HISTORY_PAGE_SIZE: usize = 30;
+
+/// Log of all the notifications emitted during live captures, stored as one JSON object per line
+#[derive(Default)]
+pub struct NotificationsHistory {
+    /// Path of the log file; `None` if the history isn't persisted
+    path: Option<PathBuf>,
+    /// Logged entries (oldest first), only read from file while the history is being browsed
+    entries: Option<Vec<LoggedNotification>>,
+}
+
+impl NotificationsHistory {
+    const FILE_NAME: &'static str = "notifications_history";
+    /// Size of the log file after which it's rotated:
+    /// only the current and the previous file are kept, so that `load` reads a bounded amount of data
+    const MAX_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+    /// Returns the history stored at the given path, or a history which isn't persisted if `None`
+    pub fn from_path(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            entries: None,
+        }
+    }
+
+    /// Path of the history in the configuration directory
+    pub fn default_path() -> Option<PathBuf> {
+        confy::get_configuration_file_path(crate::SNIFFNET_LOWERCASE, Self::FILE_NAME)
+            .ok()
+            .map(|mut path| {
+                path.set_extension("jsonl");
+                path
+            })
+    }
+
+    /// Path of the previous log file, replaced at every rotation
+    fn rotated_path(path: &Path) -> PathBuf {
+        path.with_extension("old.jsonl")
+    }
+
+    /// Appends the given notifications, ordered from the oldest to the most recent
+    pub fn append<'a>(&mut self, notifications: impl Iterator<Item = &'a LoggedNotification>) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let mut lines = String::new();
+        for notification in notifications {
+            let Ok(line) = serde_json::to_string(notification) else {
+                continue;
+            };
+            lines.push_str(&line);
+            lines.push('\n');
+            if let Some(entries) = self.entries.as_mut() {
+                entries.push(notification.clone());
+            }
+        }
+        if lines.is_empty() {
+            return;
+        }
+        if let Some(parent) = path.parent() {
+            let _ = create_dir_all(parent);
+        }
+        let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) else {
+            return;
+        };
+        let _ = file.write_all(lines.as_bytes());
+        if file
+            .metadata()
+            .is_ok_and(|metadata| metadata.len() > Self::MAX_FILE_SIZE)
+        {
+            drop(file);
+            let _ = rename(path, Self::rotated_path(path));
+        }
+    }
+
+    /// Reads the logged entries, so that they can be browsed
+    pub fn load(&mut self) {
+        let mut entries = Vec::new();
+        if let Some(path) = &self.path {
+            for path in [Self::rotated_path(path), path.clone()] {
+                let Ok(file) = File::open(path) else {
+                    continue;
+                };
+                entries.extend(
+                    BufReader::new(file)
+                        .lines()
+                        .map_while(Result::ok)
+                        // skip lines which can't be parsed (e.g., truncated writes)
+                        .filter_map(|line| serde_json::from_str(&line).ok()),
+                );
+            }
+        }
+        self.entries = Some(entries);
+    }
+
+    /// Drops the entries read from file
+    pub fn unload(&mut self) {
+        self.entries = None;
+    }
+
+    /// Whether the history is being browsed
+    pub fn is_loaded(&self) -> bool {
+        self.entries.is_some()
+    }
+
+    /// Returns the entries matching the given filter (most recent first), and the total number of matches
+    pub fn search(
+        &self,
+        filter: &HistoryFilter,
+        language: Language,
+    ) -> (Vec<&LoggedNotification>, usize) {
+        let matching: Vec<&LoggedNotification> = self
+            .entries
+            .iter()
+            .flatten()
+            .rev()
+            .filter(|n| filter.matches(n, language))
+            .collect();
+        let tot = matching.len();
+        let page_entries = matching
+            .into_iter()
+            .skip(filter.page * HISTORY_PAGE_SIZE)
+            .take(HISTORY_PAGE_SIZE)
+            .collect();
+        (page_entries, tot)
+    }
+}
+
+/// Criteria used to browse the notifications history
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HistoryFilter {
+    /// Text contained in the notification title or description (case insensitive)
+    pub text: String,
+    /// Kind of notification; `None` for all kinds
+    pub kind: Option<NotificationKind>,
+    /// First day to consider, in the format YYYY/MM/DD (or a prefix of it)
+    pub from: String,
+    /// Last day to consider, in the format YYYY/MM/DD (or a prefix of it)
+    pub to: String,
+    /// Displayed page of results, starting from 0
+    pub page: usize,
+}
+
+impl HistoryFilter {
+    fn matches(&self, notification: &LoggedNotification, language: Language) -> bool {
+        if self.kind.is_some_and(|kind| kind != notification.kind()) {
+            return false;
+        }
+
+        // timestamps are formatted as YYYY/MM/DD hh:mm:ss, so they can be compared as strings
+        let timestamp = notification.timestamp();
+        let date = timestamp.get(..10).unwrap_or(timestamp);
+        let from = self.from.trim();
+        if !from.is_empty() && date < from {
+            return false;
+        }
+        let to = self.to.trim();
+        if !to.is_empty() && date > to && !date.starts_with(to) {
+            return false;
+        }
+
+        let text = self.text.trim().to_lowercase();
+        text.is_empty()
+            || notification.title(language).to_lowercase().contains(&text)
+            || notification
+                .description(language)
+                .to_lowercase()
+                .contains(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::remove_file;
+    use std::net::IpAddr;
+
+    use super::*;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::host::Host;
+    use crate::notifications::types::logged_notification::{FavoriteTransmitted, FirstSeen};
+
+    fn favorite(id: usize, domain: &str, timestamp: &str) -> LoggedNotification {
+        LoggedNotification::FavoriteTransmitted(FavoriteTransmitted {
+            id,
+            host: Host {
+                domain: domain.to_string(),
+                ..Host::default()
+            },
+            data_info_host: DataInfoHost::default(),
+            timestamp: timestamp.to_string(),
+        })
+    }
+
+    fn new_host(id: usize, domain: &str, timestamp: &str) -> LoggedNotification {
+        LoggedNotification::NewHost(FirstSeen {
+            id,
+            host: Host {
+                domain: domain.to_string(),
+                ..Host::default()
+            },
+            address: IpAddr::from([192, 168, 1, 1]),
+            data_info_host: DataInfoHost::default(),
+            timestamp: timestamp.to_string(),
+        })
+    }
+
+    fn ids(entries: &[&LoggedNotification]) -> Vec<usize> {
+        entries.iter().map(|n| n.id()).collect()
+    }
+
+    #[test]
+    fn test_history_persists_across_instances() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_history_test_{}.jsonl",
+            std::process::id()
+        ));
+        let _ = remove_file(&path);
+
+        let mut history = NotificationsHistory::from_path(Some(path.clone()));
+        history.append(
+            [
+                favorite(1, "one.one", "2025/07/01 10:00:00"),
+                new_host(2, "dns.google", "2025/07/02 11:00:00"),
+            ]
+            .iter(),
+        );
+        history.load();
+        // appended entries are visible while browsing
+        history.append([favorite(3, "github.com", "2025/07/03 12:00:00")].iter());
+        let (entries, tot) = history.search(&HistoryFilter::default(), Language::EN);
+        assert_eq!(tot, 3);
+        assert_eq!(ids(&entries), vec![3, 2, 1]);
+
+        // a new instance, as after a restart, reads the same history
+        let mut restarted = NotificationsHistory::from_path(Some(path.clone()));
+        assert_eq!(
+            restarted.search(&HistoryFilter::default(), Language::EN).1,
+            0
+        );
+        restarted.load();
+        let (entries, _) = restarted.search(&HistoryFilter::default(), Language::EN);
+        assert_eq!(ids(&entries), vec![3, 2, 1]);
+
+        remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_history_skips_corrupted_lines() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_history_corrupted_test_{}.jsonl",
+            std::process::id()
+        ));
+        std::fs::write(&path, "{\"NewHost\":{\"id\":\n").unwrap();
+        let mut history = NotificationsHistory::from_path(Some(path.clone()));
+        history.append([favorite(1, "one.one", "2025/07/01 10:00:00")].iter());
+        history.load();
+        let (entries, _) = history.search(&HistoryFilter::default(), Language::EN);
+        assert_eq!(ids(&entries), vec![1]);
+        remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_history_rotation() {
+        let path = std::env::temp_dir().join(format!(
+            "sniffnet_history_rotation_test_{}.jsonl",
+            std::process::id()
+        ));
+        let rotated_path = NotificationsHistory::rotated_path(&path);
+        assert_eq!(
+            rotated_path.file_name().unwrap().to_string_lossy(),
+            format!(
+                "sniffnet_history_rotation_test_{}.old.jsonl",
+                std::process::id()
+            )
+        );
+        let _ = remove_file(&rotated_path);
+        // a file just below the maximum size, whose lines are read anyway
+        let line = serde_json::to_string(&favorite(1, "one.one", "2025/07/01 10:00:00")).unwrap();
+        let n = usize::try_from(NotificationsHistory::MAX_FILE_SIZE).unwrap() / (line.len() + 1);
+        std::fs::write(&path, format!("{line}\n").repeat(n)).unwrap();
+
+        let mut history = NotificationsHistory::from_path(Some(path.clone()));
+        history.append([favorite(2, "one.one", "2025/07/01 10:00:01")].iter());
+        assert!(!path.exists());
+        assert!(rotated_path.exists());
+        history.append([favorite(3, "one.one", "2025/07/01 10:00:02")].iter());
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+        // both the files are read, oldest first
+        history.load();
+        let (entries, tot) = history.search(&HistoryFilter::default(), Language::EN);
+        assert_eq!(tot, n + 2);
+        assert_eq!(ids(&entries[..3]), vec![3, 2, 1]);
+
+        remove_file(&path).unwrap();
+        remove_file(&rotated_path).unwrap();
+    }
+
+    #[test]
+    fn test_history_search() {
+        let history = NotificationsHistory {
+            path: None,
+            entries: Some(vec![
+                favorite(1, "one.one", "2025/06/30 10:00:00"),
+                new_host(2, "GitHub.io", "2025/07/02 11:00:00"),
+                favorite(3, "github.com", "2025/07/15 12:00:00"),
+                new_host(4, "dns.google", "2025/08/01 09:00:00"),
+            ]),
+        };
+        let search = |filter: HistoryFilter| ids(&history.search(&filter, Language::EN).0);
+
+        assert_eq!(
+            search(HistoryFilter {
+                kind: Some(NotificationKind::NewHost),
+                ..HistoryFilter::default()
+            }),
+            vec![4, 2]
+        );
+        assert_eq!(
+            search(HistoryFilter {
+                text: " github ".to_string(),
+                ..HistoryFilter::default()
+            }),
+            vec![3, 2]
+        );
+        // the title is searched as well
+        assert_eq!(
+            search(HistoryFilter {
+                text: "favorites".to_string(),
+                ..HistoryFilter::default()
+            }),
+            vec![3, 1]
+        );
+        assert_eq!(
+            search(HistoryFilter {
+                from: "2025/07/02".to_string(),
+                to: "2025/07".to_string(),
+                ..HistoryFilter::default()
+            }),
+            vec![3, 2]
+        );
+        assert_eq!(
+            search(HistoryFilter {
+                to: "2025/07/01".to_string(),
+                ..HistoryFilter::default()
+            }),
+            vec![1]
+        );
+        assert!(
+            search(HistoryFilter {
+                page: 1,
+                ..HistoryFilter::default()
+            })
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_history_pages() {
+        let history = NotificationsHistory {
+            path: None,
+            entries: Some(
+                (1..=HISTORY_PAGE_SIZE + 5)
+                    .map(|id| favorite(id, "one.one", "2025/07/01 10:00:00"))
+                    .collect(),
+            ),
+        };
+        let (first_page, tot) = history.search(&HistoryFilter::default(), Language::EN);
+        assert_eq!(tot, HISTORY_PAGE_SIZE + 5);
+        assert_eq!(first_page.len(), HISTORY_PAGE_SIZE);
+        assert_eq!(first_page[0].id(), HISTORY_PAGE_SIZE + 5);
+        let (second_page, _) = history.search(
+            &HistoryFilter {
+                page: 1,
+                ..HistoryFilter::default()
+            },
+            Language::EN,
+        );
+        assert_eq!(ids(&second_page), vec![5, 4, 3, 2, 1]);
+    }
+}
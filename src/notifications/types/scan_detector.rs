@@ -0,0 +1,288 @@
+//! Module defining the `ScanDetector` struct, which looks for remote hosts contacting
+//! many distinct ports or local hosts in a short time (e.g., port scans and host sweeps).
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::net::IpAddr;
+
+use crate::Protocol;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::icmp_type::{IcmpType, IcmpTypeV4, IcmpTypeV6};
+use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::info_traffic::InfoTraffic;
+use crate::networking::types::traffic_direction::TrafficDirection;
+
+/// Number of seconds in which the contacts of a remote host are counted
+pub const SCAN_WINDOW: usize = 10;
+/// Seconds during which a remote host isn't reported again after a scan
+pub const SCAN_COOLDOWN: u32 = 60;
+
+/// Remote host which contacted too many ports or local hosts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scan {
+    /// Address of the remote host
+    pub address: IpAddr,
+    /// Distinct ports contacted, sorted
+    pub ports: Vec<u16>,
+    /// Distinct local hosts contacted, sorted
+    pub hosts: Vec<IpAddr>,
+}
+
+/// Local endpoint contacted by a remote host
+type Contact = (IpAddr, Option<u16>);
+
+/// Tracker of the endpoints contacted by each remote host in the last seconds
+#[derive(Clone, Default, Debug)]
+pub struct ScanDetector {
+    /// Contacts observed in each of the last seconds (most recent last)
+    window: VecDeque<HashMap<IpAddr, HashSet<Contact>>>,
+    /// Remote hosts recently reported, with the seconds left before they can be reported again
+    cooldowns: HashMap<IpAddr, u32>,
+}
+
+impl ScanDetector {
+    /// Adds the connections started in the last second by remote hosts, returning the remote hosts
+    /// which contacted at least `ports_threshold` distinct ports or `hosts_threshold` distinct local hosts
+    /// in the last [`SCAN_WINDOW`] seconds.
+    ///
+    /// A threshold equal to 0 is disabled.
+    pub fn update(
+        &mut self,
+        info_traffic_msg: &InfoTraffic,
+        ports_threshold: u16,
+        hosts_threshold: u16,
+    ) -> Vec<Scan> {
+        self.cooldowns.retain(|_, left| {
+            *left = left.saturating_sub(1);
+            *left > 0
+        });
+
+        let mut last_second: HashMap<IpAddr, HashSet<Contact>> = HashMap::new();
+        for (key, info) in &info_traffic_msg.map {
+            if is_contact(key, info, &info_traffic_msg.map) {
+                last_second
+                    .entry(key.address1)
+                    .or_default()
+                    .insert((key.address2, key.port2));
+            }
+        }
+        if self.window.len() >= SCAN_WINDOW {
+            self.window.pop_front();
+        }
+
+        let mut scans = Vec::new();
+        for address in last_second.keys() {
+            if self.cooldowns.contains_key(address) {
+                continue;
+            }
+            let mut ports = BTreeSet::new();
+            let mut hosts = BTreeSet::new();
+            for (host, port) in self
+                .window
+                .iter()
+                .chain([&last_second])
+                .filter_map(|second| second.get(address))
+                .flatten()
+            {
+                hosts.insert(*host);
+                if let Some(port) = port {
+                    ports.insert(*port);
+                }
+            }
+            let exceeds =
+                |count: usize, threshold: u16| threshold > 0 && count >= usize::from(threshold);
+            if exceeds(ports.len(), ports_threshold) || exceeds(hosts.len(), hosts_threshold) {
+                self.cooldowns.insert(*address, SCAN_COOLDOWN);
+                scans.push(Scan {
+                    address: *address,
+                    ports: ports.into_iter().collect(),
+                    hosts: hosts.into_iter().collect(),
+                });
+            }
+        }
+        self.window.push_back(last_second);
+
+        scans.sort_by_key(|scan| scan.address);
+        scans
+    }
+}
+
+/// Whether the given entry represents a remote host trying to reach a local endpoint,
+/// rather than the reply to a request sent from here
+fn is_contact(
+    key: &AddressPortPair,
+    info: &InfoAddressPortPair,
+    map: &HashMap<AddressPortPair, InfoAddressPortPair>,
+) -> bool {
+    if info.traffic_direction != TrafficDirection::Incoming {
+        return false;
+    }
+    match key.protocol {
+        // connection requests
+        Protocol::TCP => info.tcp_flags.syn > 0,
+        // echo requests
+        Protocol::ICMP => info.icmp_types.keys().any(|icmp_type| {
+            matches!(
+                icmp_type,
+                IcmpType::V4(IcmpTypeV4::Echo) | IcmpType::V6(IcmpTypeV6::EchoRequest)
+            )
+        }),
+        // datagrams not answering to one sent from here in the same interval
+        Protocol::UDP => !map.contains_key(&AddressPortPair {
+            address1: key.address2,
+            port1: key.port2,
+            address2: key.address1,
+            port2: key.port1,
+            protocol: key.protocol,
+        }),
+        Protocol::ARP => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networking::types::tcp_flags::TcpFlagsCount;
+
+    const SCANNER: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(203, 0, 113, 7));
+
+    fn key(
+        source: IpAddr,
+        source_port: Option<u16>,
+        destination: IpAddr,
+        destination_port: Option<u16>,
+        protocol: Protocol,
+    ) -> AddressPortPair {
+        AddressPortPair {
+            address1: source,
+            port1: source_port,
+            address2: destination,
+            port2: destination_port,
+            protocol,
+        }
+    }
+
+    fn incoming_syn() -> InfoAddressPortPair {
+        InfoAddressPortPair {
+            traffic_direction: TrafficDirection::Incoming,
+            tcp_flags: TcpFlagsCount {
+                syn: 1,
+                ..TcpFlagsCount::default()
+            },
+            ..InfoAddressPortPair::default()
+        }
+    }
+
+    fn local(last_octet: u8) -> IpAddr {
+        IpAddr::from([192, 168, 1, last_octet])
+    }
+
+    /// Traffic of one second where the scanner sends a SYN to each of the given ports of a local host
+    fn syn_to_ports(host: IpAddr, ports: impl Iterator<Item = u16>) -> InfoTraffic {
+        let mut info_traffic = InfoTraffic::default();
+        for port in ports {
+            info_traffic.map.insert(
+                key(SCANNER, Some(40_000), host, Some(port), Protocol::TCP),
+                incoming_syn(),
+            );
+        }
+        info_traffic
+    }
+
+    #[test]
+    fn test_port_scan_across_seconds() {
+        let mut detector = ScanDetector::default();
+        // 15 ports per second: 30 distinct ports in 2 seconds
+        assert!(
+            detector
+                .update(&syn_to_ports(local(2), 1..=15), 25, 0)
+                .is_empty()
+        );
+        let scans = detector.update(&syn_to_ports(local(2), 16..=30), 25, 0);
+        assert_eq!(
+            scans,
+            vec![Scan {
+                address: SCANNER,
+                ports: (1..=30).collect(),
+                hosts: vec![local(2)],
+            }]
+        );
+        // the same scanner isn't reported again while in cooldown
+        assert!(
+            detector
+                .update(&syn_to_ports(local(2), 31..=60), 25, 0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_contacts_outside_the_window_are_forgotten() {
+        let mut detector = ScanDetector::default();
+        assert!(
+            detector
+                .update(&syn_to_ports(local(2), 1..=15), 25, 0)
+                .is_empty()
+        );
+        for _ in 0..SCAN_WINDOW {
+            assert!(detector.update(&InfoTraffic::default(), 25, 0).is_empty());
+        }
+        assert!(
+            detector
+                .update(&syn_to_ports(local(2), 16..=30), 25, 0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_host_sweep() {
+        let mut detector = ScanDetector::default();
+        let mut info_traffic = InfoTraffic::default();
+        for last_octet in 1..=12 {
+            let mut echo_request = InfoAddressPortPair::default();
+            echo_request
+                .icmp_types
+                .insert(IcmpType::V4(IcmpTypeV4::Echo), 1);
+            info_traffic.map.insert(
+                key(SCANNER, None, local(last_octet), None, Protocol::ICMP),
+                echo_request,
+            );
+        }
+        assert!(detector.update(&info_traffic, 25, 0).is_empty());
+        let scans = detector.update(&info_traffic, 25, 10);
+        assert_eq!(scans.len(), 1);
+        assert!(scans[0].ports.is_empty());
+        assert_eq!(scans[0].hosts.len(), 12);
+    }
+
+    #[test]
+    fn test_replies_are_not_contacts() {
+        let mut detector = ScanDetector::default();
+        let dns_server = IpAddr::from([9, 9, 9, 9]);
+        let mut info_traffic = InfoTraffic::default();
+        for port in 50_000..50_040 {
+            // DNS queries sent from here and their replies
+            info_traffic.map.insert(
+                key(local(2), Some(port), dns_server, Some(53), Protocol::UDP),
+                InfoAddressPortPair {
+                    traffic_direction: TrafficDirection::Outgoing,
+                    ..InfoAddressPortPair::default()
+                },
+            );
+            info_traffic.map.insert(
+                key(dns_server, Some(53), local(2), Some(port), Protocol::UDP),
+                InfoAddressPortPair::default(),
+            );
+            // TCP segments of connections opened from here
+            info_traffic.map.insert(
+                key(SCANNER, Some(443), local(2), Some(port), Protocol::TCP),
+                InfoAddressPortPair {
+                    tcp_flags: TcpFlagsCount {
+                        syn_ack: 1,
+                        ..TcpFlagsCount::default()
+                    },
+                    ..InfoAddressPortPair::default()
+                },
+            );
+        }
+        assert!(detector.update(&info_traffic, 10, 10).is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+//! Module defining the `TrafficBaseline` struct, which learns the usual traffic volumes
+//! in order to detect anomalies.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::chart::types::chart_type::ChartType;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::info_traffic::InfoTraffic;
+use crate::networking::types::service::Service;
+
+/// Number of seconds considered to compute the baseline
+pub const BASELINE_WINDOW: usize = 300;
+/// Minimum number of seconds to observe before detecting anomalies
+pub const MIN_BASELINE_SAMPLES: usize = 60;
+/// Seconds during which a series isn't checked again after an anomaly
+pub const ANOMALY_COOLDOWN: u32 = 60;
+
+/// Traffic anomaly detected with respect to the baseline
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anomaly {
+    /// Service whose traffic is anomalous (`None` for the whole traffic)
+    pub service: Option<Service>,
+    /// Data exchanged in the last second
+    pub data_info: DataInfo,
+    /// Average bytes per second in the baseline window
+    pub baseline_bytes: u128,
+    /// Number of standard deviations of the last second from the average
+    pub z_score: f64,
+}
+
+/// Rolling statistics of a per-second series of values
+#[derive(Clone, Default, Debug)]
+struct RollingStats {
+    samples: VecDeque<f64>,
+    sum: f64,
+    sum_squares: f64,
+    /// Seconds to wait before reporting another anomaly for this series
+    cooldown_left: u32,
+}
+
+impl RollingStats {
+    fn push(&mut self, value: f64) {
+        if self.samples.len() >= BASELINE_WINDOW
+            && let Some(old) = self.samples.pop_front()
+        {
+            self.sum -= old;
+            self.sum_squares -= old * old;
+        }
+        self.samples.push_back(value);
+        self.sum += value;
+        self.sum_squares += value * value;
+    }
+
+    /// Returns the z-score of the given value with respect to the current samples,
+    /// or `None` if the samples are not enough to tell
+    fn z_score(&self, value: f64) -> Option<f64> {
+        if self.samples.len() < MIN_BASELINE_SAMPLES {
+            return None;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let n = self.samples.len() as f64;
+        let mean = self.sum / n;
+        let variance = (self.sum_squares / n - mean * mean).max(0.0);
+        // avoid flagging tiny fluctuations of very stable series
+        let std_dev = variance.sqrt().max(mean * 0.1).max(1.0);
+        Some((value - mean) / std_dev)
+    }
+
+    fn mean(&self) -> f64 {
+        if self.samples.is_empty() {
+            0.0
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            let n = self.samples.len() as f64;
+            self.sum / n
+        }
+    }
+
+    /// Checks if the value is anomalous, then adds it to the samples
+    fn check_and_push(&mut self, value: f64, min_z_score: f64, detect_drops: bool) -> Option<f64> {
+        self.cooldown_left = self.cooldown_left.saturating_sub(1);
+        let anomalous_z_score = self.z_score(value).filter(|z| {
+            self.cooldown_left == 0 && (*z >= min_z_score || (detect_drops && *z <= -min_z_score))
+        });
+        if anomalous_z_score.is_some() {
+            self.cooldown_left = ANOMALY_COOLDOWN;
+        }
+        self.push(value);
+        anomalous_z_score
+    }
+
+    fn is_idle(&self) -> bool {
+        self.sum <= 0.0
+    }
+}
+
+/// Baseline of the bytes exchanged per second, for the whole traffic and for each service
+#[derive(Clone, Default, Debug)]
+pub struct TrafficBaseline {
+    total: RollingStats,
+    services: HashMap<Service, RollingStats>,
+}
+
+impl TrafficBaseline {
+    /// Compares the traffic of the last second with the baseline, and then adds it to the baseline.
+    ///
+    /// Drops are only reported for the whole traffic,
+    /// since it's normal for single services to become idle.
+    pub fn update(&mut self, info_traffic_msg: &InfoTraffic, min_z_score: f64) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+
+        let total_bytes = info_traffic_msg.tot_data_info.tot_data(ChartType::Bytes);
+        let baseline_bytes = self.total.mean();
+        #[allow(clippy::cast_precision_loss)]
+        if let Some(z_score) = self
+            .total
+            .check_and_push(total_bytes as f64, min_z_score, true)
+        {
+            anomalies.push(Anomaly {
+                service: None,
+                data_info: info_traffic_msg.tot_data_info,
+                baseline_bytes: to_bytes(baseline_bytes),
+                z_score,
+            });
+        }
+
+        // services not observed in the last second exchanged no data
+        for (service, stats) in &mut self.services {
+            if !info_traffic_msg.services.contains_key(service) {
+                stats.check_and_push(0.0, min_z_score, false);
+            }
+        }
+        for (service, data_info) in &info_traffic_msg.services {
+            if !matches!(service, Service::Name(_)) {
+                continue;
+            }
+            let bytes = data_info.tot_data(ChartType::Bytes);
+            let stats = self.services.entry(*service).or_default();
+            let baseline_bytes = stats.mean();
+            #[allow(clippy::cast_precision_loss)]
+            if let Some(z_score) = stats.check_and_push(bytes as f64, min_z_score, false) {
+                anomalies.push(Anomaly {
+                    service: Some(*service),
+                    data_info: *data_info,
+                    baseline_bytes: to_bytes(baseline_bytes),
+                    z_score,
+                });
+            }
+        }
+        self.services.retain(|_, stats| !stats.is_idle());
+
+        anomalies
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_bytes(value: f64) -> u128 {
+    value.round().max(0.0) as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info_traffic(total_bytes: u128, services: &[(&'static str, u128)]) -> InfoTraffic {
+        let mut info_traffic = InfoTraffic {
+            tot_data_info: DataInfo::new_for_tests(1, 0, total_bytes, 0),
+            ..InfoTraffic::default()
+        };
+        for (name, bytes) in services {
+            info_traffic.services.insert(
+                Service::Name(name),
+                DataInfo::new_for_tests(1, 0, *bytes, 0),
+            );
+        }
+        info_traffic
+    }
+
+    /// Feeds the baseline with a steady traffic oscillating between 900 and 1100 bytes per second
+    fn learn(baseline: &mut TrafficBaseline, seconds: usize) {
+        for i in 0..seconds {
+            let bytes = if i % 2 == 0 { 900 } else { 1_100 };
+            assert!(
+                baseline
+                    .update(&info_traffic(bytes, &[("https", bytes)]), 3.0)
+                    .is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_anomalies_while_learning() {
+        let mut baseline = TrafficBaseline::default();
+        learn(&mut baseline, MIN_BASELINE_SAMPLES - 1);
+        assert!(
+            baseline
+                .update(&info_traffic(1_000_000, &[]), 3.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_spike_is_detected_once() {
+        let mut baseline = TrafficBaseline::default();
+        learn(&mut baseline, MIN_BASELINE_SAMPLES);
+
+        let anomalies = baseline.update(&info_traffic(50_000, &[("https", 50_000)]), 3.0);
+        assert_eq!(anomalies.len(), 2);
+        assert_eq!(anomalies[0].service, None);
+        assert_eq!(anomalies[0].data_info.tot_data(ChartType::Bytes), 50_000);
+        assert_eq!(anomalies[0].baseline_bytes, 1_000);
+        assert!(anomalies[0].z_score > 3.0);
+        assert_eq!(anomalies[1].service, Some(Service::Name("https")));
+
+        // the following seconds are in cooldown
+        assert!(
+            baseline
+                .update(&info_traffic(50_000, &[("https", 50_000)]), 3.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_drops_only_for_total_traffic() {
+        let mut baseline = TrafficBaseline::default();
+        learn(&mut baseline, MIN_BASELINE_SAMPLES);
+
+        let anomalies = baseline.update(&info_traffic(0, &[]), 3.0);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].service, None);
+        assert!(anomalies[0].z_score < -3.0);
+    }
+
+    #[test]
+    fn test_sensitivity() {
+        let mut baseline = TrafficBaseline::default();
+        learn(&mut baseline, MIN_BASELINE_SAMPLES);
+        // 1500 bytes are 5 standard deviations above the average
+        assert!(
+            baseline
+                .update(&info_traffic(1_500, &[("https", 1_000)]), 6.0)
+                .is_empty()
+        );
+        let anomalies = baseline.update(&info_traffic(1_500, &[("https", 1_000)]), 4.0);
+        assert_eq!(anomalies.len(), 1);
+    }
+
+    #[test]
+    fn test_new_services_and_idle_services() {
+        let mut baseline = TrafficBaseline::default();
+        learn(&mut baseline, MIN_BASELINE_SAMPLES);
+        // a service never seen before has no baseline yet
+        assert!(
+            baseline
+                .update(&info_traffic(1_000, &[("ssh", 1_000)]), 3.0)
+                .is_empty()
+        );
+        assert!(baseline.services.contains_key(&Service::Name("ssh")));
+
+        // services without traffic in the whole window are forgotten
+        for _ in 0..BASELINE_WINDOW {
+            baseline.update(&info_traffic(1_000, &[("https", 1_000)]), 3.0);
+        }
+        assert!(!baseline.services.contains_key(&Service::Name("ssh")));
+    }
+}
@@ -6,6 +6,7 @@
 use crate::networking::types::data_info_host::DataInfoHost;
 use crate::networking::types::host::Host;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::report::traffic_report::ConnectionRecord;
 use crate::report::types::sort_type::SortType;
 use crate::{ChartType, InfoTraffic, ReportSortType, Service, Sniffer};
 
@@ -15,6 +16,44 @@
 pub fn get_searched_entries(
     sniffer: &Sniffer,
 ) -> (Vec<(AddressPortPair, InfoAddressPortPair)>, usize, DataInfo) {
+    let (all_results, agglomerate) = get_all_searched_entries(sniffer);
+
+    let upper_bound = min(sniffer.page_number * 20, all_results.len());
+
+    (
+        all_results
+            .get((sniffer.page_number.saturating_sub(1)) * 20..upper_bound)
+            .unwrap_or_default()
+            .iter()
+            .map(|&(key, val)| (key.to_owned(), val.to_owned()))
+            .collect(),
+        all_results.len(),
+        agglomerate,
+    )
+}
+
+/// Return all the elements that satisfy the search constraints (regardless of the current page),
+/// together with their resolved host
+pub fn get_searched_connection_records(sniffer: &Sniffer) -> Vec<ConnectionRecord> {
+    get_all_searched_entries(sniffer)
+        .0
+        .into_iter()
+        .map(|(key, val)| {
+            let address_to_lookup = get_address_to_lookup(key, val.traffic_direction);
+            let host = sniffer
+                .addresses_resolved
+                .get(&address_to_lookup)
+                .map(|(_, host)| host);
+            ConnectionRecord::new(key, val, host)
+        })
+        .collect()
+}
+
+/// Return the sorted elements that satisfy the search constraints,
+/// with their packets, in-bytes, and out-bytes count
+fn get_all_searched_entries(
+    sniffer: &Sniffer,
+) -> (Vec<(&AddressPortPair, &InfoAddressPortPair)>, DataInfo) {
     let mut agglomerate = DataInfo::default();
     let info_traffic = &sniffer.info_traffic;
     let mut all_results: Vec<(&AddressPortPair, &InfoAddressPortPair)> = info_traffic
@@ -50,6 +89,7 @@ pub fn get_searched_entries(
         ReportSortType {
             byte_sort,
             packet_sort: SortType::Neutral,
+            rtt_sort: SortType::Neutral,
         } => match byte_sort {
             SortType::Ascending => a.transmitted_bytes.cmp(&b.transmitted_bytes),
             SortType::Descending => b.transmitted_bytes.cmp(&a.transmitted_bytes),
@@ -58,26 +98,29 @@ pub fn get_searched_entries(
         ReportSortType {
             byte_sort: SortType::Neutral,
             packet_sort,
+            rtt_sort: SortType::Neutral,
         } => match packet_sort {
             SortType::Ascending => a.transmitted_packets.cmp(&b.transmitted_packets),
             SortType::Descending => b.transmitted_packets.cmp(&a.transmitted_packets),
             SortType::Neutral => b.final_timestamp.cmp(&a.final_timestamp),
         },
+        ReportSortType {
+            byte_sort: SortType::Neutral,
+            packet_sort: SortType::Neutral,
+            rtt_sort,
+        } => match rtt_sort {
+            // connections without an estimation are always at the bottom
+            SortType::Ascending => a.tcp_rtt.cmp_values(&b.tcp_rtt),
+            SortType::Descending => match (a.tcp_rtt.value(), b.tcp_rtt.value()) {
+                (Some(a_rtt), Some(b_rtt)) => b_rtt.cmp(&a_rtt),
+                _ => a.tcp_rtt.cmp_values(&b.tcp_rtt),
+            },
+            SortType::Neutral => b.final_timestamp.cmp(&a.final_timestamp),
+        },
         _ => b.final_timestamp.cmp(&a.final_timestamp),
     });
 
-    let upper_bound = min(sniffer.page_number * 20, all_results.len());
-
-    (
-        all_results
-            .get((sniffer.page_number.saturating_sub(1)) * 20..upper_bound)
-            .unwrap_or_default()
-            .iter()
-            .map(|&(key, val)| (key.to_owned(), val.to_owned()))
-            .collect(),
-        all_results.len(),
-        agglomerate,
-    )
+    (all_results, agglomerate)
 }
 
 pub fn get_host_entries(
@@ -96,6 +139,22 @@ pub fn get_host_entries(
         .collect()
 }
 
+pub fn get_application_entries(
+    info_traffic: &InfoTraffic,
+    chart_type: ChartType,
+    sort_type: SortType,
+) -> Vec<(String, DataInfo)> {
+    let mut sorted_vec: Vec<(&String, &DataInfo)> = info_traffic.applications.iter().collect();
+
+    sorted_vec.sort_by(|&(_, a), &(_, b)| a.compare(b, sort_type, chart_type));
+
+    let n_entry = min(sorted_vec.len(), 30);
+    sorted_vec[0..n_entry]
+        .iter()
+        .map(|&(application, data_info)| (application.to_owned(), *data_info))
+        .collect()
+}
+
 pub fn get_service_entries(
     info_traffic: &InfoTraffic,
     chart_type: ChartType,
@@ -1,2 +1,3 @@
 pub mod get_report_entries;
+pub mod traffic_report;
 pub mod types;
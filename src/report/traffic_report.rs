@@ -0,0 +1,569 @@
+//! Module defining the `TrafficReport` struct, a serializable snapshot of the sniffed traffic.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::IpAddr;
+
+use serde::Serialize;
+
+use crate::networking::manage_packets::get_address_to_lookup;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::host::Host;
+use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::utils::formatted_strings::get_formatted_timestamp;
+use crate::{ByteMultiple, InfoTraffic};
+
+/// Serializable snapshot of the traffic, including connections, hosts, and services
+#[derive(Serialize, Debug, PartialEq)]
+pub struct TrafficReport {
+    /// Name of the network adapter or path of the file the traffic was read from
+    pub source: String,
+    /// Total packets including those not filtered
+    pub all_packets: u128,
+    /// Total bytes including those not filtered
+    pub all_bytes: u128,
+    /// Number of dropped packets
+    pub dropped_packets: u32,
+    /// Incoming and outgoing filtered traffic
+    pub traffic: DataRecord,
+    /// Every connection in the filtered traffic
+    pub connections: Vec<ConnectionRecord>,
+    /// Every host in the filtered traffic
+    pub hosts: Vec<HostRecord>,
+    /// Every upper layer service in the filtered traffic
+    pub services: Vec<ServiceRecord>,
+}
+
+/// Packets and bytes exchanged in each direction
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub struct DataRecord {
+    pub incoming_packets: u128,
+    pub outgoing_packets: u128,
+    pub incoming_bytes: u128,
+    pub outgoing_bytes: u128,
+}
+
+/// A single connection, with the remote host it's associated to
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct ConnectionRecord {
+    pub src_ip: IpAddr,
+    pub src_port: Option<u16>,
+    pub dst_ip: IpAddr,
+    pub dst_port: Option<u16>,
+    pub protocol: String,
+    pub service: String,
+    pub traffic_direction: String,
+    pub src_mac: Option<String>,
+    pub dst_mac: Option<String>,
+    pub initial_timestamp: String,
+    pub final_timestamp: String,
+    pub packets: u128,
+    pub bytes: u128,
+    pub domain: String,
+    pub asn_code: String,
+    pub asn_name: String,
+    pub country: String,
+}
+
+/// A single host with its exchanged data
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct HostRecord {
+    pub domain: String,
+    pub asn_code: String,
+    pub asn_name: String,
+    pub country: String,
+    pub is_local: bool,
+    pub is_loopback: bool,
+    pub is_bogon: Option<&'static str>,
+    #[serde(flatten)]
+    pub data: DataRecord,
+}
+
+/// A single upper layer service with its exchanged data
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct ServiceRecord {
+    pub service: String,
+    #[serde(flatten)]
+    pub data: DataRecord,
+}
+
+impl TrafficReport {
+    /// Builds the report, looking up the remote host of each connection in `addresses_resolved`.
+    ///
+    /// Connections are sorted chronologically, hosts and services by exchanged bytes.
+    pub fn new(
+        source: String,
+        info_traffic: &InfoTraffic,
+        addresses_resolved: &HashMap<IpAddr, Host>,
+    ) -> Self {
+        let mut map: Vec<_> = info_traffic.map.iter().collect();
+        map.sort_by(|(_, a), (_, b)| {
+            a.initial_timestamp
+                .cmp(&b.initial_timestamp)
+                .then(b.transmitted_bytes.cmp(&a.transmitted_bytes))
+        });
+        let connections = map
+            .into_iter()
+            .map(|(key, val)| {
+                let address_to_lookup = get_address_to_lookup(key, val.traffic_direction);
+                ConnectionRecord::new(key, val, addresses_resolved.get(&address_to_lookup))
+            })
+            .collect();
+
+        let mut hosts: Vec<_> = info_traffic.hosts.iter().collect();
+        hosts.sort_by_key(|(_, d)| Reverse(d.data_info.tot_bytes()));
+        let hosts = hosts
+            .into_iter()
+            .map(|(host, data_info_host)| HostRecord {
+                domain: host.domain.clone(),
+                asn_code: host.asn.code.clone(),
+                asn_name: host.asn.name.clone(),
+                country: host.country.to_string(),
+                is_local: data_info_host.is_local,
+                is_loopback: data_info_host.is_loopback,
+                is_bogon: data_info_host.is_bogon,
+                data: DataRecord::from(data_info_host.data_info),
+            })
+            .collect();
+
+        let mut services: Vec<_> = info_traffic.services.iter().collect();
+        services.sort_by_key(|(_, d)| Reverse(d.tot_bytes()));
+        let services = services
+            .into_iter()
+            .map(|(service, data_info)| ServiceRecord {
+                service: service.to_string(),
+                data: DataRecord::from(*data_info),
+            })
+            .collect();
+
+        Self {
+            source,
+            all_packets: info_traffic.all_packets,
+            all_bytes: info_traffic.all_bytes,
+            dropped_packets: info_traffic.dropped_packets,
+            traffic: DataRecord::from(info_traffic.tot_data_info),
+            connections,
+            hosts,
+            services,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut ret_val = format!(
+            "Source: {}\n\
+            Observed: {} packets ({})\n\
+            Dropped: {} packets\n\
+            Incoming: {} packets ({})\n\
+            Outgoing: {} packets ({})\n",
+            self.source,
+            self.all_packets,
+            ByteMultiple::formatted_string(self.all_bytes),
+            self.dropped_packets,
+            self.traffic.incoming_packets,
+            ByteMultiple::formatted_string(self.traffic.incoming_bytes),
+            self.traffic.outgoing_packets,
+            ByteMultiple::formatted_string(self.traffic.outgoing_bytes),
+        );
+
+        let _ = write!(ret_val, "\nConnections ({}):\n", self.connections.len());
+        for c in &self.connections {
+            let _ = writeln!(
+                ret_val,
+                "    {} {} -> {} | {} | {} | {} packets ({}) | {} - {} | {}",
+                c.protocol,
+                socket_string(c.src_ip, c.src_port),
+                socket_string(c.dst_ip, c.dst_port),
+                c.service,
+                c.traffic_direction,
+                c.packets,
+                ByteMultiple::formatted_string(c.bytes),
+                c.initial_timestamp,
+                c.final_timestamp,
+                host_string(&c.domain, &c.asn_name, &c.country),
+            );
+        }
+
+        let _ = write!(ret_val, "\nHosts ({}):\n", self.hosts.len());
+        for h in &self.hosts {
+            let _ = writeln!(
+                ret_val,
+                "    {} | {}",
+                host_string(&h.domain, &h.asn_name, &h.country),
+                h.data.to_text(),
+            );
+        }
+
+        let _ = write!(ret_val, "\nServices ({}):\n", self.services.len());
+        for s in &self.services {
+            let _ = writeln!(ret_val, "    {} | {}", s.service, s.data.to_text());
+        }
+
+        ret_val
+    }
+}
+
+impl ConnectionRecord {
+    pub fn new(key: &AddressPortPair, val: &InfoAddressPortPair, host: Option<&Host>) -> Self {
+        let host = host.cloned().unwrap_or_default();
+        Self {
+            src_ip: key.address1,
+            src_port: key.port1,
+            dst_ip: key.address2,
+            dst_port: key.port2,
+            protocol: key.protocol.to_string(),
+            service: val.service.to_string(),
+            traffic_direction: format!("{:?}", val.traffic_direction),
+            src_mac: val.mac_address1.clone(),
+            dst_mac: val.mac_address2.clone(),
+            initial_timestamp: get_formatted_timestamp(val.initial_timestamp),
+            final_timestamp: get_formatted_timestamp(val.final_timestamp),
+            packets: val.transmitted_packets,
+            bytes: val.transmitted_bytes,
+            domain: host.domain,
+            asn_code: host.asn.code,
+            asn_name: host.asn.name,
+            country: host.country.to_string(),
+        }
+    }
+}
+
+impl ConnectionRecord {
+    const CSV_HEADER: &'static str = "src_ip,src_port,dst_ip,dst_port,protocol,service,traffic_direction,src_mac,dst_mac,initial_timestamp,final_timestamp,packets,bytes,domain,asn_code,asn_name,country";
+
+    /// Serializes the given connections as CSV, including a header line
+    pub fn to_csv(records: &[Self]) -> String {
+        let mut ret_val = format!("{}\n", Self::CSV_HEADER);
+        for r in records {
+            let fields = [
+                r.src_ip.to_string(),
+                r.src_port.map(|p| p.to_string()).unwrap_or_default(),
+                r.dst_ip.to_string(),
+                r.dst_port.map(|p| p.to_string()).unwrap_or_default(),
+                r.protocol.clone(),
+                r.service.clone(),
+                r.traffic_direction.clone(),
+                r.src_mac.clone().unwrap_or_default(),
+                r.dst_mac.clone().unwrap_or_default(),
+                r.initial_timestamp.clone(),
+                r.final_timestamp.clone(),
+                r.packets.to_string(),
+                r.bytes.to_string(),
+                r.domain.clone(),
+                r.asn_code.clone(),
+                r.asn_name.clone(),
+                r.country.clone(),
+            ];
+            let row = fields
+                .iter()
+                .map(|f| csv_field(f))
+                .collect::<Vec<String>>()
+                .join(",");
+            let _ = writeln!(ret_val, "{row}");
+        }
+        ret_val
+    }
+
+    /// Serializes the given connections as a JSON array
+    pub fn to_json(records: &[Self]) -> String {
+        serde_json::to_string_pretty(records).unwrap_or_default()
+    }
+}
+
+impl From<DataInfo> for DataRecord {
+    fn from(data_info: DataInfo) -> Self {
+        Self {
+            incoming_packets: data_info.incoming_packets(),
+            outgoing_packets: data_info.outgoing_packets(),
+            incoming_bytes: data_info.incoming_bytes(),
+            outgoing_bytes: data_info.outgoing_bytes(),
+        }
+    }
+}
+
+impl DataRecord {
+    fn to_text(self) -> String {
+        format!(
+            "incoming: {} packets ({}) | outgoing: {} packets ({})",
+            self.incoming_packets,
+            ByteMultiple::formatted_string(self.incoming_bytes),
+            self.outgoing_packets,
+            ByteMultiple::formatted_string(self.outgoing_bytes),
+        )
+    }
+}
+
+/// Quotes a CSV field if it contains separators, quotes, or line breaks
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn socket_string(ip: IpAddr, port: Option<u16>) -> String {
+    match port {
+        Some(port) => format!("{ip}:{port}"),
+        None => ip.to_string(),
+    }
+}
+
+fn host_string(domain: &str, asn_name: &str, country: &str) -> String {
+    let mut ret_val = if domain.is_empty() {
+        "?".to_string()
+    } else {
+        domain.to_string()
+    };
+    if !asn_name.is_empty() {
+        let _ = write!(ret_val, " - {asn_name}");
+    }
+    let _ = write!(ret_val, " ({country})");
+    ret_val
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::countries::types::country::Country;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::utils::types::timestamp::Timestamp;
+    use crate::{Protocol, Service};
+
+    use super::*;
+
+    fn host_for_tests() -> Host {
+        Host {
+            domain: "sniffnet.net".to_string(),
+            asn: Asn {
+                code: "123".to_string(),
+                name: "Sniffnet ASN".to_string(),
+            },
+            country: Country::IT,
+        }
+    }
+
+    fn info_traffic_for_tests() -> InfoTraffic {
+        let mut info_traffic = InfoTraffic {
+            tot_data_info: DataInfo::new_for_tests(3, 2, 300, 200),
+            all_packets: 6,
+            all_bytes: 600,
+            ..InfoTraffic::default()
+        };
+        info_traffic.map.insert(
+            AddressPortPair::new(
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+                Some(50000),
+                IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+                Some(443),
+                Protocol::TCP,
+            ),
+            InfoAddressPortPair {
+                transmitted_bytes: 200,
+                transmitted_packets: 2,
+                initial_timestamp: Timestamp::new(10, 0),
+                final_timestamp: Timestamp::new(11, 0),
+                service: Service::Name("https"),
+                traffic_direction: TrafficDirection::Outgoing,
+                ..InfoAddressPortPair::default()
+            },
+        );
+        info_traffic.map.insert(
+            AddressPortPair::new(
+                IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+                Some(443),
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+                Some(50000),
+                Protocol::TCP,
+            ),
+            InfoAddressPortPair {
+                transmitted_bytes: 300,
+                transmitted_packets: 3,
+                initial_timestamp: Timestamp::new(5, 0),
+                final_timestamp: Timestamp::new(12, 0),
+                service: Service::Name("https"),
+                traffic_direction: TrafficDirection::Incoming,
+                ..InfoAddressPortPair::default()
+            },
+        );
+        info_traffic.hosts.insert(
+            host_for_tests(),
+            DataInfoHost {
+                data_info: DataInfo::new_for_tests(3, 2, 300, 200),
+                ..DataInfoHost::default()
+            },
+        );
+        info_traffic.hosts.insert(
+            Host::default(),
+            DataInfoHost {
+                data_info: DataInfo::new_for_tests(1, 0, 10, 0),
+                is_local: true,
+                ..DataInfoHost::default()
+            },
+        );
+        info_traffic.services.insert(
+            Service::Name("https"),
+            DataInfo::new_for_tests(3, 2, 300, 200),
+        );
+        info_traffic
+    }
+
+    #[test]
+    fn test_traffic_report_new() {
+        let addresses_resolved =
+            HashMap::from([(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), host_for_tests())]);
+        let report = TrafficReport::new(
+            "capture.pcap".to_string(),
+            &info_traffic_for_tests(),
+            &addresses_resolved,
+        );
+
+        assert_eq!(report.source, "capture.pcap");
+        assert_eq!(report.all_packets, 6);
+        assert_eq!(report.all_bytes, 600);
+        assert_eq!(
+            report.traffic,
+            DataRecord {
+                incoming_packets: 3,
+                outgoing_packets: 2,
+                incoming_bytes: 300,
+                outgoing_bytes: 200,
+            }
+        );
+
+        // connections are sorted chronologically
+        assert_eq!(report.connections.len(), 2);
+        assert_eq!(report.connections[0].src_port, Some(443));
+        assert_eq!(report.connections[0].traffic_direction, "Incoming");
+        assert_eq!(report.connections[0].packets, 3);
+        assert_eq!(report.connections[1].src_port, Some(50000));
+        assert_eq!(report.connections[1].traffic_direction, "Outgoing");
+        assert_eq!(report.connections[1].protocol, "TCP");
+        assert_eq!(report.connections[1].service, "https");
+        // both connections are associated with the remote host
+        for c in &report.connections {
+            assert_eq!(c.domain, "sniffnet.net");
+            assert_eq!(c.asn_code, "123");
+            assert_eq!(c.asn_name, "Sniffnet ASN");
+            assert_eq!(c.country, "IT");
+        }
+
+        // hosts are sorted by exchanged bytes
+        assert_eq!(report.hosts.len(), 2);
+        assert_eq!(report.hosts[0].domain, "sniffnet.net");
+        assert!(!report.hosts[0].is_local);
+        assert_eq!(report.hosts[1].domain, "");
+        assert_eq!(report.hosts[1].country, "?");
+        assert!(report.hosts[1].is_local);
+
+        assert_eq!(
+            report.services,
+            vec![ServiceRecord {
+                service: "https".to_string(),
+                data: DataRecord {
+                    incoming_packets: 3,
+                    outgoing_packets: 2,
+                    incoming_bytes: 300,
+                    outgoing_bytes: 200,
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn test_traffic_report_unresolved_host() {
+        let report = TrafficReport::new(
+            "eth0".to_string(),
+            &info_traffic_for_tests(),
+            &HashMap::new(),
+        );
+        for c in &report.connections {
+            assert_eq!(c.domain, "");
+            assert_eq!(c.asn_name, "");
+            assert_eq!(c.country, "?");
+        }
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("sniffnet.net"), "sniffnet.net");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("Google, LLC"), "\"Google, LLC\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+    }
+
+    #[test]
+    fn test_connection_records_to_csv() {
+        let info_traffic = info_traffic_for_tests();
+        let (key, val) = info_traffic
+            .map
+            .iter()
+            .find(|(k, _)| k.port1 == Some(443))
+            .unwrap();
+        let mut host = host_for_tests();
+        host.asn.name = "Sniffnet, Inc.".to_string();
+        let mut record = ConnectionRecord::new(key, val, Some(&host));
+        record.initial_timestamp = "2025/01/01 00:00:00".to_string();
+        record.final_timestamp = "2025/01/01 00:00:07".to_string();
+        record.src_mac = Some("aa:bb:cc:dd:ee:ff".to_string());
+
+        assert_eq!(
+            ConnectionRecord::to_csv(&[record.clone()]),
+            "src_ip,src_port,dst_ip,dst_port,protocol,service,traffic_direction,src_mac,dst_mac,initial_timestamp,final_timestamp,packets,bytes,domain,asn_code,asn_name,country\n\
+            1.1.1.1,443,192.168.1.2,50000,TCP,https,Incoming,aa:bb:cc:dd:ee:ff,,2025/01/01 00:00:00,2025/01/01 00:00:07,3,300,sniffnet.net,123,\"Sniffnet, Inc.\",IT\n"
+        );
+        assert_eq!(
+            ConnectionRecord::to_csv(&[]),
+            format!("{}\n", ConnectionRecord::CSV_HEADER)
+        );
+
+        let json = ConnectionRecord::to_json(&[record]);
+        assert!(json.starts_with('['));
+        assert!(json.contains("\"asn_name\": \"Sniffnet, Inc.\""));
+        assert!(json.contains("\"dst_mac\": null"));
+        assert_eq!(ConnectionRecord::to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_traffic_report_formats() {
+        let report = TrafficReport::new(
+            "capture.pcap".to_string(),
+            &info_traffic_for_tests(),
+            &HashMap::new(),
+        );
+
+        let text = report.to_text();
+        assert!(text.starts_with(
+            "Source: capture.pcap\n\
+            Observed: 6 packets (600 B)\n\
+            Dropped: 0 packets\n\
+            Incoming: 3 packets (300 B)\n\
+            Outgoing: 2 packets (200 B)\n\
+            \nConnections (2):\n    TCP 1.1.1.1:443 -> 192.168.1.2:50000 | https | Incoming | 3 packets (300 B) | "
+        ));
+        assert!(text.contains(
+            "\nHosts (2):\n    sniffnet.net - Sniffnet ASN (IT) | incoming: 3 packets (300 B) | outgoing: 2 packets (200 B)\n    \
+            ? (?) | incoming: 1 packets (10 B) | outgoing: 0 packets (0 B)\n"
+        ));
+        assert!(text.ends_with(
+            "\nServices (1):\n    https | incoming: 3 packets (300 B) | outgoing: 2 packets (200 B)\n"
+        ));
+
+        let json = report.to_json();
+        assert!(json.contains("\"source\": \"capture.pcap\""));
+        assert!(json.contains("\"src_ip\": \"1.1.1.1\""));
+        assert!(json.contains("\"src_port\": 443"));
+        assert!(json.contains("\"is_local\": true"));
+        assert!(json.contains("\"service\": \"https\""));
+        assert!(json.contains("\"incoming_bytes\": 300"));
+    }
+}
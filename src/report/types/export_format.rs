@@ -0,0 +1,55 @@
+use crate::report::traffic_report::ConnectionRecord;
+
+/// Formats available to export the connections of the inspect page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Json];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn serialize(self, records: &[ConnectionRecord]) -> String {
+        match self {
+            ExportFormat::Csv => ConnectionRecord::to_csv(records),
+            ExportFormat::Json => ConnectionRecord::to_json(records),
+        }
+    }
+}
+
+impl std::fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.extension().to_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_format_display_and_extension() {
+        assert_eq!(ExportFormat::Csv.to_string(), "CSV");
+        assert_eq!(ExportFormat::Json.to_string(), "JSON");
+        assert_eq!(ExportFormat::Csv.extension(), "csv");
+        assert_eq!(ExportFormat::Json.extension(), "json");
+    }
+
+    #[test]
+    fn test_export_format_serialize_empty() {
+        assert!(
+            ExportFormat::Csv
+                .serialize(&[])
+                .starts_with("src_ip,src_port,")
+        );
+        assert_eq!(ExportFormat::Json.serialize(&[]), "[]");
+    }
+}
@@ -1,3 +1,4 @@
+pub mod export_format;
 pub mod report_col;
 pub mod report_sort_type;
 pub mod search_parameters;
@@ -7,9 +7,10 @@
 };
 use crate::translations::translations_2::{destination_translation, source_translation};
 use crate::translations::translations_3::{port_translation, service_translation};
+use crate::translations::translations_4::{rtt_translation, server_name_translation};
 use crate::translations::types::language::Language;
 
-// total width: 1012.0
+// total width: 1328.0
 
 const LARGE_COL_WIDTH: f32 = 221.0;
 const SMALL_COL_WIDTH: f32 = 95.0;
@@ -25,23 +26,27 @@ pub enum ReportCol {
     DstPort,
     Proto,
     Service,
+    ServerName,
     Bytes,
     Packets,
+    Rtt,
 }
 
 impl ReportCol {
-    pub(crate) const ALL: [ReportCol; 8] = [
+    pub(crate) const ALL: [ReportCol; 10] = [
         ReportCol::SrcIp,
         ReportCol::SrcPort,
         ReportCol::DstIp,
         ReportCol::DstPort,
         ReportCol::Proto,
         ReportCol::Service,
+        ReportCol::ServerName,
         ReportCol::Bytes,
         ReportCol::Packets,
+        ReportCol::Rtt,
     ];
 
-    pub(crate) const FILTER_COLUMNS_WIDTH: f32 = 4.0 * SMALL_COL_WIDTH + 2.0 * LARGE_COL_WIDTH;
+    pub(crate) const FILTER_COLUMNS_WIDTH: f32 = 4.0 * SMALL_COL_WIDTH + 3.0 * LARGE_COL_WIDTH;
 
     pub(crate) fn get_title(&self, language: Language) -> String {
         match self {
@@ -49,6 +54,7 @@ pub(crate) fn get_title(&self, language: Language) -> String {
             ReportCol::SrcPort | ReportCol::DstPort => port_translation(language).to_string(),
             ReportCol::Proto => protocol_translation(language).to_string(),
             ReportCol::Service => service_translation(language).to_string(),
+            ReportCol::ServerName => server_name_translation(language).to_string(),
             ReportCol::Bytes => {
                 let mut str = bytes_translation(language).to_string();
                 str.remove(0).to_uppercase().to_string() + &str
@@ -57,6 +63,7 @@ pub(crate) fn get_title(&self, language: Language) -> String {
                 let mut str = packets_translation(language).to_string();
                 str.remove(0).to_uppercase().to_string() + &str
             }
+            ReportCol::Rtt => rtt_translation(language).to_string(),
         }
     }
 
@@ -92,14 +99,24 @@ pub(crate) fn get_value(&self, key: &AddressPortPair, val: &InfoAddressPortPair)
             }
             ReportCol::Proto => key.protocol.to_string(),
             ReportCol::Service => val.service.to_string(),
+            ReportCol::ServerName => val
+                .tls_client_hello
+                .as_ref()
+                .and_then(|client_hello| client_hello.server_name.clone())
+                .unwrap_or_else(|| "-".to_string()),
             ReportCol::Bytes => ByteMultiple::formatted_string(val.transmitted_bytes),
             ReportCol::Packets => val.transmitted_packets.to_string(),
+            ReportCol::Rtt => val.tcp_rtt.formatted_string(),
         }
     }
 
+    pub(crate) fn is_sortable(&self) -> bool {
+        matches!(self, ReportCol::Bytes | ReportCol::Packets | ReportCol::Rtt)
+    }
+
     pub(crate) fn get_width(&self) -> f32 {
         match self {
-            ReportCol::SrcIp | ReportCol::DstIp => LARGE_COL_WIDTH,
+            ReportCol::SrcIp | ReportCol::DstIp | ReportCol::ServerName => LARGE_COL_WIDTH,
             _ => SMALL_COL_WIDTH,
         }
     }
@@ -113,7 +130,9 @@ pub(crate) fn get_max_chars(&self, language_opt: Option<Language>) -> usize {
             1
         };
         match self {
-            ReportCol::SrcIp | ReportCol::DstIp => LARGE_COL_MAX_CHARS / reduction_factor,
+            ReportCol::SrcIp | ReportCol::DstIp | ReportCol::ServerName => {
+                LARGE_COL_MAX_CHARS / reduction_factor
+            }
             _ => SMALL_COL_MAX_CHARS / reduction_factor,
         }
     }
@@ -126,7 +145,8 @@ pub(crate) fn get_filter_input_type(&self) -> FilterInputType {
             ReportCol::DstPort => FilterInputType::PortDst,
             ReportCol::Proto => FilterInputType::Proto,
             ReportCol::Service => FilterInputType::Service,
-            ReportCol::Bytes | ReportCol::Packets => FilterInputType::Country, // just to not panic...
+            ReportCol::ServerName => FilterInputType::ServerName,
+            ReportCol::Bytes | ReportCol::Packets | ReportCol::Rtt => FilterInputType::Country, // just to not panic...
         }
     }
 }
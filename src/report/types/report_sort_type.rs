@@ -13,6 +13,7 @@
 pub struct ReportSortType {
     pub byte_sort: SortType,
     pub packet_sort: SortType,
+    pub rtt_sort: SortType,
 }
 
 impl ReportSortType {
@@ -20,11 +21,15 @@ pub fn next_sort(self, report_col: &ReportCol) -> Self {
         match report_col {
             ReportCol::Bytes => Self {
                 byte_sort: self.byte_sort.next_sort(),
-                packet_sort: SortType::Neutral,
+                ..Self::default()
             },
             ReportCol::Packets => Self {
-                byte_sort: SortType::Neutral,
                 packet_sort: self.packet_sort.next_sort(),
+                ..Self::default()
+            },
+            ReportCol::Rtt => Self {
+                rtt_sort: self.rtt_sort.next_sort(),
+                ..Self::default()
             },
             _ => Self::default(),
         }
@@ -34,6 +39,7 @@ pub fn icon<'a>(self, report_col: &ReportCol) -> Text<'a, StyleType> {
         match report_col {
             ReportCol::Bytes => self.byte_sort.icon(),
             ReportCol::Packets => self.packet_sort.icon(),
+            ReportCol::Rtt => self.rtt_sort.icon(),
             _ => Icon::SortNeutral.to_text(),
         }
     }
@@ -42,6 +48,7 @@ pub fn button_type(self, report_col: &ReportCol) -> ButtonType {
         match report_col {
             ReportCol::Bytes => self.byte_sort.button_type(),
             ReportCol::Packets => self.packet_sort.button_type(),
+            ReportCol::Rtt => self.rtt_sort.button_type(),
             _ => ButtonType::SortArrows,
         }
     }
@@ -60,7 +67,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -69,7 +77,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -78,7 +87,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Ascending
+                packet_sort: SortType::Ascending,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -87,7 +97,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -96,7 +107,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -105,7 +117,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -114,7 +127,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -123,7 +137,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -132,7 +147,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Ascending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -141,7 +157,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -150,7 +167,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -159,7 +177,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Ascending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
 
@@ -168,7 +187,40 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
+            }
+        );
+
+        sort = sort
+            .next_sort(&ReportCol::Packets)
+            .next_sort(&ReportCol::Rtt);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                byte_sort: SortType::Neutral,
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Descending,
+            }
+        );
+
+        sort = sort.next_sort(&ReportCol::Rtt);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                byte_sort: SortType::Neutral,
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Ascending,
+            }
+        );
+
+        sort = sort.next_sort(&ReportCol::Bytes);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                byte_sort: SortType::Descending,
+                packet_sort: SortType::Neutral,
+                rtt_sort: SortType::Neutral,
             }
         );
     }
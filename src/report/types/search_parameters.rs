@@ -19,12 +19,18 @@ pub struct SearchParameters {
     pub proto: String,
     /// Service
     pub service: String,
+    /// Server name (TLS SNI)
+    pub server_name: String,
     /// Country
     pub country: String,
     /// Domain
     pub domain: String,
     /// Autonomous System name
     pub as_name: String,
+    /// VLAN ID
+    pub vlan: String,
+    /// MPLS label
+    pub mpls: String,
     /// Whether to display only favorites
     pub only_favorites: bool,
 }
@@ -103,22 +109,28 @@ pub enum FilterInputType {
     PortDst,
     Proto,
     Service,
+    ServerName,
     Country,
     Domain,
     AsName,
+    Vlan,
+    Mpls,
 }
 
 impl FilterInputType {
-    pub const ALL: [FilterInputType; 9] = [
+    pub const ALL: [FilterInputType; 12] = [
         Self::AddressSrc,
         Self::PortSrc,
         Self::AddressDst,
         Self::PortDst,
         Self::Proto,
         Self::Service,
+        Self::ServerName,
         Self::Country,
         Self::Domain,
         Self::AsName,
+        Self::Vlan,
+        Self::Mpls,
     ];
 
     pub fn matches_entry(
@@ -137,6 +149,10 @@ pub fn matches_entry(
         let entry_value = self.entry_value(key, value, r_dns_host).to_lowercase();
 
         if let Some(stripped_filter) = filter_value.strip_prefix('=') {
+            // connections can be carried over multiple VLANs or labels: any of them can match
+            if matches!(self, FilterInputType::Vlan | FilterInputType::Mpls) {
+                return entry_value.split(", ").any(|v| v.eq(stripped_filter));
+            }
             return entry_value.eq(stripped_filter);
         }
 
@@ -151,9 +167,12 @@ pub fn current_value(self, search_params: &SearchParameters) -> &str {
             FilterInputType::PortDst => &search_params.port_dst,
             FilterInputType::Proto => &search_params.proto,
             FilterInputType::Service => &search_params.service,
+            FilterInputType::ServerName => &search_params.server_name,
             FilterInputType::Country => &search_params.country,
             FilterInputType::Domain => &search_params.domain,
             FilterInputType::AsName => &search_params.as_name,
+            FilterInputType::Vlan => &search_params.vlan,
+            FilterInputType::Mpls => &search_params.mpls,
         }
     }
 
@@ -182,6 +201,11 @@ pub fn entry_value(
             }
             FilterInputType::Proto => key.protocol.to_string(),
             FilterInputType::Service => value.service.to_string(),
+            FilterInputType::ServerName => value
+                .tls_client_hello
+                .as_ref()
+                .and_then(|client_hello| client_hello.server_name.clone())
+                .unwrap_or_default(),
             FilterInputType::Country => r_dns_host
                 .unwrap_or(&(String::new(), Host::default()))
                 .1
@@ -197,6 +221,18 @@ pub fn entry_value(
                 .asn
                 .name
                 .to_string(),
+            FilterInputType::Vlan => value
+                .vlan_ids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+            FilterInputType::Mpls => value
+                .mpls_labels
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
         }
     }
 
@@ -226,6 +262,10 @@ pub fn clear_search(self, search_params: &SearchParameters) -> SearchParameters
                 service: String::new(),
                 ..search_params.clone()
             },
+            FilterInputType::ServerName => SearchParameters {
+                server_name: String::new(),
+                ..search_params.clone()
+            },
             FilterInputType::Domain => SearchParameters {
                 domain: String::new(),
                 ..search_params.clone()
@@ -238,6 +278,14 @@ pub fn clear_search(self, search_params: &SearchParameters) -> SearchParameters
                 as_name: String::new(),
                 ..search_params.clone()
             },
+            FilterInputType::Vlan => SearchParameters {
+                vlan: String::new(),
+                ..search_params.clone()
+            },
+            FilterInputType::Mpls => SearchParameters {
+                mpls: String::new(),
+                ..search_params.clone()
+            },
         }
     }
 
@@ -271,6 +319,10 @@ pub fn new_search(
                 service: new_value.trim().to_string(),
                 ..search_params.clone()
             },
+            FilterInputType::ServerName => SearchParameters {
+                server_name: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
             FilterInputType::Domain => SearchParameters {
                 domain: new_value.trim().to_string(),
                 ..search_params.clone()
@@ -283,6 +335,14 @@ pub fn new_search(
                 as_name: new_value,
                 ..search_params.clone()
             },
+            FilterInputType::Vlan => SearchParameters {
+                vlan: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
+            FilterInputType::Mpls => SearchParameters {
+                mpls: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
         }
     }
 }
@@ -114,3 +114,572 @@ pub fn resume_translation(language: Language) -> &'static str {
         _ => "Resume",
     }
 }
+
+pub fn save_failed_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "The file couldn't be saved",
+        Language::IT => "Impossibile salvare il file",
+        _ => "The file couldn't be saved",
+    }
+}
+
+pub fn export_results_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Export results",
+        Language::IT => "Esporta risultati",
+        _ => "Export results",
+    }
+}
+
+// Berkeley Packet Filter expression applied to the capture
+pub fn bpf_filter_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Capture filter (BPF)",
+        Language::IT => "Filtro di cattura (BPF)",
+        _ => "Capture filter (BPF)",
+    }
+}
+
+pub fn connection_state_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Connection state",
+        Language::IT => "Stato della connessione",
+        _ => "Connection state",
+    }
+}
+
+pub fn dns_log_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "DNS log",
+        Language::IT => "Registro DNS",
+        _ => "DNS log",
+    }
+}
+
+pub fn no_dns_responses_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "No DNS response has been observed yet",
+        Language::IT => "Nessuna risposta DNS è stata ancora osservata",
+        _ => "No DNS response has been observed yet",
+    }
+}
+
+pub fn dns_answers_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Answers",
+        Language::IT => "Risposte",
+        _ => "Answers",
+    }
+}
+
+// TLS Server Name Indication
+pub fn server_name_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Server name",
+        Language::IT => "Nome del server",
+        _ => "Server name",
+    }
+}
+
+pub fn application_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Application",
+        Language::IT => "Applicazione",
+        _ => "Application",
+    }
+}
+
+pub fn process_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Process",
+        Language::IT => "Processo",
+        _ => "Process",
+    }
+}
+
+pub fn save_session_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Save session",
+        Language::IT => "Salva sessione",
+        _ => "Save session",
+    }
+}
+
+pub fn open_session_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Open session",
+        Language::IT => "Apri sessione",
+        _ => "Open session",
+    }
+}
+
+pub fn new_host_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New host seen",
+        Language::IT => "Nuovo host rilevato",
+        _ => "New host seen",
+    }
+}
+
+pub fn new_asn_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New autonomous system seen",
+        Language::IT => "Nuovo sistema autonomo rilevato",
+        _ => "New autonomous system seen",
+    }
+}
+
+pub fn new_country_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New country seen",
+        Language::IT => "Nuovo paese rilevato",
+        _ => "New country seen",
+    }
+}
+
+pub fn host_identity_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Identify hosts by",
+        Language::IT => "Identifica gli host per",
+        _ => "Identify hosts by",
+    }
+}
+
+pub fn scoped_thresholds_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Thresholds for specific traffic",
+        Language::IT => "Soglie per traffico specifico",
+        _ => "Thresholds for specific traffic",
+    }
+}
+
+pub fn add_threshold_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Add threshold",
+        Language::IT => "Aggiungi soglia",
+        _ => "Add threshold",
+    }
+}
+
+pub fn desktop_notifications_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Show desktop notifications",
+        Language::IT => "Mostra notifiche desktop",
+        _ => "Show desktop notifications",
+    }
+}
+
+pub fn action_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Action",
+        Language::IT => "Azione",
+        _ => "Action",
+    }
+}
+
+pub fn command_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Command",
+        Language::IT => "Comando",
+        _ => "Command",
+    }
+}
+
+pub fn current_capture_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Current capture",
+        Language::IT => "Cattura corrente",
+        _ => "Current capture",
+    }
+}
+
+pub fn notifications_history_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "History",
+        Language::IT => "Cronologia",
+        _ => "History",
+    }
+}
+
+pub fn all_kinds_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "All",
+        Language::IT => "Tutte",
+        _ => "All",
+    }
+}
+
+pub fn search_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Search",
+        Language::IT => "Cerca",
+        _ => "Search",
+    }
+}
+
+pub fn from_date_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "From",
+        Language::IT => "Dal",
+        _ => "From",
+    }
+}
+
+pub fn to_date_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "To",
+        Language::IT => "Al",
+        _ => "To",
+    }
+}
+
+pub fn exceeded_for_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Exceeded for",
+        Language::IT => "Superata per",
+        _ => "Exceeded for",
+    }
+}
+
+pub fn rearm_below_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Re-arm below",
+        Language::IT => "Riattiva sotto",
+        _ => "Re-arm below",
+    }
+}
+
+pub fn cooldown_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Cooldown",
+        Language::IT => "Attesa minima",
+        _ => "Cooldown",
+    }
+}
+
+pub fn anomaly_detected_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Traffic anomaly detected",
+        Language::IT => "Anomalia di traffico rilevata",
+        _ => "Traffic anomaly detected",
+    }
+}
+
+pub fn baseline_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Usual",
+        Language::IT => "Solito",
+        _ => "Usual",
+    }
+}
+
+pub fn whole_traffic_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Whole traffic",
+        Language::IT => "Traffico totale",
+        _ => "Whole traffic",
+    }
+}
+
+pub fn sensitivity_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Sensitivity",
+        Language::IT => "Sensibilità",
+        _ => "Sensitivity",
+    }
+}
+
+pub fn low_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Low",
+        Language::IT => "Bassa",
+        _ => "Low",
+    }
+}
+
+pub fn medium_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Medium",
+        Language::IT => "Media",
+        _ => "Medium",
+    }
+}
+
+pub fn high_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "High",
+        Language::IT => "Alta",
+        _ => "High",
+    }
+}
+
+pub fn scan_detected_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Possible network scan",
+        Language::IT => "Possibile scansione di rete",
+        _ => "Possible network scan",
+    }
+}
+
+pub fn distinct_ports_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Distinct ports",
+        Language::IT => "Porte distinte",
+        _ => "Distinct ports",
+    }
+}
+
+pub fn distinct_hosts_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Distinct hosts",
+        Language::IT => "Host distinti",
+        _ => "Distinct hosts",
+    }
+}
+
+pub fn within_seconds_translation(language: Language, seconds: usize) -> String {
+    match language {
+        Language::IT => format!("entro {seconds} s"),
+        _ => format!("within {seconds} s"),
+    }
+}
+
+pub fn blocklists_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Blocklists",
+        Language::IT => "Liste di blocco",
+        _ => "Blocklists",
+    }
+}
+
+pub fn blocklisted_host_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Host in blocklist",
+        Language::IT => "Host in lista di blocco",
+        _ => "Host in blocklist",
+    }
+}
+
+pub fn select_blocklist_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Select blocklist file",
+        Language::IT => "Seleziona file della lista di blocco",
+        _ => "Select blocklist file",
+    }
+}
+
+pub fn entries_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "entries",
+        Language::IT => "voci",
+        _ => "entries",
+    }
+}
+
+pub fn unreadable_file_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "unreadable file",
+        Language::IT => "file non leggibile",
+        _ => "unreadable file",
+    }
+}
+
+pub fn label_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Label",
+        Language::IT => "Etichetta",
+        _ => "Label",
+    }
+}
+
+pub fn host_labels_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Host labels",
+        Language::IT => "Etichette degli host",
+        _ => "Host labels",
+    }
+}
+
+pub fn label_target_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "IP, range, CIDR, or MAC",
+        Language::IT => "IP, intervallo, CIDR o MAC",
+        _ => "IP, range, CIDR, or MAC",
+    }
+}
+
+pub fn name_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Name",
+        Language::IT => "Nome",
+        _ => "Name",
+    }
+}
+
+pub fn group_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Group",
+        Language::IT => "Gruppo",
+        _ => "Group",
+    }
+}
+
+pub fn favorite_hosts_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Favorite hosts",
+        Language::IT => "Host preferiti",
+        _ => "Favorite hosts",
+    }
+}
+
+pub fn import_favorites_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Import favorites",
+        Language::IT => "Importa preferiti",
+        _ => "Import favorites",
+    }
+}
+
+pub fn export_favorites_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Export favorites",
+        Language::IT => "Esporta preferiti",
+        _ => "Export favorites",
+    }
+}
+
+pub fn capture_also_adapter_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Capture this adapter as well",
+        Language::IT => "Cattura anche questo adattatore",
+        _ => "Capture this adapter as well",
+    }
+}
+
+pub fn network_adapters_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Network adapters",
+        Language::IT => "Adattatori di rete",
+        _ => "Network adapters",
+    }
+}
+
+pub fn monitor_mode_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Monitor mode",
+        Language::IT => "Modalità monitor",
+        _ => "Monitor mode",
+    }
+}
+
+pub fn station_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Station",
+        Language::IT => "Stazione",
+        _ => "Station",
+    }
+}
+
+pub fn signal_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Signal",
+        Language::IT => "Segnale",
+        _ => "Signal",
+    }
+}
+
+pub fn channel_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Channel",
+        Language::IT => "Canale",
+        _ => "Channel",
+    }
+}
+
+pub fn vlan_ids_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "VLAN IDs",
+        Language::IT => "ID VLAN",
+        _ => "VLAN IDs",
+    }
+}
+
+pub fn mpls_labels_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "MPLS labels",
+        Language::IT => "Etichette MPLS",
+        _ => "MPLS labels",
+    }
+}
+
+pub fn tcp_opening_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Opening",
+        Language::IT => "In apertura",
+        _ => "Opening",
+    }
+}
+
+pub fn tcp_established_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Established",
+        Language::IT => "Stabilita",
+        _ => "Established",
+    }
+}
+
+pub fn tcp_fin_wait_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Closing",
+        Language::IT => "In chiusura",
+        _ => "Closing",
+    }
+}
+
+pub fn tcp_closed_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Closed",
+        Language::IT => "Chiusa",
+        _ => "Closed",
+    }
+}
+
+pub fn tcp_reset_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Reset",
+        Language::IT => "Reimpostata",
+        _ => "Reset",
+    }
+}
+
+pub fn tcp_half_open_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Half-open",
+        Language::IT => "Semiaperta",
+        _ => "Half-open",
+    }
+}
+
+pub fn rtt_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "RTT",
+        Language::IT => "RTT",
+        _ => "RTT",
+    }
+}
+
+pub fn dns_query_type_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Type",
+        Language::IT => "Tipo",
+        _ => "Type",
+    }
+}
+
+pub fn dns_response_code_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Result",
+        Language::IT => "Esito",
+        _ => "Result",
+    }
+}
@@ -7,6 +7,7 @@
     address_translation, ip_version_translation, protocol_translation,
 };
 use crate::translations::translations_3::{invalid_filters_translation, port_translation};
+use crate::translations::translations_4::{bpf_filter_translation, vlan_ids_translation};
 use crate::utils::types::timestamp::Timestamp;
 use chrono::{Local, TimeZone};
 use std::fmt::Write;
@@ -41,6 +42,12 @@ pub fn get_invalid_filters_string(filters: &Filters, language: Language) -> Stri
     if !filters.port_valid() {
         let _ = write!(ret_val, "\n • {}", port_translation(language));
     }
+    if !filters.vlan_valid() {
+        let _ = write!(ret_val, "\n • {}", vlan_ids_translation(language));
+    }
+    if !filters.bpf_valid() {
+        let _ = write!(ret_val, "\n • {}", bpf_filter_translation(language));
+    }
     ret_val
 }
 
@@ -79,6 +86,22 @@ pub fn get_active_filters_string(filters: &Filters, language: Language) -> Strin
             filters.port_str
         );
     }
+    if filters.vlan_active() {
+        let _ = writeln!(
+            filters_string,
+            "• {}: {}",
+            vlan_ids_translation(language),
+            filters.vlan_str
+        );
+    }
+    if filters.bpf_active() {
+        let _ = writeln!(
+            filters_string,
+            "• {}: {}",
+            bpf_filter_translation(language),
+            filters.bpf.trim()
+        );
+    }
     filters_string
 }
 
@@ -201,6 +224,8 @@ pub fn redirect_stdout_stderr_to_file()
 
 #[cfg(test)]
 mod tests {
+    use crate::networking::types::port_collection::PortCollection;
+
     use super::*;
 
     #[test]
@@ -271,4 +296,23 @@ fn test_get_domain_from_r_dns() {
         assert_eq!(f("..."), "..");
         assert_eq!(f("no_dots_in_this"), "no_dots_in_this");
     }
+
+    #[test]
+    fn test_active_filters_string_bpf() {
+        let mut filters = Filters::default();
+        assert_eq!(get_active_filters_string(&filters, Language::EN), "");
+
+        filters.bpf = "  tcp port 443  ".to_string();
+        assert_eq!(
+            get_active_filters_string(&filters, Language::EN),
+            "• Capture filter (BPF): tcp port 443\n"
+        );
+
+        filters.port_str = "443".to_string();
+        filters.port_collection = PortCollection::new("443").unwrap();
+        assert_eq!(
+            get_active_filters_string(&filters, Language::EN),
+            "• Port: 443\n• Capture filter (BPF): tcp port 443\n"
+        );
+    }
 }
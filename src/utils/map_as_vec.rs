@@ -0,0 +1,53 @@
+//! Module used to (de)serialize maps as sequences of key-value pairs,
+//! since some formats (e.g., JSON) only support maps with string keys.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_seq(map)
+}
+
+pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let pairs = Vec::<(K, V)>::deserialize(deserializer)?;
+    Ok(pairs.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct WithMap {
+        #[serde(with = "crate::utils::map_as_vec")]
+        map: HashMap<(u8, u8), String>,
+    }
+
+    #[test]
+    fn test_map_as_vec_round_trip() {
+        let value = WithMap {
+            map: HashMap::from([((1, 2), "a".to_string()), ((3, 4), "b".to_string())]),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<WithMap>(&json).unwrap(), value);
+
+        let empty = WithMap {
+            map: HashMap::new(),
+        };
+        assert_eq!(serde_json::to_string(&empty).unwrap(), r#"{"map":[]}"#);
+    }
+}
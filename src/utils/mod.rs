@@ -1,4 +1,5 @@
 pub mod check_updates;
 pub mod error_logger;
 pub mod formatted_strings;
+pub mod map_as_vec;
 pub mod types;
@@ -1,7 +1,10 @@
 use crate::translations::translations_3::{
     database_from_file_translation, select_directory_translation, style_from_file_translation,
 };
-use crate::translations::translations_4::select_capture_translation;
+use crate::translations::translations_4::{
+    import_favorites_translation, open_session_translation, select_blocklist_translation,
+    select_capture_translation,
+};
 use crate::translations::types::language::Language;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -10,6 +13,9 @@ pub enum FileInfo {
     Database,
     Directory,
     PcapImport,
+    Session,
+    Blocklist,
+    Favorites,
 }
 
 impl FileInfo {
@@ -19,6 +25,10 @@ pub fn get_extensions(&self) -> Vec<&'static str> {
             FileInfo::Database => vec!["mmdb"],
             FileInfo::Directory => vec![],
             FileInfo::PcapImport => vec!["pcap", "pcapng", "cap"],
+            FileInfo::Session => vec!["json"],
+            // blocklists come with many different extensions (or none at all)
+            FileInfo::Blocklist => vec![],
+            FileInfo::Favorites => vec!["toml"],
         }
     }
 
@@ -28,6 +38,9 @@ pub fn action_info(&self, language: Language) -> &'static str {
             FileInfo::Database => database_from_file_translation(language),
             FileInfo::Directory => select_directory_translation(language),
             FileInfo::PcapImport => select_capture_translation(language),
+            FileInfo::Session => open_session_translation(language),
+            FileInfo::Blocklist => select_blocklist_translation(language),
+            FileInfo::Favorites => import_favorites_translation(language),
         }
     }
 }
@@ -1,4 +1,6 @@
-#[derive(Clone, Default, Debug, Copy, Eq, PartialEq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Timestamp {
     secs: i64,
     usecs: i64,